@@ -1,11 +1,16 @@
-use chrono::{Datelike, Duration, Local};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveTime};
+use directories::ProjectDirs;
 use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::PathBuf;
 
-use crate::api::TimeEntry;
+use crate::api::{format_duration_with_format, TimeEntry};
+use crate::config::{issue_type_color, TagDefinition, TimeFormat, DEFAULT_ISSUE_TYPE_COLORS};
 use crate::ui::WeekData;
 
+pub use timebox_core::export::ExportEntry;
+
 #[derive(Serialize)]
 pub struct WeeklyLog {
     pub week_start: String,
@@ -14,80 +19,419 @@ pub struct WeeklyLog {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_name: Option<String>,
     pub total_seconds: i64,
+    // JSON export only: seconds per tag, plus "Untagged" for entries without a known tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_totals: Option<BTreeMap<String, i64>>,
     pub entries: Vec<ExportEntry>,
 }
 
+/// Formats offered by the week export menu
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Ics,
+    Html,
+}
+
+/// Bucket in `WeeklyLog::tag_totals` for entries without a known tag
+pub const UNTAGGED: &str = "Untagged";
+
 #[derive(Serialize)]
-pub struct ExportEntry {
-    pub worklog_id: String,
-    pub issue_key: String,
-    pub issue_summary: String,
-    pub issue_type: String,
-    pub seconds: i64,
-    pub description: String,
-    pub date: String,
-    pub start_time: String,
-}
-
-impl From<&TimeEntry> for ExportEntry {
-    fn from(entry: &TimeEntry) -> Self {
-        Self {
-            worklog_id: entry.worklog_id.clone(),
-            issue_key: entry.issue_key.clone(),
-            issue_summary: entry.issue_summary.clone(),
-            issue_type: entry.issue_type.clone(),
-            seconds: entry.seconds,
-            description: entry.description.clone(),
-            date: entry.date.format("%Y-%m-%d").to_string(),
-            start_time: entry.start_time.clone(),
-        }
-    }
+pub struct RangeLog {
+    pub start_date: String,
+    pub end_date: String,
+    pub exported_at: String,
+    pub total_seconds: i64,
+    pub entries: Vec<ExportEntry>,
 }
 
 /// Export the current week's data to a JSON file
 /// Returns the path of the created file on success
 /// If user_name is provided, includes it in the filename and JSON
-pub fn export_week(week_data: &WeekData, user_name: Option<&str>) -> Result<PathBuf, String> {
-    // Get exe directory
-    let exe_path = std::env::current_exe()
-        .map_err(|e| format!("Failed to get exe path: {}", e))?;
-    let exe_dir = exe_path.parent()
-        .ok_or("Failed to get exe directory")?;
-
-    // Create weekly-logs directory
-    let logs_dir = exe_dir.join("weekly-logs");
-    fs::create_dir_all(&logs_dir)
-        .map_err(|e| format!("Failed to create weekly-logs directory: {}", e))?;
+/// If annotations are provided, each entry's private note is included
+/// Files go to `export_directory`, or the app's data directory when unset (see `logs_dir`)
+pub fn export_week(week_data: &WeekData, user_name: Option<&str>, tags: &[TagDefinition], annotations: Option<&HashMap<String, String>>, export_directory: Option<&str>) -> Result<PathBuf, String> {
+    let file_path = logs_dir(export_directory)?.join(week_filename(week_data, user_name, "json"));
+
+    let mut log = weekly_log(week_data, user_name);
+    log.tag_totals = Some(tag_totals(week_data, tags));
+    if let Some(annotations) = annotations {
+        attach_annotations(&mut log.entries, annotations);
+    }
+
+    // Write JSON file
+    let json = serde_json::to_string_pretty(&log)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(&file_path, json)
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(file_path)
+}
+
+/// Export the current week's data to a CSV file (RFC 4180), one row per entry
+/// Uses the same filename and private note handling as `export_week`
+pub fn export_week_csv(week_data: &WeekData, user_name: Option<&str>, annotations: Option<&HashMap<String, String>>, export_directory: Option<&str>) -> Result<PathBuf, String> {
+    let file_path = logs_dir(export_directory)?.join(week_filename(week_data, user_name, "csv"));
+
+    let mut log = weekly_log(week_data, user_name);
+    let mut header = vec!["worklog_id", "issue_key", "issue_summary", "issue_type", "seconds", "hours", "description", "date", "start_time"];
+    if let Some(annotations) = annotations {
+        attach_annotations(&mut log.entries, annotations);
+        header.push("private_note");
+    }
+
+    let mut csv = header.join(",");
+    csv.push_str("\r\n");
+    for entry in &log.entries {
+        let mut fields = vec![
+            csv_field(&entry.worklog_id),
+            csv_field(&entry.issue_key),
+            csv_field(&entry.issue_summary),
+            csv_field(&entry.issue_type),
+            entry.seconds.to_string(),
+            format!("{:.2}", entry.seconds as f64 / 3600.0),
+            csv_field(&entry.description),
+            entry.date.clone(),
+            entry.start_time.clone(),
+        ];
+        if annotations.is_some() {
+            fields.push(csv_field(entry.private_note.as_deref().unwrap_or_default()));
+        }
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+
+    fs::write(&file_path, csv)
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(file_path)
+}
+
+/// Quote a CSV field when it holds a comma, quote or line break (quotes are doubled)
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// The week as a Markdown table for pasting into chat, PRs or standup notes
+/// Rows are sorted by date, then start time
+pub fn week_markdown_table(week_data: &WeekData, time_format: TimeFormat, decimal_precision: u8) -> String {
+    let mut entries: Vec<&TimeEntry> = week_data.entries.iter().collect();
+    entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.start_time.cmp(&b.start_time)));
+
+    let mut table = String::from("| Date | Issue | Summary | Duration | Description |\n|---|---|---|---|---|\n");
+    for entry in entries {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            entry.date.format("%a %Y-%m-%d"),
+            markdown_cell(&entry.issue_key),
+            markdown_cell(&entry.issue_summary),
+            format_duration_with_format(entry.seconds, time_format, decimal_precision),
+            markdown_cell(&entry.description),
+        ));
+    }
+    table
+}
 
+/// Keep a value inside its table cell: escape pipes and flatten line breaks
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+        .split(['\r', '\n'])
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// YYYY-Www[-name].ext for a week's export, with the user name sanitized
+fn week_filename(week_data: &WeekData, user_name: Option<&str>, extension: &str) -> String {
     // Calculate ISO week number
-    let week_start = week_data.week_start;
-    let week_end = week_start + Duration::days(6);
-    let iso_week = week_start.iso_week();
+    let iso_week = week_data.week_start.iso_week();
 
     // Build filename - include user name if provided
-    let filename = if let Some(name) = user_name {
+    if let Some(name) = user_name {
         // Sanitize name for filename (replace spaces with dashes, lowercase)
         let safe_name: String = name.chars()
             .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
             .collect();
-        format!("{}-W{:02}-{}.json", iso_week.year(), iso_week.week(), safe_name)
+        format!("{}-W{:02}-{}.{}", iso_week.year(), iso_week.week(), safe_name, extension)
     } else {
-        format!("{}-W{:02}.json", iso_week.year(), iso_week.week())
-    };
-    let file_path = logs_dir.join(&filename);
+        format!("{}-W{:02}.{}", iso_week.year(), iso_week.week(), extension)
+    }
+}
 
-    // Build the log structure
-    let total_seconds: i64 = week_data.entries.iter().map(|e| e.seconds).sum();
-    let log = WeeklyLog {
+/// Build the log structure for a week (without `tag_totals`, which only the JSON export fills in)
+pub fn weekly_log(week_data: &WeekData, user_name: Option<&str>) -> WeeklyLog {
+    let week_start = week_data.week_start;
+    let week_end = week_start + Duration::days(6);
+    WeeklyLog {
         week_start: week_start.format("%Y-%m-%d").to_string(),
         week_end: week_end.format("%Y-%m-%d").to_string(),
         exported_at: Local::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
         user_name: user_name.map(String::from),
-        total_seconds,
+        total_seconds: week_data.total_seconds(),
+        tag_totals: None,
         entries: week_data.entries.iter().map(ExportEntry::from).collect(),
+    }
+}
+
+/// Seconds per configured tag (read from description prefixes as in `WeekData::tag_totals`),
+/// plus an `UNTAGGED` bucket for entries without a known tag
+fn tag_totals(week_data: &WeekData, tags: &[TagDefinition]) -> BTreeMap<String, i64> {
+    let mut totals: BTreeMap<String, i64> = week_data.tag_totals(tags).into_iter().collect();
+    let untagged = week_data.untagged_seconds(tags);
+    if untagged > 0 {
+        *totals.entry(UNTAGGED.to_string()).or_default() += untagged;
+    }
+    totals
+}
+
+/// Styles for `entries_table_html`, shared by the emailed log and the exported report
+const TABLE_CSS: &str = "\
+     table { border-collapse: collapse; width: 100%; margin-top: 20px; }\n\
+     td, th { padding: 4px 10px; text-align: left; vertical-align: top; }\n\
+     tr.day th { border-top: 1px solid #ccc; padding-top: 14px; }\n\
+     tr.subtotal td { color: #666; border-top: 1px dashed #ddd; }\n\
+     tr.total td { font-weight: bold; border-top: 2px solid #222; padding-top: 8px; }\n\
+     .key { white-space: nowrap; }\n\
+     .swatch { display: inline-block; width: 10px; height: 10px; border-radius: 2px; margin-right: 6px; }\n\
+     .num { text-align: right; white-space: nowrap; }\n";
+
+/// The log's entries by date, then start time
+fn sorted_entries(log: &WeeklyLog) -> Vec<&ExportEntry> {
+    let mut entries: Vec<&ExportEntry> = log.entries.iter().collect();
+    entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.start_time.cmp(&b.start_time)));
+    entries
+}
+
+/// "Name: week of YYYY-MM-DD", escaped for HTML
+fn report_title(log: &WeeklyLog) -> String {
+    match &log.user_name {
+        Some(name) => format!("{}: week of {}", html_escape(name), log.week_start),
+        None => format!("Week of {}", log.week_start),
+    }
+}
+
+/// Table of `entries` (sorted, see `sorted_entries`) grouped by day, with a total row per day and for the week
+fn entries_table_html(entries: &[&ExportEntry], issue_type_colors: &HashMap<String, [u8; 3]>, time_format: TimeFormat, decimal_precision: u8) -> String {
+    let duration = |seconds: i64| format_duration_with_format(seconds, time_format, decimal_precision);
+
+    let mut rows = String::new();
+    for (i, entry) in entries.iter().enumerate() {
+        if i == 0 || entries[i - 1].date != entry.date {
+            rows.push_str(&format!(
+                "<tr class=\"day\"><th colspan=\"5\">{}</th></tr>\n",
+                html_escape(&day_heading(&entry.date, "%A, %b %-d"))
+            ));
+        }
+        let [r, g, b] = issue_type_color(&entry.issue_type, issue_type_colors);
+        rows.push_str(&format!(
+            "<tr><td>{}</td><td class=\"key\"><span class=\"swatch\" style=\"background:#{:02x}{:02x}{:02x}\"></span>{}</td><td>{}</td><td>{}</td><td class=\"num\">{}</td></tr>\n",
+            html_escape(&entry.start_time),
+            r, g, b,
+            html_escape(&entry.issue_key),
+            html_escape(&entry.issue_summary),
+            html_escape(entry.description.trim()),
+            duration(entry.seconds)
+        ));
+        if entries.get(i + 1).is_none_or(|next| next.date != entry.date) {
+            let day_total: i64 = entries.iter().filter(|e| e.date == entry.date).map(|e| e.seconds).sum();
+            rows.push_str(&format!(
+                "<tr class=\"subtotal\"><td colspan=\"4\">Day total</td><td class=\"num\">{}</td></tr>\n",
+                duration(day_total)
+            ));
+        }
+    }
+    let total: i64 = entries.iter().map(|e| e.seconds).sum();
+
+    format!(
+        "<table>\n{rows}<tr class=\"total\"><td colspan=\"4\">Week total</td><td class=\"num\">{}</td></tr>\n</table>\n",
+        duration(total)
+    )
+}
+
+/// Render a weekly log as a standalone HTML document for email: the entry table without the chart
+pub fn weekly_log_html(log: &WeeklyLog, issue_type_colors: &HashMap<String, [u8; 3]>, time_format: TimeFormat, decimal_precision: u8) -> String {
+    let title = report_title(log);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n\
+         body {{ font-family: sans-serif; color: #222; }}\n{TABLE_CSS}\
+         </style>\n</head>\n<body>\n<h2>{title}</h2>\n<p>{} to {} &middot; {} total</p>\n{}</body>\n</html>\n",
+        log.week_start,
+        log.week_end,
+        format_duration_with_format(log.total_seconds, time_format, decimal_precision),
+        entries_table_html(&sorted_entries(log), issue_type_colors, time_format, decimal_precision),
+    )
+}
+
+/// Escape text for HTML element content
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Export the current week as a self-contained HTML timesheet (see `weekly_report_html`)
+/// Uses the same filename as `export_week`, with an .html extension
+pub fn export_week_html(week_data: &WeekData, user_name: Option<&str>, issue_type_colors: &HashMap<String, [u8; 3]>, time_format: TimeFormat, decimal_precision: u8, export_directory: Option<&str>) -> Result<PathBuf, String> {
+    let file_path = logs_dir(export_directory)?.join(week_filename(week_data, user_name, "html"));
+
+    let log = weekly_log(week_data, user_name);
+    fs::write(&file_path, weekly_report_html(&log, issue_type_colors, time_format, decimal_precision))
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(file_path)
+}
+
+/// Printable weekly report: branded header, entries grouped by day with a total row per day,
+/// a grand total, and an inline SVG chart of each day's time stacked by issue type
+/// Everything is inline so the file opens (and prints) in any browser on its own
+pub fn weekly_report_html(log: &WeeklyLog, issue_type_colors: &HashMap<String, [u8; 3]>, time_format: TimeFormat, decimal_precision: u8) -> String {
+    let entries = sorted_entries(log);
+    let mut days: Vec<&str> = entries.iter().map(|e| e.date.as_str()).collect();
+    days.dedup();
+
+    let title = report_title(log);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Timebox - {title}</title>\n<style>\n\
+         body {{ font-family: -apple-system, 'Segoe UI', sans-serif; color: #222; margin: 32px; }}\n\
+         header {{ border-bottom: 3px solid #1398f4; padding-bottom: 8px; margin-bottom: 20px; }}\n\
+         .brand {{ color: #1398f4; font-weight: bold; letter-spacing: 0.05em; text-transform: uppercase; font-size: 12px; }}\n\
+         h1 {{ margin: 4px 0; font-size: 22px; }}\n\
+         .range {{ color: #666; }}\n\
+         svg {{ display: block; margin-bottom: 8px; }}\n\
+         .legend span {{ margin-right: 14px; font-size: 12px; }}\n{TABLE_CSS}\
+         @media print {{ body {{ margin: 0; }} tr {{ break-inside: avoid; }} }}\n\
+         </style>\n</head>\n<body>\n<header>\n<div class=\"brand\">Timebox</div>\n<h1>{title}</h1>\n\
+         <div class=\"range\">{} to {} &middot; {} total</div>\n</header>\n{}\n{}\
+         <p class=\"range\">Exported {}</p>\n</body>\n</html>\n",
+        log.week_start,
+        log.week_end,
+        format_duration_with_format(log.total_seconds, time_format, decimal_precision),
+        week_chart_svg(&entries, &days, issue_type_colors, time_format, decimal_precision),
+        entries_table_html(&entries, issue_type_colors, time_format, decimal_precision),
+        log.exported_at.replace('T', " "),
+    )
+}
+
+/// A day's date formatted for display, or the raw string if it doesn't parse
+fn day_heading(date: &str, format: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|d| d.format(format).to_string())
+        .unwrap_or_else(|_| date.to_string())
+}
+
+/// Stacked bar chart, one bar per logged day, with a segment per issue type and a legend below
+fn week_chart_svg(entries: &[&ExportEntry], days: &[&str], issue_type_colors: &HashMap<String, [u8; 3]>, time_format: TimeFormat, decimal_precision: u8) -> String {
+    const BAR_WIDTH: f64 = 48.0;
+    const GAP: f64 = 24.0;
+    const CHART_HEIGHT: f64 = 160.0;
+    const LABEL_HEIGHT: f64 = 36.0;
+
+    if days.is_empty() {
+        return String::new();
+    }
+
+    // Issue types in a stable order: the built-in ones first, then others as they appear
+    let mut issue_types: Vec<String> = DEFAULT_ISSUE_TYPE_COLORS.iter()
+        .map(|(name, _)| name.to_string())
+        .filter(|name| entries.iter().any(|e| e.issue_type.eq_ignore_ascii_case(name)))
+        .collect();
+    for entry in entries {
+        if !issue_types.iter().any(|t| t.eq_ignore_ascii_case(&entry.issue_type)) {
+            issue_types.push(entry.issue_type.clone());
+        }
+    }
+
+    let duration = |seconds: i64| format_duration_with_format(seconds, time_format, decimal_precision);
+    let day_seconds = |day: &str, issue_type: Option<&str>| -> i64 {
+        entries.iter()
+            .filter(|e| e.date == day && issue_type.is_none_or(|t| e.issue_type.eq_ignore_ascii_case(t)))
+            .map(|e| e.seconds)
+            .sum()
     };
+    let max_seconds = days.iter().map(|day| day_seconds(day, None)).max().unwrap_or(0).max(3600) as f64;
+
+    let width = days.len() as f64 * (BAR_WIDTH + GAP) + GAP;
+    let mut shapes = String::new();
+    for (i, day) in days.iter().enumerate() {
+        let x = GAP + i as f64 * (BAR_WIDTH + GAP);
+        let mut y = CHART_HEIGHT;
+        for issue_type in &issue_types {
+            let seconds = day_seconds(day, Some(issue_type));
+            if seconds == 0 {
+                continue;
+            }
+            let height = seconds as f64 / max_seconds * CHART_HEIGHT;
+            y -= height;
+            let [r, g, b] = issue_type_color(issue_type, issue_type_colors);
+            shapes.push_str(&format!(
+                "<rect x=\"{x:.1}\" y=\"{y:.1}\" width=\"{BAR_WIDTH}\" height=\"{height:.1}\" fill=\"#{r:02x}{g:02x}{b:02x}\"><title>{}: {}</title></rect>\n",
+                html_escape(issue_type),
+                duration(seconds)
+            ));
+        }
+        let center = x + BAR_WIDTH / 2.0;
+        shapes.push_str(&format!(
+            "<text x=\"{center:.1}\" y=\"{:.1}\" text-anchor=\"middle\" font-size=\"11\" fill=\"#222\">{}</text>\n\
+             <text x=\"{center:.1}\" y=\"{:.1}\" text-anchor=\"middle\" font-size=\"12\" fill=\"#666\">{}</text>\n",
+            (y - 4.0).max(10.0),
+            duration(day_seconds(day, None)),
+            CHART_HEIGHT + 18.0,
+            html_escape(&day_heading(day, "%a %-d"))
+        ));
+    }
+
+    let legend: String = issue_types.iter()
+        .map(|issue_type| {
+            let [r, g, b] = issue_type_color(issue_type, issue_type_colors);
+            format!(
+                "<span><span class=\"swatch\" style=\"background:#{r:02x}{g:02x}{b:02x}\"></span>{}</span>",
+                html_escape(issue_type)
+            )
+        })
+        .collect();
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width:.0}\" height=\"{:.0}\" viewBox=\"0 0 {width:.0} {:.0}\">\n\
+         <line x1=\"0\" y1=\"{CHART_HEIGHT}\" x2=\"{width:.0}\" y2=\"{CHART_HEIGHT}\" stroke=\"#ccc\"/>\n{shapes}</svg>\n\
+         <div class=\"legend\">{legend}</div>",
+        CHART_HEIGHT + LABEL_HEIGHT,
+        CHART_HEIGHT + LABEL_HEIGHT,
+    )
+}
+
+/// Export entries between two dates (inclusive) to timebox_YYYY-MM-DD_YYYY-MM-DD.json
+/// Returns the path of the created file on success
+pub fn export_range(entries: &[TimeEntry], start_date: NaiveDate, end_date: NaiveDate, annotations: Option<&HashMap<String, String>>, export_directory: Option<&str>) -> Result<PathBuf, String> {
+    let logs_dir = logs_dir(export_directory)?;
+    let filename = format!(
+        "timebox_{}_{}.json",
+        start_date.format("%Y-%m-%d"),
+        end_date.format("%Y-%m-%d")
+    );
+    let file_path = logs_dir.join(&filename);
+
+    let mut entries: Vec<&TimeEntry> = entries.iter()
+        .filter(|e| e.date >= start_date && e.date <= end_date)
+        .collect();
+    entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.start_time.cmp(&b.start_time)));
+
+    let mut log = RangeLog {
+        start_date: start_date.format("%Y-%m-%d").to_string(),
+        end_date: end_date.format("%Y-%m-%d").to_string(),
+        exported_at: Local::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
+        total_seconds: entries.iter().map(|e| e.seconds).sum(),
+        entries: entries.into_iter().map(ExportEntry::from).collect(),
+    };
+    if let Some(annotations) = annotations {
+        attach_annotations(&mut log.entries, annotations);
+    }
 
-    // Write JSON file
     let json = serde_json::to_string_pretty(&log)
         .map_err(|e| format!("Failed to serialize: {}", e))?;
     fs::write(&file_path, json)
@@ -95,3 +439,210 @@ pub fn export_week(week_data: &WeekData, user_name: Option<&str>) -> Result<Path
 
     Ok(file_path)
 }
+
+/// Fill in each entry's private note from the local annotations
+fn attach_annotations(entries: &mut [ExportEntry], annotations: &HashMap<String, String>) {
+    for entry in entries {
+        entry.private_note = annotations.get(&entry.worklog_id).cloned();
+    }
+}
+
+/// Where exports go when no folder is configured: an `exports` folder in the app's data directory
+/// (the executable's own folder is often read-only, e.g. /Applications or Program Files)
+pub fn default_export_directory() -> Option<PathBuf> {
+    ProjectDirs::from("com", "tzankich", "timebox").map(|dirs| dirs.data_dir().join("exports"))
+}
+
+/// The configured export folder, or `default_export_directory` (created if missing)
+fn logs_dir(export_directory: Option<&str>) -> Result<PathBuf, String> {
+    let logs_dir = match export_directory.map(str::trim).filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => default_export_directory().ok_or("Could not determine data directory")?,
+    };
+    fs::create_dir_all(&logs_dir)
+        .map_err(|e| format!("Failed to create export directory: {}", e))?;
+
+    Ok(logs_dir)
+}
+
+/// Build a VEVENT block for a single entry (local floating time, CRLF line endings)
+fn ics_event(entry: &TimeEntry) -> String {
+    let start_time = NaiveTime::parse_from_str(&entry.start_time, "%H:%M")
+        .unwrap_or_else(|_| NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default());
+    let start = entry.date.and_time(start_time);
+    let end = start + Duration::seconds(entry.seconds);
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@timebox", entry.worklog_id),
+        format!("DTSTAMP:{}", chrono::Utc::now().format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART:{}", start.format("%Y%m%dT%H%M%S")),
+        format!("DTEND:{}", end.format("%Y%m%dT%H%M%S")),
+        format!("SUMMARY:{}", ics_escape(&format!("[{}] {}", entry.issue_key, entry.issue_summary))),
+    ];
+    if !entry.description.trim().is_empty() {
+        lines.push(format!("DESCRIPTION:{}", ics_escape(entry.description.trim())));
+    }
+    lines.push("END:VEVENT".to_string());
+    lines.iter().map(|line| ics_fold(line)).collect::<Vec<_>>().join("\r\n")
+}
+
+/// Fold a content line at 75 octets per RFC 5545, continuing with a leading space
+/// Never splits a multi-byte character
+fn ics_fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
+
+/// Escape text values per RFC 5545 (backslash, comma, semicolon, newline)
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Wrap VEVENT blocks in a VCALENDAR
+fn ics_calendar(events: &[String]) -> String {
+    format!(
+        "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//timebox//timebox//EN\r\n{}\r\nEND:VCALENDAR\r\n",
+        events.join("\r\n")
+    )
+}
+
+/// Export the current week's entries to an iCalendar (.ics) file, one event per entry
+/// Uses the same filename as `export_week`, for importing into Google Calendar or Outlook
+pub fn export_week_ics(week_data: &WeekData, user_name: Option<&str>, export_directory: Option<&str>) -> Result<PathBuf, String> {
+    let file_path = logs_dir(export_directory)?.join(week_filename(week_data, user_name, "ics"));
+
+    let events: Vec<String> = week_data.entries.iter().map(ics_event).collect();
+    fs::write(&file_path, ics_calendar(&events))
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(file_path)
+}
+
+/// Write a single entry as an .ics file in the temp directory
+/// Returns the path so the caller can open it with the system calendar
+pub fn export_entry_ics(entry: &TimeEntry) -> Result<PathBuf, String> {
+    let file_path = std::env::temp_dir().join(format!("timebox_entry_{}.ics", entry.worklog_id));
+    fs::write(&file_path, ics_calendar(&[ics_event(entry)]))
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+    Ok(file_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export_entry(date: &str, start_time: &str, issue_type: &str, seconds: i64) -> ExportEntry {
+        ExportEntry {
+            worklog_id: format!("{}-{}", date, start_time),
+            issue_key: "PROJ-1".to_string(),
+            issue_summary: "Summary".to_string(),
+            issue_type: issue_type.to_string(),
+            seconds,
+            description: String::new(),
+            date: date.to_string(),
+            start_time: start_time.to_string(),
+            private_note: None,
+        }
+    }
+
+    fn week_log(entries: Vec<ExportEntry>) -> WeeklyLog {
+        WeeklyLog {
+            week_start: "2026-10-12".to_string(),
+            week_end: "2026-10-18".to_string(),
+            exported_at: "2026-10-14T12:00:00".to_string(),
+            user_name: None,
+            total_seconds: entries.iter().map(|e| e.seconds).sum(),
+            tag_totals: None,
+            entries,
+        }
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("Fixed login"), "Fixed login");
+        assert_eq!(csv_field("Login, signup"), "\"Login, signup\"");
+        assert_eq!(csv_field("The \"new\" flow"), "\"The \"\"new\"\" flow\"");
+        assert_eq!(csv_field("Line one\nLine two"), "\"Line one\nLine two\"");
+    }
+
+    #[test]
+    fn tag_totals_are_left_out_unless_filled_in() {
+        let mut log = week_log(vec![export_entry("2026-10-12", "09:00", "Task", 3600)]);
+        let json = serde_json::to_value(&log).unwrap();
+        assert!(json.get("tag_totals").is_none());
+
+        log.tag_totals = Some(BTreeMap::from([(UNTAGGED.to_string(), 600), ("FE".to_string(), 3000)]));
+        let json = serde_json::to_string(&log).unwrap();
+        // Sorted by name, so exports of the same week diff cleanly
+        assert!(json.contains(r#""tag_totals":{"FE":3000,"Untagged":600}"#));
+    }
+
+    #[test]
+    fn report_sorts_entries_itself() {
+        let log = week_log(vec![
+            export_entry("2026-10-13", "09:00", "Task", 3600),
+            export_entry("2026-10-12", "14:00", "Task", 3600),
+            export_entry("2026-10-12", "09:00", "Task", 3600),
+        ]);
+        let html = weekly_report_html(&log, &HashMap::new(), TimeFormat::HoursMinutes, 2);
+        // One group per day, even though the days weren't contiguous in the log
+        assert_eq!(html.matches("Day total").count(), 2);
+        let monday = html.find("Monday").unwrap();
+        let tuesday = html.find("Tuesday").unwrap();
+        assert!(monday < tuesday);
+        assert!(html[monday..].find("09:00").unwrap() < html[monday..].find("14:00").unwrap());
+    }
+
+    #[test]
+    fn report_uses_configured_colors_and_time_format() {
+        let log = week_log(vec![export_entry("2026-10-12", "09:00", "Bug", 5400)]);
+        let colors = HashMap::from([("bug".to_string(), [1, 2, 3])]);
+        let html = weekly_report_html(&log, &colors, TimeFormat::Decimal, 2);
+        assert!(html.contains("#010203"));
+        assert!(html.contains("1.5h"));
+        assert!(!html.contains("1h 30m"));
+
+        let mail = weekly_log_html(&log, &colors, TimeFormat::Decimal, 2);
+        assert!(mail.contains("#010203"));
+        assert!(mail.contains("1.5h"));
+    }
+
+    #[test]
+    fn short_ics_lines_are_not_folded() {
+        assert_eq!(ics_fold("SUMMARY:[PROJ-1] Review"), "SUMMARY:[PROJ-1] Review");
+        let exact = "X".repeat(75);
+        assert_eq!(ics_fold(&exact), exact);
+    }
+
+    #[test]
+    fn long_ics_lines_fold_at_75_octets() {
+        let line = format!("DESCRIPTION:{}", "a".repeat(200));
+        let folded = ics_fold(&line);
+        let parts: Vec<&str> = folded.split("\r\n").collect();
+        assert!(parts.len() > 1);
+        assert!(parts.iter().all(|part| part.len() <= 75));
+        assert!(parts[1..].iter().all(|part| part.starts_with(' ')));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn ics_folding_keeps_multibyte_characters_whole() {
+        let line = format!("SUMMARY:{}", "\u{e9}\u{1f600}".repeat(30));
+        let folded = ics_fold(&line);
+        assert!(folded.split("\r\n").all(|part| part.len() <= 75));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
@@ -0,0 +1,6 @@
+pub use timebox_core::{api, config};
+pub mod export;
+mod mail;
+mod summary;
+pub mod ui;
+mod update;
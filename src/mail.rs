@@ -0,0 +1,55 @@
+use anyhow::{Context, Result};
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::time::Duration;
+
+use crate::config::SmtpConfig;
+
+/// Port where the server expects TLS from the first byte (SMTPS)
+const IMPLICIT_TLS_PORT: u16 = 465;
+
+/// Give up on a server that stops answering
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Send an HTML email to every configured recipient
+/// Returns the number of recipients it was sent to
+pub async fn send_html(smtp: &SmtpConfig, subject: &str, html: &str) -> Result<usize> {
+    if smtp.to_addresses.is_empty() {
+        anyhow::bail!("No recipients configured");
+    }
+
+    let from: Mailbox = smtp.from_address.parse()
+        .with_context(|| format!("Invalid sender address: {}", smtp.from_address))?;
+    let mut builder = Message::builder().from(from).subject(subject);
+    for to in &smtp.to_addresses {
+        let mailbox: Mailbox = to.parse().with_context(|| format!("Invalid recipient address: {}", to))?;
+        builder = builder.to(mailbox);
+    }
+    let message = builder
+        .header(ContentType::TEXT_HTML)
+        .body(html.to_string())
+        .context("Could not build the email")?;
+
+    // Port 465 is TLS from the start; anything else must upgrade with STARTTLS before logging in
+    let transport = if smtp.port == IMPLICIT_TLS_PORT {
+        AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp.host)
+    } else {
+        AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)
+    }
+    .with_context(|| format!("Could not set up TLS for {}", smtp.host))?
+    .port(smtp.port)
+    .timeout(Some(SMTP_TIMEOUT));
+    let transport = match &smtp.password {
+        Some(password) => transport.credentials(Credentials::new(smtp.username.clone(), password.clone())),
+        None => transport,
+    };
+
+    transport.build()
+        .send(message)
+        .await
+        .with_context(|| format!("Could not send through {}:{}", smtp.host, smtp.port))?;
+
+    Ok(smtp.to_addresses.len())
+}
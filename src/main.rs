@@ -1,12 +1,8 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod api;
-mod config;
-mod export;
-mod ui;
-mod update;
-
 use eframe::egui;
+use timebox::config::Config;
+use timebox::ui;
 
 fn load_icon() -> Option<egui::IconData> {
     // Icon is embedded in the binary at compile time
@@ -27,6 +23,13 @@ fn main() -> eframe::Result<()> {
         .with_min_inner_size([900.0, 700.0])
         .with_title("Timebox");
 
+    // Reopen where the window was last left
+    if let Some(state) = Config::load().ok().and_then(|config| config.window_state) {
+        viewport = viewport
+            .with_inner_size([state.width, state.height])
+            .with_position([state.x, state.y]);
+    }
+
     if let Some(icon) = load_icon() {
         viewport = viewport.with_icon(std::sync::Arc::new(icon));
     }
@@ -0,0 +1,123 @@
+use anyhow::{Context, Result};
+use reqwest::{Client, Url};
+
+use crate::api::format_duration;
+use crate::ui::WeekData;
+
+/// Only loopback endpoints are allowed so worklog text never leaves the machine
+pub fn is_local_endpoint(endpoint: &str) -> bool {
+    let Ok(url) = Url::parse(endpoint) else {
+        return false;
+    };
+    matches!(url.host_str(), Some("localhost") | Some("127.0.0.1") | Some("[::1]") | Some("::1"))
+}
+
+/// Build the prompt from the week's entries - issue keys, durations and descriptions only
+/// (no names, emails or issue summaries)
+pub fn build_prompt(week_data: &WeekData) -> String {
+    let mut lines = Vec::new();
+    for day in week_data.all_days() {
+        for entry in week_data.entries_for_day(day) {
+            let description = entry.description.replace('\n', " ");
+            lines.push(format!(
+                "- {} {} ({}): {}",
+                day.format("%a"),
+                entry.issue_key,
+                format_duration(entry.seconds),
+                description.trim()
+            ));
+        }
+    }
+    format!("Summarize this week's work in 3 bullet points:\n{}", lines.join("\n"))
+}
+
+/// Send the prompt to a self-hosted generate endpoint (Ollama-style) and stream the text back
+/// Accepts newline-delimited JSON or SSE "data:" lines; `on_chunk` receives each text fragment
+pub async fn stream_summary(
+    endpoint: &str,
+    model: &str,
+    prompt: &str,
+    mut on_chunk: impl FnMut(String),
+) -> Result<()> {
+    if !is_local_endpoint(endpoint) {
+        anyhow::bail!("Summary endpoint must be on localhost");
+    }
+
+    let body = serde_json::json!({
+        "model": model,
+        "prompt": prompt,
+        "stream": true,
+    });
+
+    let mut response = Client::new()
+        .post(endpoint)
+        .json(&body)
+        .send()
+        .await
+        .context("Summary endpoint unreachable")?;
+
+    if !response.status().is_success() {
+        anyhow::bail!("Summary request failed: {}", response.status());
+    }
+
+    let mut buffer: Vec<u8> = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        buffer.extend_from_slice(&chunk);
+        while let Some(newline) = buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=newline).collect();
+            if let Some(text) = parse_stream_line(&String::from_utf8_lossy(&line)) {
+                on_chunk(text);
+            }
+        }
+    }
+    if let Some(text) = parse_stream_line(&String::from_utf8_lossy(&buffer)) {
+        on_chunk(text);
+    }
+
+    Ok(())
+}
+
+/// Extract the text fragment from one streamed line
+/// Supports Ollama ("response") and OpenAI-compatible ("choices[0].delta.content" / "choices[0].text")
+fn parse_stream_line(line: &str) -> Option<String> {
+    let line = line.trim();
+    let line = line.strip_prefix("data:").map(str::trim).unwrap_or(line);
+    if line.is_empty() || line == "[DONE]" {
+        return None;
+    }
+
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    let text = value.get("response")
+        .or_else(|| value.pointer("/choices/0/delta/content"))
+        .or_else(|| value.pointer("/choices/0/text"))
+        .or_else(|| value.pointer("/message/content"))?
+        .as_str()?;
+
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_lines_yield_their_text() {
+        assert_eq!(parse_stream_line(r#"data: {"choices":[{"delta":{"content":"Fixed"}}]}"#).as_deref(), Some("Fixed"));
+        assert_eq!(parse_stream_line(r#"{"response":" login","done":false}"#).as_deref(), Some(" login"));
+        assert_eq!(parse_stream_line(r#"{"message":{"content":"bugs"}}"#).as_deref(), Some("bugs"));
+        assert_eq!(parse_stream_line("data: [DONE]"), None);
+        assert_eq!(parse_stream_line(""), None);
+        assert_eq!(parse_stream_line("data: {not json"), None);
+        assert_eq!(parse_stream_line(r#"{"response":"","done":true}"#), None);
+    }
+
+    #[test]
+    fn only_loopback_endpoints_are_local() {
+        assert!(is_local_endpoint("http://localhost:11434/api/generate"));
+        assert!(is_local_endpoint("http://127.0.0.1:8080/v1/completions"));
+        assert!(is_local_endpoint("http://[::1]:11434/api/generate"));
+        assert!(!is_local_endpoint("https://api.example.com/v1/chat/completions"));
+        assert!(!is_local_endpoint("http://localhost.example.com/api"));
+        assert!(!is_local_endpoint("not a url"));
+    }
+}
@@ -1,15 +1,19 @@
-use chrono::{Duration, Local, NaiveDate};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Weekday};
 use eframe::egui;
 use egui::{Color32, RichText};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::time::Instant;
+use timebox_core::week_start;
 
-use crate::api::{JiraClient, TimeEntry, Issue, parse_duration, format_duration_with_format, extract_time, parse_date};
-use crate::config::{Config, TimeFormat, ClockFormat, ListViewMode, ViewMode};
-use crate::export;
+use crate::api::{JiraClient, TimeEntry, Issue, Sprint, parse_duration, format_duration_with_format, normalize_issue_key, extract_time, parse_date, parse_start_time};
+use crate::config::{default_issue_type_color, issue_type_color, parse_categories_from_description, Annotations, AuthMethod, CachedSprint, Config, ConfigProfile, PendingOperation, PendingQueue, RemoteConfig, WindowState, DEFAULT_ISSUE_TYPE_COLORS, TimeFormat, ClockFormat, ColorblindMode, LinkBehavior, ListViewMode, QuickAction, QuickActionType, TagAutoRule, TagDefinition, ViewMode, UpdateChannel, WeekendDisplay};
+use crate::export::{self, ExportFormat};
+use crate::mail;
+use crate::summary;
 use crate::update::{self, UpdateInfo};
-use super::views::{self, week_start, WeekData};
+use super::views::{self, CachedWeek, WeekData};
+use super::theme::BucketKind;
 
 pub struct JiraTimeApp {
     config: Config,
@@ -20,6 +24,7 @@ pub struct JiraTimeApp {
 
     // Data - now using week-based caching
     week_data: WeekData,
+    week_cache: HashMap<NaiveDate, CachedWeek>,  // Weeks fetched this session with when they arrived, keyed by week start
     time_buckets: Vec<Issue>,
 
     // Weekly bucket tickets (Meeting, Support, Admin) - cached per week
@@ -31,29 +36,46 @@ pub struct JiraTimeApp {
     show_dialog: bool,
     dialog_mode: DialogMode,
     dialog_hours: String,
+    dialog_timer: TimerState,
     dialog_issue: String,
     dialog_description: String,
+    dialog_description_preview: bool,  // Show the description rendered instead of the text area
     dialog_worklog_id: String,
+    dialog_worklog_date: NaiveDate,  // Date of the worklog being edited (may be outside the displayed week)
     dialog_start_time: String,
     dialog_categories: Vec<bool>,  // Multi-select category tags
+    dialog_draft: Option<DialogDraft>,  // Cancelled add dialog input, offered on the next open
+    dialog_opened_input: Option<DialogDraft>,  // What the add dialog showed when it opened, incl. pre-fills
 
     // Form validation errors (true = has error)
     error_issue: bool,
     error_hours: bool,
+    error_start_time: bool,
 
     // Issue autocomplete
     issue_suggestions: Vec<Issue>,
     show_suggestions: bool,
+    autocomplete_selection: Option<usize>,  // Keyboard-highlighted row among the visible suggestions
     last_issue_search: String,
     last_search_time: Instant,
     searching_issues: bool,
-    validated_issue: Option<(String, String, String)>,  // (issue key, issue summary, issue type)
-
-    // Dialog accent color (for TIM tickets)
+    validated_issue: Option<ValidatedIssue>,
+    suggestion_project_filter: Option<String>,  // Project key chip selected in the dropdown
+    issue_from_clipboard: bool,  // Issue key was pre-filled from the clipboard
+    recent_issues: VecDeque<(String, String, String)>,  // Last issues logged to (key, summary, type), newest first
+    issue_from_draft: bool,  // Issue key was restored from a draft, validated once search confirms it
+    focus_duration_pending: bool,  // Move focus to the duration field on the next frame
+    focus_issue_pending: bool,     // Move focus to the issue field on the next frame
+
+    // Dialog accent color (for bucket tickets)
     dialog_accent_color: Option<Color32>,
 
     // Delete confirmation
     pending_delete: Option<TimeEntry>,
+    inline_edit_state: Option<(String, String)>,  // (worklog_id, edited_text) for inline duration edits
+    new_entry_worklog_ids: HashMap<String, Instant>,  // Just-added entries fading in, with when they were added
+    schedule_hover_started: HashMap<String, Instant>,  // Schedule block under the pointer, for the delayed tooltip
+    team_entries: Vec<(String, Vec<TimeEntry>)>,  // Team members' worklogs for the displayed week (display name, entries)
     show_delete_confirm: bool,
 
     // Schedule reschedule dialog
@@ -63,20 +85,106 @@ pub struct JiraTimeApp {
     reschedule_time: String,
     reschedule_duration: String,
     reschedule_is_clone: bool,  // true = clone to new day, false = move
+    schedule_view_entered: bool,  // Initial scroll to the current time has been applied
+
+    // Weekly summary from a self-hosted model
+    show_summary: bool,
+    summary_text: String,
+    summary_streaming: bool,
+    summary_unavailable: bool,  // Endpoint failed - hide the button for this session
+
+    // Weekly report email
+    report_sending: bool,
+
+    // In-app issue details (issue key), opened from issue key links with LinkBehavior::OpenInApp
+    issue_details: Option<String>,
+    // All of our worklogs on the details issue, by issue key
+    issue_history: Option<(String, IssueHistory)>,
+
+    // Issue key -> whether its group is open in the issue-grouped list (missing = open)
+    expanded_issue_groups: HashMap<String, bool>,
+
+    // Private notes (annotations.json), and the note being edited as (worklog_id, text)
+    annotations: Annotations,
+    annotation_edit: Option<(String, String)>,
+
+    // Ctrl+Z history; `undoing` keeps the reversal itself off the stack
+    undo_stack: UndoStack,
+    undoing: bool,
+
+    // Changes made while offline (pending/<site>.json beside the config), replayed once Jira answers again
+    pending: PendingQueue,
+    replaying_pending: bool,
+
+    // Custom date range export
+    show_export_range: bool,
+    export_range_start: String,
+    export_range_end: String,
+    export_range_loading: bool,
+    export_range_preview: Option<(NaiveDate, NaiveDate, Vec<TimeEntry>)>,  // (start, end, entries) fetched for the range
+
+    // Month view: our worklogs for a month (by its first day), and the month last asked for
+    // (None again once it's stale); a month that failed to load waits for a refresh to retry
+    month_data: Option<(NaiveDate, Vec<TimeEntry>)>,
+    month_requested: Option<NaiveDate>,
+    month_loading: bool,
+    month_failed: Option<NaiveDate>,
+
+    show_shortcuts: bool,  // Keyboard shortcut overlay
+
+    window_state_pending: Option<(WindowState, Instant)>,  // Placement waiting out WINDOW_STATE_SAVE_DELAY
+
+    week_slide: Option<WeekSlide>,  // Slide animation between weeks
+
+    // Browser-style week navigation history
+    navigation_history: VecDeque<NaiveDate>,  // Previously viewed weeks (most recent last)
+    navigation_forward: Vec<NaiveDate>,       // Weeks left via "back" (most recent last)
+
+    // Jira profiles: index of the one in use, and the settings dialog's working copy
+    active_profile_index: usize,
+    settings_profiles: Vec<ConfigProfile>,
+    settings_active_profile: usize,  // Profile the connection fields below are editing
 
     // Settings dialog
     show_settings: bool,
+    connection_testing: bool,
+    connection_test_result: Option<Result<String, String>>,  // Display name, or why the test failed
     settings_domain: String,
     settings_email: String,
     settings_token: String,
+    masked_token: String,  // Hint standing in for the saved token, computed when settings open
+    settings_auth_method: AuthMethod,
     settings_font_scale: f32,
-    settings_tags: String,
+    settings_tags: Vec<TagDefinition>,
+    settings_new_tag: String,
+    tag_drag_idx: Option<usize>,  // Tag chip being dragged in settings
     settings_time_format: TimeFormat,
     settings_clock_format: ClockFormat,
     settings_show_start_time: bool,
+    settings_show_status_bar: bool,
+    settings_decimal_precision: u8,
+    settings_min_schedule_block_px: f32,
+    settings_show_block_label_threshold_px: f32,
+    settings_default_start_time: String,
+    settings_daily_target_hours: f32,
+    settings_update_channel: UpdateChannel,
+    settings_export_directory: String,
+    settings_connect_timeout_secs: u64,
+    settings_request_timeout_secs: u64,
+    settings_max_retries: u8,
+    settings_colorblind_mode: ColorblindMode,
+    settings_weekend_display: WeekendDisplay,
+    settings_week_start_day: Weekday,
+    settings_link_behavior: LinkBehavior,
+    settings_quick_actions: Vec<QuickAction>,
+    settings_tag_auto_rules: Vec<TagAutoRule>,
+    settings_issue_type_colors: Vec<(String, [u8; 3])>,  // Built-in types first, then custom ones
+    settings_project_colors: Vec<(String, [u8; 3])>,
 
     // Status
     status_message: Option<(String, bool)>, // (message, is_error)
+    last_sync_at: Option<DateTime<Local>>,  // When worklogs last arrived from Jira
+    offline_data_from: Option<DateTime<Local>>,  // While offline: when the week on screen was fetched (None = nothing to show)
     loading: bool,
     is_offline: bool,
 
@@ -103,23 +211,207 @@ enum AppState {
     Main,
 }
 
+/// Egui ID of the issue search field in the add/edit dialog
+const ISSUE_FIELD_ID: &str = "issue_search";
+
+/// Number of weeks kept in the back-navigation history
+const MAX_NAVIGATION_HISTORY: usize = 20;
+
+/// Issues offered as chips at the top of the add dialog
+const MAX_RECENT_ISSUES: usize = 10;
+
+/// Number of worklog changes Ctrl+Z can walk back through
+const MAX_UNDO_ENTRIES: usize = 20;
+
+/// A cancelled add dialog's draft is no longer offered after this long
+const DIALOG_DRAFT_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Window moves and resizes are saved once the window has been still this long
+const WINDOW_STATE_SAVE_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Duration of each half of the week slide animation (seconds)
+const WEEK_SLIDE_SECONDS: f32 = 0.2;
+
+/// Slide animation when moving to another week
+/// The old week slides out, a spinner shows while loading, then the new week slides in
+struct WeekSlide {
+    direction: f32,  // 1.0 = forward (content moves left), -1.0 = back
+    outgoing: Option<(WeekData, NaiveDate)>,  // Previous week and selected day, until it has slid out
+    incoming: bool,  // New week loaded and sliding in
+    started: bool,   // False until the phase's first frame has reset the animation to its start offset
+}
+
+impl WeekSlide {
+    /// One animation per phase, reused by every slide
+    fn id(&self) -> egui::Id {
+        egui::Id::new(("week_slide", self.incoming))
+    }
+}
+
+/// The dialog's issue once Jira has confirmed it, with time tracking for the estimate bar
+#[derive(Debug, Clone)]
+struct ValidatedIssue {
+    key: String,
+    summary: String,
+    issue_type: String,
+    status: String,          // Workflow status name, empty if unknown
+    time_spent: i64,         // Seconds logged so far across everyone
+    original_estimate: i64,  // Seconds, 0 = no estimate
+}
+
+impl ValidatedIssue {
+    fn from_issue(issue: &Issue) -> Self {
+        Self {
+            key: issue.key.clone(),
+            summary: issue.fields.summary.clone(),
+            issue_type: issue.fields.issue_type.as_ref()
+                .map(|t| t.name.clone())
+                .unwrap_or_else(|| "Task".to_string()),
+            status: issue.status_name().to_string(),
+            time_spent: issue.fields.timespent.unwrap_or(0),
+            original_estimate: issue.fields.time_original_estimate.unwrap_or(0),
+        }
+    }
+
+    /// Weekly bucket tickets carry no time tracking
+    fn without_estimate(key: &str, summary: &str, issue_type: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            summary: summary.to_string(),
+            issue_type: issue_type.to_string(),
+            status: String::new(),
+            time_spent: 0,
+            original_estimate: 0,
+        }
+    }
+}
+
+/// Our worklogs on one issue: None while loading, then the entries (newest first) or an error
+type IssueHistory = Option<Result<Vec<TimeEntry>, String>>;
+
+/// Add dialog input kept after Cancel so it can be restored on the next open
+#[derive(Debug, Clone)]
+struct DialogDraft {
+    issue: String,
+    hours: String,
+    description: String,
+    categories: Vec<bool>,
+    start_time: String,
+    saved_at: Instant,
+}
+
+/// A worklog change that Ctrl+Z can reverse
+#[derive(Debug, Clone)]
+enum UndoEntry {
+    Added(TimeEntry),   // Undo deletes it
+    Edited(TimeEntry),  // The entry before the edit; undo puts it back
+    Deleted(TimeEntry), // Undo logs it again (Jira gives it a new worklog ID)
+}
+
+/// The Jira request that reverses an `UndoEntry`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UndoAction {
+    Delete,  // Remove the added worklog
+    Update,  // Put the edited worklog back as it was
+    Relog,   // Log the deleted worklog again
+}
+
+impl UndoEntry {
+    /// Decided by the kind of change alone: the week on screen may not hold the worklog
+    fn action(&self) -> UndoAction {
+        match self {
+            UndoEntry::Added(_) => UndoAction::Delete,
+            UndoEntry::Edited(_) => UndoAction::Update,
+            UndoEntry::Deleted(_) => UndoAction::Relog,
+        }
+    }
+
+    fn entry_mut(&mut self) -> &mut TimeEntry {
+        match self {
+            UndoEntry::Added(entry) | UndoEntry::Edited(entry) | UndoEntry::Deleted(entry) => entry,
+        }
+    }
+
+    fn into_entry(self) -> TimeEntry {
+        match self {
+            UndoEntry::Added(entry) | UndoEntry::Edited(entry) | UndoEntry::Deleted(entry) => entry,
+        }
+    }
+}
+
+/// Recent worklog changes, newest last, capped at MAX_UNDO_ENTRIES
+#[derive(Debug, Default)]
+struct UndoStack {
+    entries: Vec<UndoEntry>,
+}
+
+impl UndoStack {
+    fn push(&mut self, entry: UndoEntry) {
+        if self.entries.len() == MAX_UNDO_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(entry);
+    }
+
+    fn pop(&mut self) -> Option<UndoEntry> {
+        self.entries.pop()
+    }
+
+    /// Point older changes at the ID Jira gave a worklog that undo logged again
+    fn remap_worklog_id(&mut self, old_id: &str, new_id: &str) {
+        for undo in &mut self.entries {
+            let entry = undo.entry_mut();
+            if entry.worklog_id == old_id {
+                entry.worklog_id = new_id.to_string();
+            }
+        }
+    }
+}
+
+/// Stopwatch in the add dialog that fills in the duration when stopped
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TimerState {
+    Idle,
+    Running { start: Instant },
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum DialogMode {
     Add,
     Edit,
 }
 
+#[allow(clippy::large_enum_variant)]
 enum AsyncResult {
-    WorklogsLoaded(Vec<TimeEntry>, Vec<Issue>),
+    WorklogsLoaded(Vec<TimeEntry>, Vec<Issue>, DateTime<Local>),  // (entries, time buckets, when fetched)
     WorklogSaved(String, TimeEntry, bool),  // (message, entry, is_edit)
     WorklogDeleted(String, String),  // (message, worklog_id)
     IssueSuggestions(Vec<Issue>),
-    WeeklyBucketsLoaded(Vec<(String, String, String, String)>),  // (category, issue_key, issue_summary, issue_type)
+    WeeklyBucketsLoaded(NaiveDate, Vec<(String, String, String, String)>),  // (week_start, [(category, issue_key, issue_summary, issue_type)])
     UpdateAvailable(UpdateInfo),
     UpdateApplied,
     UpdateError(String),
+    SummaryChunk(String),
+    SummaryDone,
+    SummaryFailed,
+    ExportRangeLoaded(NaiveDate, NaiveDate, Vec<TimeEntry>),  // (start, end, entries)
+    MonthLoaded(NaiveDate, Vec<TimeEntry>),  // (first day of the month, entries)
+    MonthFailed(NaiveDate, Option<String>),  // (first day of the month, error; None when offline)
+    IssueWorklogHistory(String, Vec<TimeEntry>),  // (issue key, all of our worklogs on it)
+    IssueWorklogHistoryFailed(String, String),  // (issue key, error)
+    SprintsLoaded(Vec<Sprint>),
+    TeamWorklogsLoaded(NaiveDate, Vec<(String, Vec<TimeEntry>)>),  // (week start, display name -> entries per member)
+    RemoteConfigLoaded(RemoteConfig),
+    ConnectionTestOk(String),  // Display name the settings credentials belong to
+    ConnectionTestFailed(String),
+    ReportSent(usize),  // Number of accepted recipients
+    ReportFailed(String),
     Error(String),
     Offline,
+    Queued(PendingOperation),  // A save or delete that couldn't reach Jira
+    PendingReplayed(String, usize, Vec<String>),  // (site, operations processed from the front of its queue, errors)
+    WorklogRelogged(String, String),  // (old worklog ID, new one) after undoing a delete
+    ScriptFailed(String),  // A quick action script exited unsuccessfully
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -132,6 +424,32 @@ enum ProgressPhase {
     Shrinking,    // shrink back on error
 }
 
+/// Whether a request failed because Jira couldn't be reached (rather than refusing it)
+fn is_offline_error(e: &anyhow::Error) -> bool {
+    let err_str = e.to_string().to_lowercase();
+    ["connection", "network", "dns", "resolve", "timeout", "timed out", "unreachable", "error sending request", "no route", "failed to lookup"]
+        .iter()
+        .any(|pattern| err_str.contains(pattern))
+}
+
+/// Whether a request was sent but no answer came back in time, so Jira may still have acted on it
+fn is_timeout_error(e: &anyhow::Error) -> bool {
+    e.chain().any(|cause| cause.downcast_ref::<reqwest::Error>().is_some_and(|e| e.is_timeout()))
+}
+
+const TAG_CHIP_FONT_SIZE: f32 = 13.0;
+const TAG_CHIP_MIN_FONT_SIZE: f32 = 9.0;
+
+/// Chip font size that lets the widest tag (`widest`, laid out at TAG_CHIP_FONT_SIZE) fit in `max_text_width`,
+/// so it isn't allocated past the right edge of the dialog; never below TAG_CHIP_MIN_FONT_SIZE
+fn tag_chip_font_size(widest: f32, max_text_width: f32) -> f32 {
+    if widest > max_text_width && widest > 0.0 {
+        (TAG_CHIP_FONT_SIZE * max_text_width / widest).max(TAG_CHIP_MIN_FONT_SIZE)
+    } else {
+        TAG_CHIP_FONT_SIZE
+    }
+}
+
 impl JiraTimeApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         let config = Config::load().unwrap_or_default();
@@ -147,23 +465,54 @@ impl JiraTimeApp {
         let (result_tx, result_rx) = channel();
 
         let today = Local::now().date_naive();
-        let current_week_start = week_start(today);
+        let current_week_start = week_start(today, config.week_start_day);
         let num_tags = config.tags.len();
+        let recent_issues = config.recent_issues.iter().cloned().collect();
+        let pending = PendingQueue::load(&config.jira_domain).unwrap_or_default();
 
         let mut app = Self {
             show_settings: false,
+            connection_testing: false,
+            connection_test_result: None,
+            active_profile_index: config.active_profile,
+            settings_profiles: config.profiles_with_active(),
+            settings_active_profile: config.active_profile,
             settings_domain: config.jira_domain.trim_end_matches(".atlassian.net").to_string(),
             settings_email: config.email.clone(),
             settings_token: String::new(),
+            masked_token: String::new(),
+            settings_auth_method: config.auth_method,
             settings_font_scale: config.font_scale,
-            settings_tags: config.tags.join(", "),
+            settings_tags: config.tags.clone(),
+            settings_new_tag: String::new(),
+            tag_drag_idx: None,
             settings_time_format: config.time_format,
             settings_clock_format: config.clock_format,
             settings_show_start_time: config.show_start_time,
+            settings_show_status_bar: config.show_status_bar,
+            settings_decimal_precision: config.decimal_precision,
+            settings_min_schedule_block_px: config.min_schedule_block_px,
+            settings_show_block_label_threshold_px: config.show_block_label_threshold_px,
+            settings_default_start_time: config.default_start_time.clone(),
+            settings_daily_target_hours: config.daily_target_hours,
+            settings_update_channel: config.update_channel,
+            settings_export_directory: config.export_directory.clone().unwrap_or_default(),
+            settings_connect_timeout_secs: config.connect_timeout_secs,
+            settings_request_timeout_secs: config.request_timeout_secs,
+            settings_max_retries: config.max_retries,
+            settings_colorblind_mode: config.colorblind_mode,
+            settings_weekend_display: config.weekend_display,
+            settings_week_start_day: config.week_start_day,
+            settings_link_behavior: config.link_behavior,
+            settings_quick_actions: config.quick_actions.clone(),
+            settings_tag_auto_rules: config.tag_auto_rules.clone(),
+            settings_issue_type_colors: Self::issue_type_color_rows(&config),
+            settings_project_colors: config.project_colors.clone(),
             config,
             state,
             selected_date: today,
             week_data: WeekData::new(current_week_start),
+            week_cache: HashMap::new(),
             time_buckets: Vec::new(),
             weekly_buckets: HashMap::new(),
             weekly_buckets_week: None,
@@ -171,21 +520,38 @@ impl JiraTimeApp {
             show_dialog: false,
             dialog_mode: DialogMode::Add,
             dialog_hours: String::new(),
+            dialog_timer: TimerState::Idle,
             dialog_issue: String::new(),
             dialog_description: String::new(),
+            dialog_description_preview: false,
             dialog_worklog_id: String::new(),
+            dialog_worklog_date: today,
             dialog_start_time: String::new(),
+            dialog_draft: None,
+            dialog_opened_input: None,
             dialog_categories: vec![false; num_tags],
             error_issue: false,
             error_hours: false,
+            error_start_time: false,
             issue_suggestions: Vec::new(),
             show_suggestions: false,
+            autocomplete_selection: None,
             last_issue_search: String::new(),
             last_search_time: Instant::now(),
             searching_issues: false,
             validated_issue: None,
+            suggestion_project_filter: None,
+            issue_from_clipboard: false,
+            recent_issues,
+            issue_from_draft: false,
+            focus_duration_pending: false,
+            focus_issue_pending: false,
             dialog_accent_color: None,
             pending_delete: None,
+            inline_edit_state: None,
+            new_entry_worklog_ids: HashMap::new(),
+            schedule_hover_started: HashMap::new(),
+            team_entries: Vec::new(),
             show_delete_confirm: false,
             show_reschedule_dialog: false,
             reschedule_entry: None,
@@ -193,7 +559,38 @@ impl JiraTimeApp {
             reschedule_time: String::new(),
             reschedule_duration: String::new(),
             reschedule_is_clone: false,
+            schedule_view_entered: false,
+            show_summary: false,
+            summary_text: String::new(),
+            summary_streaming: false,
+            summary_unavailable: false,
+            report_sending: false,
+            issue_details: None,
+            issue_history: None,
+            expanded_issue_groups: HashMap::new(),
+            annotations: Annotations::load().unwrap_or_default(),
+            undo_stack: UndoStack::default(),
+            undoing: false,
+            pending,
+            replaying_pending: false,
+            annotation_edit: None,
+            show_export_range: false,
+            export_range_start: String::new(),
+            export_range_end: String::new(),
+            export_range_loading: false,
+            month_data: None,
+            month_requested: None,
+            month_loading: false,
+            month_failed: None,
+            export_range_preview: None,
+            show_shortcuts: false,
+            window_state_pending: None,
+            week_slide: None,
+            navigation_history: VecDeque::new(),
+            navigation_forward: Vec::new(),
             status_message: None,
+            last_sync_at: None,
+            offline_data_from: None,
             loading: false,
             is_offline: false,
             update_info: None,
@@ -212,10 +609,12 @@ impl JiraTimeApp {
             // DEMO MODE: Use fake data for screenshots (comment out for normal use)
             //app.load_demo_data();
             app.refresh_data();
+            app.load_sprints();
         }
 
         // Check for updates on startup
         app.check_for_updates();
+        app.load_remote_config();
 
         app
     }
@@ -226,9 +625,10 @@ impl JiraTimeApp {
         }
         self.update_checking = true;
 
+        let channel = self.config.update_channel;
         let tx = self.result_tx.clone();
         self.runtime.spawn(async move {
-            match update::check_for_update() {
+            match update::check_for_update(channel) {
                 Ok(Some(info)) => {
                     let _ = tx.send(AsyncResult::UpdateAvailable(info));
                 }
@@ -247,6 +647,9 @@ impl JiraTimeApp {
         if self.update_applying {
             return;
         }
+        let Some(version) = self.update_info.as_ref().map(|info| info.latest_version.clone()) else {
+            return;
+        };
         self.update_applying = true;
         self.status_message = None;
         // Start progress animation
@@ -256,7 +659,7 @@ impl JiraTimeApp {
 
         let tx = self.result_tx.clone();
         self.runtime.spawn(async move {
-            match update::apply_update() {
+            match update::apply_update(&version) {
                 Ok(()) => {
                     let _ = tx.send(AsyncResult::UpdateApplied);
                 }
@@ -290,60 +693,84 @@ impl JiraTimeApp {
                 issue_key: "TIM-42".to_string(),
                 issue_summary: "MEETINGS - 2024-12-02 to 2024-12-06".to_string(),
                 issue_type: "Task".to_string(),
+                issue_status: String::new(),
                 seconds: 3600,
                 description: descriptions[0].to_string(),
                 date: today,
                 start_time: "09:00".to_string(),
+                time_spent_total: 0,
+                time_original_estimate: 0,
+                custom_properties: HashMap::new(),
             },
             TimeEntry {
                 worklog_id: "2".to_string(),
                 issue_key: "PROJ-123".to_string(),
                 issue_summary: "Implement user authentication flow".to_string(),
                 issue_type: "Story".to_string(),
+                issue_status: "In Progress".to_string(),
                 seconds: 5400,
                 description: descriptions[1].to_string(),
                 date: today,
                 start_time: "10:00".to_string(),
+                time_spent_total: 16200,
+                time_original_estimate: 28800,
+                custom_properties: HashMap::new(),
             },
             TimeEntry {
                 worklog_id: "3".to_string(),
                 issue_key: "TIM-43".to_string(),
                 issue_summary: "SUPPORT - 2024-12-02 to 2024-12-06".to_string(),
                 issue_type: "Task".to_string(),
+                issue_status: String::new(),
                 seconds: 1800,
                 description: descriptions[2].to_string(),
                 date: today,
                 start_time: "11:30".to_string(),
+                time_spent_total: 0,
+                time_original_estimate: 0,
+                custom_properties: HashMap::new(),
             },
             TimeEntry {
                 worklog_id: "4".to_string(),
                 issue_key: "PROJ-456".to_string(),
                 issue_summary: "Fix database connection pooling issue".to_string(),
                 issue_type: "Bug".to_string(),
+                issue_status: "Done".to_string(),
                 seconds: 7200,
                 description: descriptions[3].to_string(),
                 date: today,
                 start_time: "13:00".to_string(),
+                time_spent_total: 28800,
+                time_original_estimate: 21600,
+                custom_properties: HashMap::new(),
             },
             TimeEntry {
                 worklog_id: "5".to_string(),
                 issue_key: "TIM-44".to_string(),
                 issue_summary: "ADMIN - 2024-12-02 to 2024-12-06".to_string(),
                 issue_type: "Task".to_string(),
+                issue_status: String::new(),
                 seconds: 2700,
                 description: descriptions[4].to_string(),
                 date: today,
                 start_time: "15:00".to_string(),
+                time_spent_total: 0,
+                time_original_estimate: 0,
+                custom_properties: HashMap::new(),
             },
             TimeEntry {
                 worklog_id: "6".to_string(),
                 issue_key: "PROJ-789".to_string(),
                 issue_summary: "Code review and documentation updates".to_string(),
                 issue_type: "Task".to_string(),
+                issue_status: "In Progress".to_string(),
                 seconds: 3600,
                 description: descriptions[5].to_string(),
                 date: today,
                 start_time: "16:00".to_string(),
+                time_spent_total: 25200,
+                time_original_estimate: 25200,
+                custom_properties: HashMap::new(),
             },
         ];
 
@@ -354,20 +781,28 @@ impl JiraTimeApp {
             issue_key: "PROJ-101".to_string(),
             issue_summary: "Sprint planning and backlog refinement".to_string(),
             issue_type: "Epic".to_string(),
+            issue_status: String::new(),
             seconds: 10800,
             description: descriptions[6].to_string(),
             date: yesterday,
             start_time: "09:00".to_string(),
+            time_spent_total: 21600,
+            time_original_estimate: 28800,
+            custom_properties: HashMap::new(),
         });
         self.week_data.entries.push(TimeEntry {
             worklog_id: "8".to_string(),
             issue_key: "TIM-42".to_string(),
             issue_summary: "MEETINGS - 2024-12-02 to 2024-12-06".to_string(),
             issue_type: "Task".to_string(),
+            issue_status: String::new(),
             seconds: 5400,
             description: descriptions[7].to_string(),
             date: yesterday,
             start_time: "14:00".to_string(),
+            time_spent_total: 0,
+            time_original_estimate: 0,
+            custom_properties: HashMap::new(),
         });
 
         // Fake weekly buckets (key, summary, issue_type)
@@ -380,22 +815,72 @@ impl JiraTimeApp {
     fn check_async_results(&mut self) {
         while let Ok(result) = self.result_rx.try_recv() {
             match result {
-                AsyncResult::WorklogsLoaded(entries, buckets) => {
+                AsyncResult::WorklogsLoaded(entries, buckets, fetched_at) => {
                     self.week_data.entries = entries;
+                    self.week_cache.insert(self.week_data.week_start, CachedWeek { fetched_at, week: self.week_data.clone() });
+                    self.offline_data_from = None;
+                    // Jira answers again, so a month that failed can load now
+                    self.month_failed = None;
+                    if let Some(slide) = &mut self.week_slide {
+                        slide.outgoing = None;
+                        slide.incoming = true;
+                        slide.started = false;
+                    }
                     self.time_buckets = buckets;
                     self.loading = false;
                     self.is_offline = false;
                     self.status_message = None;
+                    self.last_sync_at = Some(Local::now());
                     // Trigger completion animation
                     self.progress_phase = ProgressPhase::Completing;
                     self.progress_start = std::time::Instant::now();
+                    // Back online: send anything saved while we weren't
+                    if !self.pending.operations.is_empty() && !self.replaying_pending {
+                        self.replay_pending();
+                    }
                 }
                 AsyncResult::WorklogSaved(_msg, entry, is_edit) => {
                     self.loading = false;
                     self.show_dialog = false;
+                    self.dialog_timer = TimerState::Idle;
                     // Trigger completion animation
                     self.progress_phase = ProgressPhase::Completing;
                     self.progress_start = std::time::Instant::now();
+                    self.remember_recent_issue(&entry);
+                    if self.issue_history.as_ref().is_some_and(|(key, _)| *key == entry.issue_key) {
+                        self.issue_history = None;
+                    }
+                    let previous = if is_edit {
+                        self.week_data.entries.iter().find(|e| e.worklog_id == entry.worklog_id).cloned()
+                    } else {
+                        None
+                    };
+                    self.invalidate_month(entry.date);
+                    if let Some(before) = &previous {
+                        self.invalidate_month(before.date);
+                    }
+                    if std::mem::take(&mut self.undoing) {
+                        self.status_message = Some(("Undone".to_string(), false));
+                    } else if is_edit {
+                        if let Some(before) = previous.clone() {
+                            self.undo_stack.push(UndoEntry::Edited(before));
+                        }
+                    } else {
+                        self.undo_stack.push(UndoEntry::Added(entry.clone()));
+                    }
+                    // Keep the issue's spent total in step with the local change
+                    let previous_seconds = if is_edit {
+                        previous.map(|e| e.seconds).unwrap_or(entry.seconds)
+                    } else {
+                        0
+                    };
+                    let spent_delta = entry.seconds - previous_seconds;
+                    let issue_key = entry.issue_key.clone();
+                    let mut entry = entry;
+                    if let Some(sibling) = self.week_data.entries.iter().find(|e| e.issue_key == issue_key) {
+                        entry.time_spent_total = sibling.time_spent_total;
+                        entry.time_original_estimate = sibling.time_original_estimate;
+                    }
                     // Update local data instead of full refresh
                     if is_edit {
                         // Update existing entry
@@ -404,6 +889,7 @@ impl JiraTimeApp {
                         {
                             existing.seconds = entry.seconds;
                             existing.description = entry.description;
+                            existing.date = entry.date;
                             existing.start_time = entry.start_time;
                         }
                         // Re-sort since start time may have changed
@@ -411,37 +897,108 @@ impl JiraTimeApp {
                             a.date.cmp(&b.date).then_with(|| a.start_time.cmp(&b.start_time))
                         });
                     } else {
-                        // Add new entry and sort by start time
+                        self.dialog_draft = None;
+                        // Add new entry (fading in) and sort by start time
+                        self.new_entry_worklog_ids.insert(entry.worklog_id.clone(), Instant::now());
                         self.week_data.entries.push(entry);
                         self.week_data.entries.sort_by(|a, b| {
                             a.date.cmp(&b.date).then_with(|| a.start_time.cmp(&b.start_time))
                         });
                     }
+                    for e in self.week_data.entries.iter_mut().filter(|e| e.issue_key == issue_key) {
+                        e.time_spent_total += spent_delta;
+                    }
                 }
                 AsyncResult::WorklogDeleted(_msg, worklog_id) => {
                     self.loading = false;
                     // Trigger completion animation
                     self.progress_phase = ProgressPhase::Completing;
                     self.progress_start = std::time::Instant::now();
-                    // Remove entry from local data
+                    // Remove entry from local data and take its time off the issue's spent total
+                    let undoing = std::mem::take(&mut self.undoing);
+                    if undoing {
+                        self.status_message = Some(("Undone".to_string(), false));
+                    }
+                    // The history panel refetches without the deleted worklog
+                    self.issue_history = None;
+                    if let Some(removed) = self.week_data.entries.iter().find(|e| e.worklog_id == worklog_id).cloned() {
+                        self.invalidate_month(removed.date);
+                        for e in self.week_data.entries.iter_mut().filter(|e| e.issue_key == removed.issue_key) {
+                            e.time_spent_total = (e.time_spent_total - removed.seconds).max(0);
+                        }
+                        if !undoing {
+                            self.undo_stack.push(UndoEntry::Deleted(removed));
+                        }
+                    }
                     self.week_data.entries.retain(|e| e.worklog_id != worklog_id);
+                    if self.annotations.get(&worklog_id).is_some() {
+                        self.annotations.set(&worklog_id, "");
+                        let _ = self.annotations.save();
+                    }
                 }
                 AsyncResult::IssueSuggestions(issues) => {
                     self.issue_suggestions = issues;
+                    self.autocomplete_selection = None;
+                    // Keep the project filter only while that project is still in the results
+                    if let Some(project) = &self.suggestion_project_filter {
+                        if !self.issue_suggestions.iter().any(|i| i.project_key() == project) {
+                            self.suggestion_project_filter = None;
+                        }
+                    }
                     self.searching_issues = false;
                     self.show_suggestions = !self.issue_suggestions.is_empty();
+
+                    // A key pasted from the clipboard (or restored from a draft) is validated as soon as Jira confirms it
+                    if (self.issue_from_clipboard || self.issue_from_draft) && self.validated_issue.is_none() {
+                        if let Some(issue) = self.issue_suggestions.iter().find(|i| i.key == self.dialog_issue) {
+                            self.validated_issue = Some(ValidatedIssue::from_issue(issue));
+                            self.apply_tag_auto_rules();
+                            self.show_suggestions = false;
+                            self.focus_duration_pending = true;
+                        }
+                    }
                 }
-                AsyncResult::WeeklyBucketsLoaded(buckets) => {
+                AsyncResult::WeeklyBucketsLoaded(week_start, buckets) => {
+                    self.weekly_buckets_loading = false;
+                    if week_start != self.week_data.week_start {
+                        // User navigated while this was in flight - discard and load the current week
+                        self.load_weekly_buckets(self.week_data.week_start);
+                        continue;
+                    }
                     self.weekly_buckets.clear();
                     for (category, key, summary, issue_type) in buckets {
                         self.weekly_buckets.insert(category, (key, summary, issue_type));
                     }
-                    self.weekly_buckets_week = Some(self.week_data.week_start);
-                    self.weekly_buckets_loading = false;
+                    self.weekly_buckets_week = Some(week_start);
+                }
+                AsyncResult::TeamWorklogsLoaded(week_start, team) => {
+                    // Drop results for a week the user has already left
+                    if week_start == self.week_data.week_start {
+                        self.team_entries = team;
+                    }
+                }
+                AsyncResult::SprintsLoaded(sprints) => {
+                    // Sprint dates are ISO timestamps; only the day matters for the header
+                    let day = |date: &Option<String>| date.as_deref()
+                        .and_then(|d| d.get(..10))
+                        .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+                    self.config.cached_sprints = sprints.iter()
+                        .map(|s| CachedSprint { name: s.name.clone(), start_date: day(&s.start_date), end_date: day(&s.end_date) })
+                        .collect();
+                    self.config.cached_sprints_at = Some(Local::now().naive_local());
+                    let _ = self.config.save();
+                }
+                AsyncResult::RemoteConfigLoaded(remote) => {
+                    self.config.apply_remote(remote);
+                    self.dialog_categories.resize(self.config.tags.len(), false);
+                    let _ = self.config.save();
                 }
                 AsyncResult::Error(msg) => {
                     self.loading = false;
+                    self.undoing = false;
+                    self.week_slide = None;
                     self.searching_issues = false;
+                    self.export_range_loading = false;
                     self.is_offline = false;
                     self.status_message = Some((msg, true));
                     // Trigger shrink animation
@@ -450,13 +1007,67 @@ impl JiraTimeApp {
                 }
                 AsyncResult::Offline => {
                     self.loading = false;
+                    self.undoing = false;
+                    self.week_slide = None;
                     self.searching_issues = false;
+                    self.export_range_loading = false;
                     self.is_offline = true;
                     self.status_message = None;
+                    // Keep what's on screen, or fall back to the copy saved the last time this week loaded
+                    self.offline_data_from = if self.week_data.entries.is_empty() {
+                        WeekData::load_cached(&self.config.jira_domain, self.week_data.week_start).map(|cached| {
+                            self.week_data = cached.week;
+                            cached.fetched_at
+                        })
+                    } else {
+                        self.week_cache.get(&self.week_data.week_start).map(|cached| cached.fetched_at)
+                    };
                     // Trigger shrink animation
                     self.progress_phase = ProgressPhase::Shrinking;
                     self.progress_start = std::time::Instant::now();
                 }
+                AsyncResult::Queued(operation) => {
+                    self.loading = false;
+                    self.undoing = false;
+                    self.progress_phase = ProgressPhase::Shrinking;
+                    self.progress_start = std::time::Instant::now();
+                    let message = match &operation {
+                        PendingOperation::Delete { issue_key, .. } => format!("Offline - delete on {} will sync when reconnected", issue_key),
+                        _ => format!("Offline - {} saved locally, will sync when reconnected", operation.issue_key()),
+                    };
+                    if !matches!(operation, PendingOperation::Delete { .. }) {
+                        self.show_dialog = false;
+                        self.dialog_timer = TimerState::Idle;
+                        self.dialog_draft = None;
+                    }
+                    self.pending.operations.push(operation);
+                    let _ = self.pending.save();
+                    self.status_message = Some((message, false));
+                }
+                AsyncResult::ScriptFailed(message) => {
+                    self.status_message = Some((message, true));
+                }
+                AsyncResult::WorklogRelogged(old_id, new_id) => {
+                    self.undo_stack.remap_worklog_id(&old_id, &new_id);
+                }
+                AsyncResult::PendingReplayed(jira_domain, processed, errors) => {
+                    self.replaying_pending = false;
+                    // Profile switched mid-replay: that site's queue is no longer the one loaded
+                    if jira_domain != self.pending.jira_domain {
+                        continue;
+                    }
+                    let processed = processed.min(self.pending.operations.len());
+                    self.pending.operations.drain(..processed);
+                    let _ = self.pending.save();
+                    if let Some(first) = errors.first() {
+                        self.status_message = Some((format!("{} offline change(s) failed to sync: {}", errors.len(), first), true));
+                    } else if processed > 0 {
+                        self.status_message = Some((format!("Synced {} offline change(s)", processed), false));
+                    }
+                    if processed > 0 {
+                        self.refresh_data();
+                    }
+                }
                 AsyncResult::UpdateAvailable(info) => {
                     self.update_checking = false;
                     self.update_info = Some(info);
@@ -481,179 +1092,1186 @@ impl JiraTimeApp {
                     self.update_applying = false;
                     self.status_message = Some((msg, true));
                 }
+                AsyncResult::SummaryChunk(text) => {
+                    self.summary_text.push_str(&text);
+                }
+                AsyncResult::SummaryDone => {
+                    self.summary_streaming = false;
+                }
+                AsyncResult::SummaryFailed => {
+                    // Silently hide the feature - the endpoint is optional and self-hosted
+                    self.summary_streaming = false;
+                    self.summary_unavailable = true;
+                    self.show_summary = false;
+                }
+                AsyncResult::ExportRangeLoaded(start, end, entries) => {
+                    self.export_range_loading = false;
+                    self.export_range_preview = Some((start, end, entries));
+                }
+                AsyncResult::MonthLoaded(month, entries) => {
+                    if self.month_requested == Some(month) {
+                        self.month_loading = false;
+                        self.month_data = Some((month, entries));
+                    }
+                }
+                AsyncResult::MonthFailed(month, error) => {
+                    if self.month_requested == Some(month) {
+                        self.month_loading = false;
+                        self.month_requested = None;
+                        self.month_failed = Some(month);
+                        match error {
+                            Some(msg) => self.status_message = Some((msg, true)),
+                            None => self.is_offline = true,
+                        }
+                    }
+                }
+                AsyncResult::IssueWorklogHistory(issue_key, entries) => {
+                    if self.issue_history.as_ref().is_some_and(|(key, _)| *key == issue_key) {
+                        self.issue_history = Some((issue_key, Some(Ok(entries))));
+                    }
+                }
+                AsyncResult::IssueWorklogHistoryFailed(issue_key, error) => {
+                    if self.issue_history.as_ref().is_some_and(|(key, _)| *key == issue_key) {
+                        self.issue_history = Some((issue_key, Some(Err(error))));
+                    }
+                }
+                AsyncResult::ConnectionTestOk(name) => {
+                    self.connection_testing = false;
+                    self.connection_test_result = Some(Ok(name));
+                }
+                AsyncResult::ConnectionTestFailed(error) => {
+                    self.connection_testing = false;
+                    self.connection_test_result = Some(Err(error));
+                }
+                AsyncResult::ReportSent(recipients) => {
+                    self.report_sending = false;
+                    let plural = if recipients == 1 { "" } else { "s" };
+                    self.status_message = Some((format!("Report sent to {} recipient{}", recipients, plural), false));
+                }
+                AsyncResult::ReportFailed(msg) => {
+                    self.report_sending = false;
+                    self.status_message = Some((msg, true));
+                }
             }
         }
     }
 
-    fn refresh_data(&mut self) {
-        self.load_week(self.week_data.week_start);
+    /// Parse the custom export fields as an inclusive (start, end) date range
+    fn export_range_dates(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let start = NaiveDate::parse_from_str(self.export_range_start.trim(), "%Y-%m-%d").ok()?;
+        let end = NaiveDate::parse_from_str(self.export_range_end.trim(), "%Y-%m-%d").ok()?;
+        (start <= end).then_some((start, end))
     }
 
-    fn load_week(&mut self, week_start_date: NaiveDate) {
-        if !self.config.is_configured() {
-            return;
-        }
+    fn open_export_range_dialog(&mut self) {
+        let week_start = self.week_data.week_start;
+        self.export_range_start = week_start.format("%Y-%m-%d").to_string();
+        self.export_range_end = (week_start + Duration::days(6)).format("%Y-%m-%d").to_string();
+        self.export_range_preview = None;
+        self.show_export_range = true;
+    }
 
-        // If already loading, don't start another request
-        if self.loading {
-            // But still update UI state immediately
-            self.week_data.week_start = week_start_date;
+    /// Gather the entries for the custom export range
+    /// A range inside the loaded week reuses it; anything else is fetched from Jira
+    fn load_export_range(&mut self) {
+        let Some((start, end)) = self.export_range_dates() else {
+            return;
+        };
+        let loaded_start = self.week_data.week_start;
+        let loaded_end = loaded_start + Duration::days(6);
+        if start >= loaded_start && end <= loaded_end && !self.loading {
+            let entries = self.week_data.entries.iter()
+                .filter(|e| e.date >= start && e.date <= end)
+                .cloned()
+                .collect();
+            self.export_range_preview = Some((start, end, entries));
             return;
         }
 
-        // Clear entries immediately for snappy UI
-        self.week_data = WeekData::new(week_start_date);
-
-        self.loading = true;
-        self.progress = 0.0;
-        self.progress_phase = ProgressPhase::FastStart;
-        self.progress_start = std::time::Instant::now();
+        self.export_range_loading = true;
+        self.export_range_preview = None;
 
         let config = self.config.clone();
         let tx = self.result_tx.clone();
-
-        // Always load the full week (Mon-Sun)
-        let start_date = week_start_date;
-        let end_date = week_start_date + Duration::days(6);
-
-        // Also load weekly buckets for quick-add buttons
-        self.load_weekly_buckets(week_start_date);
-
-        // Spawn async task
         self.runtime.spawn(async move {
             let result = async {
                 let client = JiraClient::new(&config)?;
-                let worklogs = client.get_my_worklogs(start_date, end_date).await?;
-                let buckets = client.get_time_buckets().await.unwrap_or_default();
-                Ok::<_, anyhow::Error>((worklogs, buckets))
+                client.get_my_worklogs(start, end).await
             }.await;
 
             match result {
-                Ok((worklogs, buckets)) => {
-                    let entries: Vec<TimeEntry> = worklogs
-                        .into_iter()
-                        .map(|(issue_key, issue_summary, issue_type, worklog)| {
-                            let description = worklog.comment_text();
-                            let seconds = worklog.time_spent_seconds;
-                            let date = parse_date(&worklog.started);
-                            let start_time = extract_time(&worklog.started);
-                            TimeEntry {
-                                worklog_id: worklog.id,
-                                issue_key,
-                                issue_summary,
-                                issue_type,
-                                seconds,
-                                description,
-                                date,
-                                start_time,
-                            }
-                        })
+                Ok(worklogs) => {
+                    let entries = worklogs.into_iter()
+                        .map(|(issue, worklog)| TimeEntry::from_worklog(issue, worklog))
                         .collect();
-                    let _ = tx.send(AsyncResult::WorklogsLoaded(entries, buckets));
+                    let _ = tx.send(AsyncResult::ExportRangeLoaded(start, end, entries));
                 }
                 Err(e) => {
-                    // Check if this is a network connectivity error
-                    let err_str = e.to_string().to_lowercase();
-                    if err_str.contains("connection") || err_str.contains("network")
-                       || err_str.contains("dns") || err_str.contains("resolve")
-                       || err_str.contains("timeout") || err_str.contains("unreachable")
-                       || err_str.contains("error sending request") || err_str.contains("no route")
-                       || err_str.contains("failed to lookup") {
+                    if is_offline_error(&e) {
                         let _ = tx.send(AsyncResult::Offline);
                     } else {
-                        let _ = tx.send(AsyncResult::Error(format!("Error: {}", e)));
+                        let _ = tx.send(AsyncResult::Error(format!("Export failed: {}", e)));
                     }
                 }
             }
         });
     }
 
-    fn save_settings(&mut self) {
-        // Build full domain from subdomain input
-        let full_domain = if self.settings_domain.contains('.') {
-            self.settings_domain.clone()
-        } else {
-            format!("{}.atlassian.net", self.settings_domain)
+    fn summarize_week(&mut self) {
+        let Some(endpoint) = self.config.ai_summary_endpoint.clone() else {
+            return;
         };
+        self.show_summary = true;
+        if self.summary_streaming {
+            return;
+        }
 
-        // Check if credentials changed (need to reload if so)
-        let credentials_changed =
-            self.config.jira_domain != full_domain
-            || self.config.email != self.settings_email
-            || !self.settings_token.is_empty();
-
-        self.config.jira_domain = full_domain;
-        self.config.email = self.settings_email.clone();
-        self.config.font_scale = self.settings_font_scale;
-        self.config.time_format = self.settings_time_format;
-        self.config.clock_format = self.settings_clock_format;
-        self.config.show_start_time = self.settings_show_start_time;
-        // Parse tags from comma-separated string
-        self.config.tags = self.settings_tags
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
-        // Update dialog_categories to match new tag count
-        self.dialog_categories = vec![false; self.config.tags.len()];
+        self.summary_streaming = true;
+        self.summary_text = String::new();
 
-        if !self.settings_token.is_empty() {
-            self.config.api_token = Some(self.settings_token.clone());
-        }
+        let model = self.config.ai_summary_model.clone();
+        let prompt = summary::build_prompt(&self.week_data);
+        let tx = self.result_tx.clone();
+        self.runtime.spawn(async move {
+            let chunk_tx = tx.clone();
+            let result = summary::stream_summary(&endpoint, &model, &prompt, |text| {
+                let _ = chunk_tx.send(AsyncResult::SummaryChunk(text));
+            }).await;
 
-        match self.config.save() {
-            Ok(_) => {
-                self.show_settings = false;
-                // Transition from Setup to Main if now configured
-                if self.config.is_configured() && self.state == AppState::Setup {
-                    self.state = AppState::Main;
+            match result {
+                Ok(()) => {
+                    let _ = tx.send(AsyncResult::SummaryDone);
                 }
-                if credentials_changed {
-                    self.refresh_data();
+                Err(_) => {
+                    let _ = tx.send(AsyncResult::SummaryFailed);
                 }
             }
-            Err(e) => {
-                self.status_message = Some((format!("Failed to save: {}", e), true));
-            }
-        }
+        });
     }
 
-    fn load_weekly_buckets(&mut self, week_start_date: NaiveDate) {
-        // Skip if already loading or already have buckets for this week
-        if self.weekly_buckets_loading {
+    /// Email the displayed week as an HTML report to the configured recipients
+    fn send_weekly_report(&mut self) {
+        let Some(smtp) = self.config.smtp_config.clone() else {
             return;
-        }
-        if self.weekly_buckets_week == Some(week_start_date) {
+        };
+        if self.report_sending {
             return;
         }
+        self.report_sending = true;
+
+        let log = export::weekly_log(&self.week_data, None);
+        let html = export::weekly_log_html(&log, &self.config.issue_type_colors, self.config.time_format, self.config.decimal_precision);
+        let start = self.week_data.week_start;
+        let subject = format!(
+            "Timebox weekly report: {} - {}",
+            start.format("%b %-d"),
+            (start + Duration::days(6)).format("%b %-d, %Y")
+        );
+        let tx = self.result_tx.clone();
+        self.runtime.spawn(async move {
+            match mail::send_html(&smtp, &subject, &html).await {
+                Ok(recipients) => {
+                    let _ = tx.send(AsyncResult::ReportSent(recipients));
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncResult::ReportFailed(format!("Report not sent: {:#}", e)));
+                }
+            }
+        });
+    }
 
-        self.weekly_buckets_loading = true;
+    fn refresh_data(&mut self) {
+        // The month view refetches too the next time it's shown
+        self.month_requested = None;
+        self.month_failed = None;
+        self.load_week(self.week_data.week_start);
+    }
+
+    /// Mark the month view's data stale when a save or delete lands in the month it shows
+    fn invalidate_month(&mut self, date: NaiveDate) {
+        if self.month_requested == date.with_day(1) {
+            self.month_requested = None;
+        }
+    }
 
+    /// Fetch our worklogs for the month starting on `month` (its first day), for the month view
+    fn load_month(&mut self, month: NaiveDate) {
+        if !self.config.is_configured() {
+            return;
+        }
+        self.month_requested = Some(month);
+        self.month_loading = true;
+        self.month_failed = None;
+
+        let end = month.checked_add_months(chrono::Months::new(1)).unwrap_or(month) - Duration::days(1);
         let config = self.config.clone();
         let tx = self.result_tx.clone();
-
         self.runtime.spawn(async move {
             let result = async {
                 let client = JiraClient::new(&config)?;
-                client.search_all_weekly_buckets(week_start_date).await
+                client.get_my_worklogs(month, end).await
             }.await;
 
             match result {
-                Ok(buckets) => {
-                    let bucket_data: Vec<(String, String, String, String)> = buckets
-                        .into_iter()
-                        .map(|(cat, issue)| {
-                            let issue_type = issue.fields.issue_type
-                                .map(|t| t.name)
-                                .unwrap_or_else(|| "Task".to_string());
-                            (cat, issue.key, issue.fields.summary, issue_type)
-                        })
+                Ok(worklogs) => {
+                    let entries = worklogs.into_iter()
+                        .map(|(issue, worklog)| TimeEntry::from_worklog(issue, worklog))
                         .collect();
-                    let _ = tx.send(AsyncResult::WeeklyBucketsLoaded(bucket_data));
+                    let _ = tx.send(AsyncResult::MonthLoaded(month, entries));
                 }
-                Err(_) => {
-                    // Silently fail - buckets are optional
-                    let _ = tx.send(AsyncResult::WeeklyBucketsLoaded(Vec::new()));
+                Err(e) => {
+                    let error = (!is_offline_error(&e)).then(|| format!("Couldn't load the month: {}", e));
+                    let _ = tx.send(AsyncResult::MonthFailed(month, error));
+                }
+            }
+        });
+    }
+
+    /// Navigate directly to a week, recording the current one in the back history
+    fn navigate_to_week(&mut self, week_start_date: NaiveDate) {
+        if week_start_date != self.week_data.week_start {
+            if self.navigation_history.len() == MAX_NAVIGATION_HISTORY {
+                self.navigation_history.pop_front();
+            }
+            self.navigation_history.push_back(self.week_data.week_start);
+            self.navigation_forward.clear();
+        }
+        self.start_week_slide(week_start_date);
+        self.selected_date = week_start_date;
+        self.load_week(week_start_date);
+    }
+
+    /// Per-hour intensity from cached weeks in the four weeks up to the displayed one
+    fn recent_hour_heatmap(&self) -> Option<[f32; 24]> {
+        let current = self.week_data.week_start;
+        let mut weeks = vec![self.week_data.clone()];
+        weeks.extend((1..4).filter_map(|i| self.week_cache.get(&(current - Duration::weeks(i))).map(|cached| cached.week.clone())));
+        if weeks.iter().all(|w| w.entries.is_empty()) {
+            return None;
+        }
+        Some(views::compute_hour_heatmap(&weeks))
+    }
+
+    fn run_quick_action(&mut self, ctx: &egui::Context, action: &QuickActionType) {
+        match action {
+            QuickActionType::OpenUrl(url) => {
+                if let Err(e) = open::that(url) {
+                    self.status_message = Some((format!("Couldn't open {}: {}", url, e), true));
+                }
+            }
+            QuickActionType::CopyTemplate(template) => {
+                ctx.copy_text(self.expand_template(template));
+                self.status_message = Some(("Copied to clipboard".to_string(), false));
+            }
+            QuickActionType::RunScript(script) => {
+                let mut command = if cfg!(target_os = "windows") {
+                    let mut command = std::process::Command::new("cmd");
+                    command.arg("/C");
+                    command
+                } else {
+                    let mut command = std::process::Command::new("sh");
+                    command.arg("-c");
+                    command
+                };
+                match command.arg(self.expand_template(script)).spawn() {
+                    Ok(mut child) => {
+                        // Waited on off the UI thread, so the script doesn't linger as a zombie
+                        let tx = self.result_tx.clone();
+                        self.runtime.spawn_blocking(move || {
+                            let message = match child.wait() {
+                                Ok(status) if status.success() => return,
+                                Ok(status) => match status.code() {
+                                    Some(code) => format!("Script exited with status {}", code),
+                                    None => "Script was terminated".to_string(),
+                                },
+                                Err(e) => format!("Script failed: {}", e),
+                            };
+                            let _ = tx.send(AsyncResult::ScriptFailed(message));
+                        });
+                    }
+                    Err(e) => {
+                        self.status_message = Some((format!("Script failed to start: {}", e), true));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fill in {date}, {week_start}, {week_end}, {day_total} and {week_total}
+    fn expand_template(&self, template: &str) -> String {
+        let week_start = self.week_data.week_start;
+        let day_total: i64 = self.week_data.entries_for_day(self.selected_date).iter().map(|e| e.seconds).sum();
+        let week_total: i64 = self.week_data.total_seconds();
+        let total = |seconds: i64| format_duration_with_format(seconds, self.config.time_format, self.config.decimal_precision);
+        template
+            .replace("{date}", &self.selected_date.format("%Y-%m-%d").to_string())
+            .replace("{week_start}", &week_start.format("%Y-%m-%d").to_string())
+            .replace("{week_end}", &(week_start + Duration::days(6)).format("%Y-%m-%d").to_string())
+            .replace("{day_total}", &total(day_total))
+            .replace("{week_total}", &total(week_total))
+    }
+
+    /// Slide the current week out towards the side opposite `week_start_date`
+    fn start_week_slide(&mut self, week_start_date: NaiveDate) {
+        if week_start_date == self.week_data.week_start || self.loading || self.is_offline {
+            return;
+        }
+        self.week_slide = Some(WeekSlide {
+            direction: if week_start_date > self.week_data.week_start { 1.0 } else { -1.0 },
+            outgoing: Some((self.week_data.clone(), self.selected_date)),
+            incoming: false,
+            started: false,
+        });
+    }
+
+    fn navigate_back(&mut self) {
+        if let Some(previous) = self.navigation_history.pop_back() {
+            self.navigation_forward.push(self.week_data.week_start);
+            self.start_week_slide(previous);
+            self.selected_date = previous;
+            self.load_week(previous);
+        }
+    }
+
+    fn navigate_forward(&mut self) {
+        if let Some(next) = self.navigation_forward.pop() {
+            self.navigation_history.push_back(self.week_data.week_start);
+            self.start_week_slide(next);
+            self.selected_date = next;
+            self.load_week(next);
+        }
+    }
+
+    fn load_week(&mut self, week_start_date: NaiveDate) {
+        if !self.config.is_configured() {
+            return;
+        }
+
+        // If already loading, don't start another request
+        if self.loading {
+            // But still update UI state immediately
+            self.week_data.week_start = week_start_date;
+            return;
+        }
+
+        // Keep any local edits to the week being left in its cached copy (still dated by its fetch)
+        if let Some(cached) = self.week_cache.get_mut(&self.week_data.week_start) {
+            cached.week = self.week_data.clone();
+        }
+
+        // Clear entries immediately for snappy UI
+        self.week_data = WeekData::new(week_start_date);
+
+        self.loading = true;
+        self.progress = 0.0;
+        self.progress_phase = ProgressPhase::FastStart;
+        self.progress_start = std::time::Instant::now();
+
+        let config = self.config.clone();
+        let tx = self.result_tx.clone();
+
+        // Always load the full seven-day week
+        let start_date = week_start_date;
+        let end_date = week_start_date + Duration::days(6);
+
+        // Also load weekly buckets for quick-add buttons, and the team's worklogs alongside our own
+        self.load_weekly_buckets(week_start_date);
+        self.load_team_worklogs(week_start_date);
+
+        // Spawn async task
+        self.runtime.spawn(async move {
+            let result = async {
+                let client = JiraClient::new(&config)?;
+                let worklogs = client.get_my_worklogs(start_date, end_date).await?;
+                let buckets = client.get_time_buckets().await.unwrap_or_default();
+                Ok::<_, anyhow::Error>((worklogs, buckets))
+            }.await;
+
+            match result {
+                Ok((worklogs, buckets)) => {
+                    let week = WeekData {
+                        week_start: start_date,
+                        entries: worklogs
+                            .into_iter()
+                            .map(|(issue, worklog)| TimeEntry::from_worklog(issue, worklog))
+                            .collect(),
+                    };
+                    // Written here rather than on the UI thread, which would stall on a slow disk
+                    let fetched_at = Local::now();
+                    let _ = week.save_cached(&config.jira_domain, fetched_at);
+                    let _ = tx.send(AsyncResult::WorklogsLoaded(week.entries, buckets, fetched_at));
+                }
+                Err(e) => {
+                    if is_offline_error(&e) {
+                        let _ = tx.send(AsyncResult::Offline);
+                    } else {
+                        let _ = tx.send(AsyncResult::Error(format!("Error: {}", e)));
+                    }
+                }
+            }
+        });
+    }
+
+    /// Forget everything loaded from the previous Jira site or login
+    fn clear_jira_data(&mut self) {
+        self.week_data.entries.clear();
+        self.week_cache.clear();
+        self.team_entries.clear();
+        self.time_buckets.clear();
+        self.weekly_buckets.clear();
+        self.weekly_buckets_week = None;
+        self.issue_suggestions.clear();
+        self.validated_issue = None;
+        self.issue_details = None;
+        self.issue_history = None;
+        self.month_data = None;
+        self.month_requested = None;
+        self.month_failed = None;
+        self.last_sync_at = None;
+        // Issue keys from the old site mean nothing on the new one
+        self.recent_issues.clear();
+        self.config.recent_issues.clear();
+        // Offline changes wait for their own site; undo would act on the wrong one
+        self.pending = PendingQueue::load(&self.config.jira_domain).unwrap_or_default();
+        self.undo_stack = UndoStack::default();
+    }
+
+    /// Full domain from the settings subdomain input ("company" -> "company.atlassian.net")
+    fn settings_full_domain(&self) -> String {
+        if self.settings_domain.is_empty() || self.settings_domain.contains('.') {
+            self.settings_domain.clone()
+        } else {
+            format!("{}.atlassian.net", self.settings_domain)
+        }
+    }
+
+    /// Suggestions shown in the dropdown, narrowed by the project chip if one is picked
+    fn visible_suggestions(&self) -> Vec<&Issue> {
+        self.issue_suggestions.iter()
+            .filter(|i| match self.suggestion_project_filter.as_deref() {
+                Some(project) => i.project_key() == project,
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Category tags in settings as chips: drag to reorder, × to remove, and a field to add one
+    /// (the log dialog shows tags in this order)
+    fn render_tag_chips_editor(&mut self, ui: &mut egui::Ui) {
+        let pointer = ui.ctx().pointer_interact_pos();
+        if !ui.input(|i| i.pointer.primary_down()) {
+            self.tag_drag_idx = None;
+        }
+
+        // Tag names are matched case-insensitively in descriptions, so "fe" would shadow "FE"
+        let typed = TagDefinition::parse(&self.settings_new_tag);
+        let duplicate = self.settings_tags.iter().any(|t| t.name.eq_ignore_ascii_case(&typed.name));
+
+        let mut drag_started = None;
+        let mut hovered_while_dragging = None;
+        let mut remove = None;
+        let mut add = false;
+        ui.vertical(|ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.set_max_width(400.0);
+                ui.spacing_mut().item_spacing = egui::vec2(6.0, 6.0);
+                for (idx, tag) in self.settings_tags.iter().enumerate() {
+                    let dragging = self.tag_drag_idx == Some(idx);
+                    let fill = if dragging { Color32::from_rgb(19, 152, 244) } else { Color32::from_rgb(0x2a, 0x2a, 0x32) };
+                    // Keyed by name so a drag stays with its chip as the chips swap places
+                    let chip = ui.push_id(&tag.name, |ui| {
+                        egui::Frame::none()
+                            .fill(fill)
+                            .rounding(6.0)
+                            .inner_margin(egui::Margin::symmetric(8.0, 4.0))
+                            .show(ui, |ui| {
+                                ui.spacing_mut().item_spacing.x = 4.0;
+                                let handle = ui.add(egui::Label::new(
+                                    RichText::new(format!("{} {}", egui_phosphor::regular::DOTS_SIX_VERTICAL, tag.label())).size(13.0)
+                                ).sense(egui::Sense::drag()));
+                                if handle.hovered() || dragging {
+                                    ui.ctx().set_cursor_icon(if dragging { egui::CursorIcon::Grabbing } else { egui::CursorIcon::Grab });
+                                }
+                                if handle.drag_started() {
+                                    drag_started = Some(idx);
+                                }
+                                if ui.add(egui::Button::new(RichText::new(egui_phosphor::regular::X).size(11.0)).frame(false))
+                                    .on_hover_text("Remove")
+                                    .clicked()
+                                {
+                                    remove = Some(idx);
+                                }
+                            })
+                            .response
+                    }).inner;
+                    if self.tag_drag_idx.is_some() && !dragging && pointer.is_some_and(|pos| chip.rect.contains(pos)) {
+                        hovered_while_dragging = pointer.map(|pos| (idx, pos.x - chip.rect.center().x));
+                    }
+                }
+
+                let new_tag = ui.add(egui::TextEdit::singleline(&mut self.settings_new_tag)
+                    .hint_text("🐛 Bugfix")
+                    .desired_width(100.0));
+                let entered = new_tag.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                if ui.add_enabled(!duplicate, egui::Button::new(egui_phosphor::regular::PLUS)).on_hover_text("Add tag").clicked() || entered {
+                    add = true;
+                }
+            });
+            if duplicate {
+                ui.label(RichText::new(format!("\"{}\" is already a tag", typed.name))
+                    .size(12.0)
+                    .color(Color32::from_rgb(0xe5, 0x4d, 0x42)));
+            } else {
+                ui.label(RichText::new("Drag to reorder; an emoji before the name shows on the chip")
+                    .size(12.0)
+                    .color(Color32::from_rgb(150, 150, 150)));
+            }
+        });
+
+        if let Some(idx) = drag_started {
+            self.tag_drag_idx = Some(idx);
+        }
+        // Swap once the pointer crosses the target chip's center, so a swap doesn't
+        // put the chip back under the pointer and flip straight back next frame
+        if let (Some(from), Some((to, offset))) = (self.tag_drag_idx, hovered_while_dragging) {
+            if (to > from && offset > 0.0) || (to < from && offset < 0.0) {
+                self.settings_tags.swap(from, to);
+                self.tag_drag_idx = Some(to);
+            }
+        }
+        if let Some(idx) = remove {
+            let removed = self.settings_tags.remove(idx);
+            for rule in &mut self.settings_tag_auto_rules {
+                rule.tags.retain(|t| !t.eq_ignore_ascii_case(&removed.name));
+            }
+            self.tag_drag_idx = None;
+        }
+        // Re-checked against the text as typed this frame
+        let new_tag = TagDefinition::parse(&self.settings_new_tag);
+        if add && !new_tag.name.is_empty() && !self.settings_tags.iter().any(|t| t.name.eq_ignore_ascii_case(&new_tag.name)) {
+            self.settings_tags.push(new_tag);
+            self.settings_new_tag.clear();
+        }
+    }
+
+    /// Check the credentials typed in settings (before saving) by asking Jira who they belong to
+    fn test_connection(&mut self) {
+        let mut config = self.config.clone();
+        config.jira_domain = self.settings_full_domain();
+        config.email = self.settings_email.trim().to_string();
+        config.auth_method = self.settings_auth_method;
+        config.api_token = if self.settings_token.is_empty() {
+            self.settings_profiles.get(self.settings_active_profile).and_then(|p| p.api_token.clone())
+        } else {
+            Some(self.settings_token.clone())
+        };
+        self.connection_testing = true;
+        self.connection_test_result = None;
+        let tx = self.result_tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = async {
+                let client = JiraClient::new(&config)?;
+                client.get_myself().await
+            }.await;
+
+            let _ = match result {
+                Ok(user) => {
+                    let id = user.id().to_string();
+                    tx.send(AsyncResult::ConnectionTestOk(user.display_name.or(user.email_address).unwrap_or(id)))
+                }
+                Err(e) => tx.send(AsyncResult::ConnectionTestFailed(e.to_string())),
+            };
+        });
+    }
+
+    /// Switch the settings connection fields to another profile, keeping edits to the current one
+    fn select_settings_profile(&mut self, index: usize) {
+        let full_domain = self.settings_full_domain();
+        if let Some(current) = self.settings_profiles.get_mut(self.settings_active_profile) {
+            current.jira_domain = full_domain;
+            current.email = self.settings_email.clone();
+            current.auth_method = self.settings_auth_method;
+            if !self.settings_token.is_empty() {
+                current.api_token = Some(self.settings_token.clone());
+            }
+        }
+        if let Some(profile) = self.settings_profiles.get(index) {
+            self.settings_domain = profile.jira_domain.trim_end_matches(".atlassian.net").to_string();
+            self.settings_email = profile.email.clone();
+            self.settings_auth_method = profile.auth_method;
+            self.settings_token = String::new();
+            self.masked_token = profile.api_token.as_deref().map(views::masked_token).unwrap_or_default();
+            self.settings_active_profile = index;
+        }
+    }
+
+    fn save_settings(&mut self) {
+        let full_domain = self.settings_full_domain();
+
+        let api_token = if self.settings_token.is_empty() {
+            self.settings_profiles.get(self.settings_active_profile).and_then(|p| p.api_token.clone())
+        } else {
+            Some(self.settings_token.clone())
+        };
+
+        // Check if credentials changed (need to reload if so)
+        let credentials_changed =
+            self.config.jira_domain != full_domain
+            || self.config.email != self.settings_email
+            || self.config.auth_method != self.settings_auth_method
+            || self.config.api_token != api_token;
+
+        // The connection fields belong to the profile picked in settings
+        if self.settings_profiles.is_empty() {
+            self.settings_profiles.push(ConfigProfile { name: "Default".to_string(), ..Default::default() });
+            self.settings_active_profile = 0;
+        }
+        let active = self.settings_active_profile.min(self.settings_profiles.len() - 1);
+        self.settings_profiles[active].jira_domain = full_domain;
+        self.settings_profiles[active].email = self.settings_email.clone();
+        self.settings_profiles[active].auth_method = self.settings_auth_method;
+        self.settings_profiles[active].api_token = api_token;
+        for (i, profile) in self.settings_profiles.iter_mut().enumerate() {
+            if profile.name.trim().is_empty() {
+                profile.name = format!("Profile {}", i + 1);
+            }
+        }
+        self.config.profiles = self.settings_profiles.clone();
+        self.config.activate_profile(active);
+        self.active_profile_index = active;
+        self.config.font_scale = self.settings_font_scale;
+        self.config.time_format = self.settings_time_format;
+        self.config.clock_format = self.settings_clock_format;
+        self.config.show_start_time = self.settings_show_start_time;
+        self.config.show_status_bar = self.settings_show_status_bar;
+        self.config.decimal_precision = self.settings_decimal_precision;
+        self.config.min_schedule_block_px = self.settings_min_schedule_block_px;
+        self.config.show_block_label_threshold_px = self.settings_show_block_label_threshold_px;
+        self.config.default_start_time = if self.settings_default_start_time.trim().is_empty() {
+            "09:00".to_string()
+        } else {
+            self.settings_default_start_time.trim().to_string()
+        };
+        let export_directory = self.settings_export_directory.trim();
+        self.config.export_directory = (!export_directory.is_empty()).then(|| export_directory.to_string());
+        self.config.connect_timeout_secs = self.settings_connect_timeout_secs;
+        self.config.request_timeout_secs = self.settings_request_timeout_secs;
+        self.config.max_retries = self.settings_max_retries;
+        let channel_changed = self.config.update_channel != self.settings_update_channel;
+        self.config.update_channel = self.settings_update_channel;
+        self.config.colorblind_mode = self.settings_colorblind_mode;
+        self.config.weekend_display = self.settings_weekend_display;
+        let week_start_changed = self.config.week_start_day != self.settings_week_start_day;
+        self.config.week_start_day = self.settings_week_start_day;
+        self.config.link_behavior = self.settings_link_behavior;
+        // Drop rows left empty in the editor
+        self.config.quick_actions = self.settings_quick_actions.iter()
+            .filter(|a| !a.action.value().trim().is_empty())
+            .cloned()
+            .collect();
+        // Only keep colors that differ from the built-in ones
+        self.config.issue_type_colors = self.settings_issue_type_colors.iter()
+            .filter(|(name, _)| !name.trim().is_empty())
+            .filter(|(name, rgb)| {
+                let built_in = DEFAULT_ISSUE_TYPE_COLORS.iter().any(|(n, _)| n.eq_ignore_ascii_case(name.trim()));
+                !built_in || *rgb != default_issue_type_color(name.trim())
+            })
+            .map(|(name, rgb)| (name.trim().to_string(), *rgb))
+            .collect();
+        // Accept "PROJ" or "PROJ-" and match keys case-insensitively
+        self.config.project_colors = self.settings_project_colors.iter()
+            .map(|(prefix, rgb)| (prefix.trim().trim_end_matches('-').to_uppercase(), *rgb))
+            .filter(|(prefix, _)| !prefix.is_empty())
+            .collect();
+        self.config.daily_target_hours = self.settings_daily_target_hours;
+        self.config.tags = self.settings_tags.clone();
+        // Drop rules without a prefix or any tags, and names no longer in the tag list
+        let tags = &self.config.tags;
+        self.config.tag_auto_rules = self.settings_tag_auto_rules.iter()
+            .map(|rule| TagAutoRule::new(
+                rule.project_prefix.trim(),
+                rule.tags.iter().filter(|t| tags.iter().any(|tag| tag.name.eq_ignore_ascii_case(t))).cloned().collect(),
+            ))
+            .filter(|rule| !rule.project_prefix.is_empty() && !rule.tags.is_empty())
+            .collect();
+        // Update dialog_categories to match new tag count
+        self.dialog_categories = vec![false; self.config.tags.len()];
+
+        // Sprints belong to the old site's boards
+        if credentials_changed {
+            self.config.cached_sprints.clear();
+            self.config.cached_sprints_at = None;
+        }
+
+        match self.config.save() {
+            Ok(_) => {
+                self.show_settings = false;
+                // Transition from Setup to Main if now configured
+                if self.config.is_configured() && self.state == AppState::Setup {
+                    self.state = AppState::Main;
+                }
+                if credentials_changed {
+                    self.clear_jira_data();
+                }
+                // Cached weeks and history are keyed by the old start day
+                if week_start_changed {
+                    self.week_cache.clear();
+                    self.navigation_history.clear();
+                    self.navigation_forward.clear();
+                    self.week_data = WeekData::new(week_start(self.selected_date, self.config.week_start_day));
+                }
+                if credentials_changed || week_start_changed {
+                    self.refresh_data();
+                }
+                if credentials_changed {
+                    self.load_sprints();
+                }
+                if channel_changed {
+                    // Re-check so the indicator reflects the new channel
+                    self.update_info = None;
+                    self.update_checking = false;
+                    self.check_for_updates();
+                }
+            }
+            Err(e) => {
+                self.status_message = Some((format!("Failed to save: {}", e), true));
+            }
+        }
+    }
+
+    /// Fetch every team member's worklogs for the week, one request per member in parallel
+    fn load_team_worklogs(&mut self, week_start: NaiveDate) {
+        self.team_entries.clear();
+        if self.config.team_members.is_empty() {
+            return;
+        }
+
+        let config = self.config.clone();
+        let tx = self.result_tx.clone();
+        let end = week_start + Duration::days(6);
+
+        self.runtime.spawn(async move {
+            let Ok(client) = JiraClient::new(&config) else {
+                return;
+            };
+            let client = std::sync::Arc::new(client);
+            let handles: Vec<_> = config.team_members.iter()
+                .map(|member| {
+                    let client = client.clone();
+                    let account_id = member.jira_account_id.clone();
+                    tokio::spawn(async move {
+                        client.get_user_worklogs(&account_id, week_start, end).await
+                    })
+                })
+                .collect();
+
+            let mut team = Vec::new();
+            for (member, handle) in config.team_members.iter().zip(handles) {
+                // A member that fails to load (permissions, bad ID) just shows an empty column
+                let entries = match handle.await {
+                    Ok(Ok(worklogs)) => worklogs.into_iter()
+                        .map(|(issue, worklog)| TimeEntry::from_worklog(issue, worklog))
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                team.push((member.display_name.clone(), entries));
+            }
+            let _ = tx.send(AsyncResult::TeamWorklogsLoaded(week_start, team));
+        });
+    }
+
+    /// Refresh the active sprint cache once it has expired
+    fn load_sprints(&mut self) {
+        if !self.config.sprints_expired() {
+            return;
+        }
+
+        let config = self.config.clone();
+        let tx = self.result_tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = async {
+                let client = JiraClient::new(&config)?;
+                client.get_active_sprints().await
+            }.await;
+
+            // Silently fail - boards without Jira Software (or permission) just show no badge
+            if let Ok(sprints) = result {
+                let _ = tx.send(AsyncResult::SprintsLoaded(sprints));
+            }
+        });
+    }
+
+    /// Fetch the shared team config (at most once per REMOTE_CONFIG_REFRESH_HOURS)
+    fn load_remote_config(&mut self) {
+        if !self.config.remote_config_due() {
+            return;
+        }
+        let Some(url) = self.config.remote_config_url.clone() else {
+            return;
+        };
+
+        let tx = self.result_tx.clone();
+        self.runtime.spawn(async move {
+            // Plain client - the URL is public (static file or gist), no Jira credentials
+            let result = async {
+                let response = reqwest::Client::new().get(url.trim()).send().await?.error_for_status()?;
+                response.json::<RemoteConfig>().await
+            }.await;
+
+            // Silently fail - the local config keeps working, and the next launch tries again
+            if let Ok(remote) = result {
+                let _ = tx.send(AsyncResult::RemoteConfigLoaded(remote));
+            }
+        });
+    }
+
+    /// Fetch every worklog we've ever logged to the details issue, for its history list
+    /// `template` supplies the issue fields (summary, type, time tracking) for the entries
+    fn load_issue_history(&mut self, template: TimeEntry) {
+        let issue_key = template.issue_key.clone();
+        self.issue_history = Some((issue_key.clone(), None));
+
+        let config = self.config.clone();
+        let tx = self.result_tx.clone();
+        self.runtime.spawn(async move {
+            let result = async {
+                let client = JiraClient::new(&config)?;
+                let myself = client.get_myself().await?;
+                client.get_issue_worklogs(&issue_key).await.map(|worklogs| (myself, worklogs))
+            }.await;
+
+            match result {
+                Ok((myself, worklogs)) => {
+                    let mut entries: Vec<TimeEntry> = worklogs.into_iter()
+                        .filter(|w| w.author.id() == myself.id())
+                        .map(|w| TimeEntry {
+                            worklog_id: w.id.clone(),
+                            seconds: w.time_spent_seconds,
+                            description: w.comment_text(),
+                            date: parse_date(&w.started),
+                            start_time: extract_time(&w.started),
+                            custom_properties: w.custom_properties(),
+                            ..template.clone()
+                        })
+                        .collect();
+                    entries.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| b.start_time.cmp(&a.start_time)));
+                    let _ = tx.send(AsyncResult::IssueWorklogHistory(issue_key, entries));
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncResult::IssueWorklogHistoryFailed(issue_key, e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Details window for an issue: summary, estimate, this week's logs and all of our logs, with a link to Jira
+    fn render_issue_details(&mut self, ctx: &egui::Context, issue_key: &str) {
+        let (content_bg, frame_color, frame_text) = super::theme::dialog_colors();
+        let dialog_frame = egui::Frame::none()
+            .fill(content_bg)
+            .stroke(egui::Stroke::new(2.0, frame_color))
+            .rounding(egui::Rounding::same(8.0))
+            .inner_margin(egui::Margin::same(20.0));
+
+        if self.issue_history.as_ref().map(|(key, _)| key.as_str()) != Some(issue_key) {
+            if let Some(first) = self.week_data.entries.iter().find(|e| e.issue_key == issue_key).cloned() {
+                self.load_issue_history(first);
+            }
+        }
+
+        let entries: Vec<&TimeEntry> = self.week_data.entries.iter()
+            .filter(|e| e.issue_key == issue_key)
+            .collect();
+        let secondary_color = Color32::from_rgb(150, 150, 150);
+
+        let history = self.issue_history.as_ref().and_then(|(_, history)| history.as_ref());
+
+        let mut open = true;
+        let mut open_in_jira = false;
+        let mut edit_entry = None;
+        egui::Window::new(issue_key)
+            .id(egui::Id::new("issue_details"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(380.0)
+            .anchor(egui::Align2::RIGHT_TOP, [-16.0, 60.0])
+            .frame(dialog_frame)
+            .show(ctx, |ui| {
+                ui.set_max_width(380.0);
+                let Some(first) = entries.first() else {
+                    ui.label(RichText::new("No logs for this issue in the displayed week").color(secondary_color));
+                    return;
+                };
+
+                ui.add(egui::Label::new(RichText::new(&first.issue_summary).size(15.0).color(Color32::WHITE)).wrap());
+                ui.label(RichText::new(&first.issue_type).size(12.0).color(frame_text));
+
+                if first.time_original_estimate > 0 {
+                    ui.add_space(8.0);
+                    views::render_estimate_progress(ui, first.time_spent_total, first.time_original_estimate, 0, 340.0);
+                }
+
+                ui.add_space(12.0);
+                let week_total: i64 = entries.iter().map(|e| e.seconds).sum();
+                ui.label(RichText::new(format!("This week: {}", format_duration_with_format(week_total, self.config.time_format, self.config.decimal_precision)))
+                    .size(14.0)
+                    .color(Color32::WHITE)
+                    .family(super::theme::bold_family()));
+                egui::Grid::new("issue_details_logs")
+                    .num_columns(3)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        for entry in &entries {
+                            ui.label(RichText::new(entry.date.format("%a %b %-d").to_string()).color(secondary_color));
+                            ui.label(format_duration_with_format(entry.seconds, self.config.time_format, self.config.decimal_precision));
+                            ui.add(egui::Label::new(entry.description.trim()).truncate());
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(12.0);
+                ui.label(RichText::new("All my logs")
+                    .size(14.0)
+                    .color(Color32::WHITE)
+                    .family(super::theme::bold_family()));
+                match history {
+                    None => {
+                        ui.spinner();
+                    }
+                    Some(Err(error)) => {
+                        ui.add(egui::Label::new(RichText::new(format!("Couldn't load the history: {}", error)).size(12.0).color(Color32::from_rgb(224, 108, 117))).wrap());
+                    }
+                    Some(Ok(history)) => {
+                        let total: i64 = history.iter().map(|e| e.seconds).sum();
+                        ui.label(RichText::new(format!("{} logs, {} in total", history.len(), format_duration_with_format(total, self.config.time_format, self.config.decimal_precision)))
+                            .size(12.0)
+                            .color(secondary_color));
+                        egui::ScrollArea::vertical()
+                            .id_salt("issue_history")
+                            .max_height(240.0)
+                            .show(ui, |ui| {
+                                egui::Grid::new("issue_history_logs")
+                                    .num_columns(4)
+                                    .spacing([12.0, 6.0])
+                                    .show(ui, |ui| {
+                                        for entry in history {
+                                            ui.label(RichText::new(entry.date.format("%Y-%m-%d").to_string()).color(secondary_color));
+                                            ui.label(format_duration_with_format(entry.seconds, self.config.time_format, self.config.decimal_precision));
+                                            ui.add(egui::Label::new(entry.description.trim()).truncate());
+                                            if ui.small_button(egui_phosphor::regular::PENCIL_SIMPLE).on_hover_text("Edit log").clicked() {
+                                                edit_entry = Some(entry.clone());
+                                            }
+                                            ui.end_row();
+                                        }
+                                    });
+                            });
+                    }
+                }
+
+                ui.add_space(12.0);
+                if ui.button(format!("{} Open in Jira", egui_phosphor::regular::ARROW_SQUARE_OUT)).clicked() {
+                    open_in_jira = true;
+                }
+            });
+
+        if open_in_jira {
+            let _ = open::that(format!("https://{}/browse/{}", self.config.jira_domain, issue_key));
+        }
+        if let Some(entry) = edit_entry {
+            self.open_edit_dialog(&entry);
+        }
+        if !open || (!self.show_dialog && ctx.input(|i| i.key_pressed(egui::Key::Escape))) {
+            self.issue_details = None;
+        }
+    }
+
+    /// Small "Private note" editor for one worklog; saving writes annotations.json
+    fn render_annotation_editor(&mut self, ctx: &egui::Context) {
+        let (content_bg, frame_color, frame_text) = super::theme::dialog_colors();
+        let dialog_frame = egui::Frame::none()
+            .fill(content_bg)
+            .stroke(egui::Stroke::new(2.0, frame_color))
+            .rounding(egui::Rounding::same(8.0))
+            .inner_margin(egui::Margin::same(20.0));
+
+        let Some((worklog_id, text)) = self.annotation_edit.as_mut() else {
+            return;
+        };
+        let mut save = false;
+        let mut cancel = false;
+        egui::Window::new("Private note")
+            .id(egui::Id::new("annotation_editor"))
+            .collapsible(false)
+            .resizable(false)
+            .default_width(320.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .frame(dialog_frame)
+            .show(ctx, |ui| {
+                ui.label(RichText::new("Only stored on this computer, never sent to Jira").size(12.0).color(frame_text));
+                ui.add_space(8.0);
+                let response = ui.add(egui::TextEdit::multiline(text)
+                    .desired_width(320.0)
+                    .desired_rows(4)
+                    .hint_text("Note"));
+                if !response.has_focus() && ui.memory(|m| m.focused().is_none()) {
+                    response.request_focus();
+                }
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        save = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+
+        if save {
+            self.annotations.set(worklog_id, text);
+            if let Err(e) = self.annotations.save() {
+                self.status_message = Some((format!("Failed to save note: {}", e), true));
+            }
+            self.annotation_edit = None;
+        } else if cancel || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.annotation_edit = None;
+        }
+    }
+
+    /// Bottom bar: today's and the week's totals, today's entry count, and the last sync time
+    /// (or the latest confirmation message, click to dismiss)
+    fn render_status_bar(&mut self, ctx: &egui::Context) {
+        let (_, _, secondary_color) = super::theme::entry_colors();
+        let font = egui::FontId::proportional(11.0);
+
+        // Today's week may not be the one displayed
+        let today = Local::now().date_naive();
+        let today_week_start = week_start(today, self.config.week_start_day);
+        let today_week = if self.week_data.week_start == today_week_start {
+            Some(&self.week_data)
+        } else {
+            self.week_cache.get(&today_week_start).map(|cached| &cached.week)
+        };
+        let today_seconds = today_week.map(|w| w.seconds_for_day(today)).unwrap_or(0);
+        let today_entries = today_week.map(|w| w.entries_for_day(today).len()).unwrap_or(0);
+        let week_seconds = self.week_data.total_seconds();
+        let time_format = self.config.time_format;
+        let decimal_precision = self.config.decimal_precision;
+
+        let sync_text = match self.last_sync_at {
+            Some(at) => {
+                let minutes = (Local::now() - at).num_minutes();
+                if minutes < 1 {
+                    "Synced just now".to_string()
+                } else if minutes < 60 {
+                    format!("Synced {} min ago", minutes)
+                } else {
+                    format!("Synced {}h ago", minutes / 60)
+                }
+            }
+            None => "Not synced yet".to_string(),
+        };
+        let message = self.status_message.as_ref()
+            .filter(|(_, is_error)| !is_error)
+            .map(|(msg, _)| msg.clone());
+
+        let mut dismiss = false;
+        egui::TopBottomPanel::bottom("status_bar")
+            .exact_height(24.0)
+            .frame(egui::Frame::none()
+                .fill(ctx.style().visuals.panel_fill)
+                .inner_margin(egui::Margin::symmetric(12.0, 0.0)))
+            .show(ctx, |ui| {
+                ui.horizontal_centered(|ui| {
+                    let plural = if today_entries == 1 { "entry" } else { "entries" };
+                    ui.label(RichText::new(format!(
+                        "Today {}  ·  Week {}  ·  {} {} today",
+                        format_duration_with_format(today_seconds, time_format, decimal_precision),
+                        format_duration_with_format(week_seconds, time_format, decimal_precision),
+                        today_entries,
+                        plural,
+                    )).font(font.clone()).color(secondary_color));
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        match &message {
+                            Some(msg) => {
+                                let response = ui.add(egui::Label::new(
+                                    RichText::new(msg).font(font.clone()).color(Color32::from_rgb(152, 195, 121))
+                                ).truncate().sense(egui::Sense::click()));
+                                if response.on_hover_text("Click to dismiss").clicked() {
+                                    dismiss = true;
+                                }
+                            }
+                            None => {
+                                ui.label(RichText::new(&sync_text).font(font.clone()).color(secondary_color));
+                            }
+                        }
+                    });
+                });
+            });
+
+        if dismiss {
+            self.status_message = None;
+        }
+        // Keep "Synced N min ago" current
+        ctx.request_repaint_after(std::time::Duration::from_secs(30));
+    }
+
+    /// Names of cached sprints running during the displayed week
+    fn sprints_in_week(&self) -> Vec<&str> {
+        let start = self.week_data.week_start;
+        let end = start + Duration::days(6);
+        self.config.cached_sprints.iter()
+            .filter(|s| s.start_date.map(|d| d <= end).unwrap_or(true) && s.end_date.map(|d| d >= start).unwrap_or(true))
+            .map(|s| s.name.as_str())
+            .collect()
+    }
+
+    fn load_weekly_buckets(&mut self, week_start_date: NaiveDate) {
+        // Skip if already have buckets for this week
+        if self.weekly_buckets_week == Some(week_start_date) {
+            return;
+        }
+        // Drop the previous week's buckets so the dialog never offers stale quick-add tickets
+        self.weekly_buckets.clear();
+        self.weekly_buckets_week = None;
+        // An in-flight load for another week reloads the current week when it lands
+        if self.weekly_buckets_loading {
+            return;
+        }
+
+        self.weekly_buckets_loading = true;
+
+        let config = self.config.clone();
+        let tx = self.result_tx.clone();
+        // Bucket tickets are named after the Monday, even in Sunday-first weeks
+        let monday = week_start(week_start_date + Duration::days(1), Weekday::Mon);
+
+        self.runtime.spawn(async move {
+            let result = async {
+                let client = JiraClient::new(&config)?;
+                client.search_all_weekly_buckets(monday).await
+            }.await;
+
+            match result {
+                Ok(buckets) => {
+                    let bucket_data: Vec<(String, String, String, String)> = buckets
+                        .into_iter()
+                        .map(|(cat, issue)| {
+                            let issue_type = issue.fields.issue_type
+                                .map(|t| t.name)
+                                .unwrap_or_else(|| "Task".to_string());
+                            (cat, issue.key, issue.fields.summary, issue_type)
+                        })
+                        .collect();
+                    let _ = tx.send(AsyncResult::WeeklyBucketsLoaded(week_start_date, bucket_data));
+                }
+                Err(_) => {
+                    // Silently fail - buckets are optional
+                    let _ = tx.send(AsyncResult::WeeklyBucketsLoaded(week_start_date, Vec::new()));
                 }
             }
         });
@@ -696,96 +2314,342 @@ impl JiraTimeApp {
     fn open_add_dialog(&mut self) {
         self.dialog_mode = DialogMode::Add;
         self.dialog_hours = String::new();
+        self.dialog_timer = TimerState::Idle;
         self.dialog_issue = String::new();
         self.dialog_description = String::new();
+        self.dialog_description_preview = false;
         self.dialog_worklog_id = String::new();
-        self.dialog_start_time = String::new();
+        // Schedule clicks overwrite this with the clicked slot
+        self.dialog_start_time = if self.config.show_start_time {
+            self.config.default_start_time.clone()
+        } else {
+            String::new()
+        };
         self.dialog_categories = vec![false; self.config.tags.len()];
         self.dialog_accent_color = None;
         self.error_issue = false;
         self.error_hours = false;
+        self.error_start_time = false;
         self.issue_suggestions = Vec::new();
         self.show_suggestions = false;
+        self.autocomplete_selection = None;
         self.last_issue_search = String::new();
         self.validated_issue = None;
+        self.suggestion_project_filter = None;
+        self.focus_duration_pending = false;
+        self.focus_issue_pending = true;
+        self.issue_from_draft = false;
+        self.dialog_opened_input = None;
+        if self.dialog_draft.as_ref().is_some_and(|draft| draft.saved_at.elapsed() > DIALOG_DRAFT_MAX_AGE) {
+            self.dialog_draft = None;
+        }
         self.show_dialog = true;
-        // Load recent issues immediately
-        self.search_issues("");
+        // Pre-fill an issue key copied from Jira, otherwise load recent issues immediately
+        if let Some(key) = Self::clipboard_issue_key() {
+            self.dialog_issue = key.clone();
+            self.issue_from_clipboard = true;
+            self.search_issues(&key);
+        } else {
+            self.issue_from_clipboard = false;
+            self.search_issues("");
+        }
+    }
+
+    /// Move an issue to the front of the recent chips (kept in the config between launches)
+    fn remember_recent_issue(&mut self, entry: &TimeEntry) {
+        self.recent_issues.retain(|(key, _, _)| *key != entry.issue_key);
+        self.recent_issues.push_front((entry.issue_key.clone(), entry.issue_summary.clone(), entry.issue_type.clone()));
+        self.recent_issues.truncate(MAX_RECENT_ISSUES);
+        self.config.recent_issues = self.recent_issues.iter().cloned().collect();
+        let _ = self.config.save();
+    }
+
+    /// Issue type color rows for the settings table: the built-in types (with any override), then custom types
+    fn issue_type_color_rows(config: &Config) -> Vec<(String, [u8; 3])> {
+        let color_for = |name: &str| issue_type_color(name, &config.issue_type_colors);
+        let mut rows: Vec<(String, [u8; 3])> = DEFAULT_ISSUE_TYPE_COLORS.iter()
+            .map(|(name, _)| (name.to_string(), color_for(name)))
+            .collect();
+        let mut custom: Vec<(String, [u8; 3])> = config.issue_type_colors.iter()
+            .filter(|(name, _)| !DEFAULT_ISSUE_TYPE_COLORS.iter().any(|(n, _)| n.eq_ignore_ascii_case(name)))
+            .map(|(name, rgb)| (name.clone(), *rgb))
+            .collect();
+        custom.sort();
+        rows.extend(custom);
+        rows
+    }
+
+    /// Tick the tags of every auto rule matching the validated issue (suggestions only - never unticks)
+    fn apply_tag_auto_rules(&mut self) {
+        if self.dialog_mode != DialogMode::Add {
+            return;
+        }
+        let mut categories = std::mem::take(&mut self.dialog_categories);
+        self.mark_auto_rule_tags(&mut categories);
+        self.dialog_categories = categories;
+    }
+
+    /// Tick the tags the auto rules pick for the validated issue
+    fn mark_auto_rule_tags(&self, categories: &mut [bool]) {
+        let Some(issue) = &self.validated_issue else {
+            return;
+        };
+        for rule in &self.config.tag_auto_rules {
+            let prefix = rule.project_prefix.trim();
+            if prefix.is_empty() || !issue.key.to_uppercase().starts_with(&prefix.to_uppercase()) {
+                continue;
+            }
+            for (selected, tag) in categories.iter_mut().zip(&self.config.tags) {
+                if rule.applies_tag(&tag.name) {
+                    *selected = true;
+                }
+            }
+        }
+    }
+
+    /// The add dialog's current input
+    fn dialog_input(&self) -> DialogDraft {
+        DialogDraft {
+            issue: self.dialog_issue.clone(),
+            hours: self.dialog_hours.clone(),
+            description: self.dialog_description.clone(),
+            categories: self.dialog_categories.clone(),
+            start_time: self.dialog_start_time.clone(),
+            saved_at: Instant::now(),
+        }
+    }
+
+    /// Remember the add dialog's input when it is cancelled with anything changed since it opened
+    /// (a clipboard issue, a clicked schedule slot or the auto-rule tags alone don't count)
+    fn stash_dialog_draft(&mut self) {
+        let Some(opened) = self.dialog_opened_input.take() else {
+            return;
+        };
+        if self.dialog_mode != DialogMode::Add {
+            return;
+        }
+        let mut auto_categories = opened.categories.clone();
+        self.mark_auto_rule_tags(&mut auto_categories);
+        let has_input = !self.dialog_issue.trim().eq_ignore_ascii_case(opened.issue.trim())
+            || self.dialog_hours.trim() != opened.hours.trim()
+            || self.dialog_description.trim() != opened.description.trim()
+            || self.dialog_start_time.trim() != opened.start_time.trim()
+            || (self.dialog_categories != opened.categories && self.dialog_categories != auto_categories);
+        if has_input {
+            self.dialog_draft = Some(self.dialog_input());
+        }
+    }
+
+    /// Fill the add dialog from the saved draft
+    fn restore_dialog_draft(&mut self) {
+        let Some(draft) = self.dialog_draft.take() else {
+            return;
+        };
+        self.dialog_hours = draft.hours;
+        self.dialog_description = draft.description;
+        self.dialog_start_time = draft.start_time;
+        // Tags may have been edited since the draft was made
+        self.dialog_categories = draft.categories;
+        self.dialog_categories.resize(self.config.tags.len(), false);
+        self.issue_from_clipboard = false;
+        self.issue_from_draft = true;
+        self.validated_issue = None;
+        self.dialog_issue = draft.issue;
+        if !self.dialog_issue.is_empty() {
+            // Exact matches in the results validate the issue
+            self.search_issues(&self.dialog_issue.clone());
+        }
+        self.focus_duration_pending = !self.dialog_issue.is_empty() && self.dialog_hours.is_empty();
+    }
+
+    /// Read the clipboard and return its contents if it is exactly an issue key (e.g. "PROJ-123")
+    fn clipboard_issue_key() -> Option<String> {
+        let text = arboard::Clipboard::new().ok()?.get_text().ok()?;
+        let text = text.trim();
+        let (project, number) = text.split_once('-')?;
+        let valid = !project.is_empty()
+            && project.chars().all(|c| c.is_ascii_uppercase())
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit());
+        valid.then(|| text.to_string())
+    }
+
+    /// Add dialog pre-filled from an existing entry, to log the same work again on the selected day
+    fn open_duplicate_dialog(&mut self, entry: &TimeEntry) {
+        self.open_add_dialog();
+        self.issue_from_clipboard = false;
+        self.focus_issue_pending = false;
+        self.focus_duration_pending = true;
+        self.dialog_hours = views::exact_duration_text(entry.seconds, self.config.time_format, self.config.decimal_precision);
+        self.dialog_issue = entry.issue_key.clone();
+        let (categories, desc) = parse_categories_from_description(&entry.description, &self.config.tags);
+        self.dialog_categories = categories;
+        self.dialog_description = desc;
+        self.dialog_description_preview = false;
+        self.dialog_start_time = entry.start_time.clone();
+        self.dialog_accent_color = match super::theme::bucket_kind(&entry.issue_key, &entry.issue_summary, &self.config.project_colors) {
+            BucketKind::Regular => None,
+            kind => Some(super::theme::dialog_bucket_color(kind, self.config.colorblind_mode)),
+        };
+        self.validated_issue = Some(ValidatedIssue {
+            key: entry.issue_key.clone(),
+            summary: entry.issue_summary.clone(),
+            issue_type: entry.issue_type.clone(),
+            status: entry.issue_status.clone(),
+            time_spent: entry.time_spent_total,
+            original_estimate: entry.time_original_estimate,
+        });
     }
 
     fn open_edit_dialog(&mut self, entry: &TimeEntry) {
         self.dialog_mode = DialogMode::Edit;
-        self.dialog_hours = format_duration_with_format(entry.seconds, self.config.time_format);
+        self.dialog_hours = views::exact_duration_text(entry.seconds, self.config.time_format, self.config.decimal_precision);
         self.dialog_issue = entry.issue_key.clone();
 
         // Parse categories from description and extract remaining text
-        let (categories, desc) = Self::parse_categories_from_description(&entry.description, &self.config.tags);
+        let (categories, desc) = parse_categories_from_description(&entry.description, &self.config.tags);
         self.dialog_categories = categories;
         self.dialog_description = desc;
+        self.dialog_description_preview = false;
 
         self.dialog_worklog_id = entry.worklog_id.clone();
+        self.dialog_worklog_date = entry.date;
         self.dialog_start_time = entry.start_time.clone();  // Pre-fill with current start time
         // Set accent color based on ticket type (same logic as entry cards)
-        self.dialog_accent_color = if entry.issue_key.starts_with("TIM-") {
-            let summary_upper = entry.issue_summary.to_uppercase();
-            if summary_upper.contains("MEETING") {
-                Some(Color32::from_rgb(0xdc, 0x26, 0x7f))  // Pink
-            } else if summary_upper.contains("SUPPORT") {
-                Some(Color32::from_rgb(0xfe, 0x61, 0x00))  // Orange
-            } else if summary_upper.contains("ADMIN") {
-                Some(Color32::from_rgb(0xff, 0xb0, 0x00))  // Yellow
-            } else {
-                None  // Default blue will be used
-            }
-        } else {
-            None  // Default blue for regular tickets
+        self.dialog_accent_color = match super::theme::bucket_kind(&entry.issue_key, &entry.issue_summary, &self.config.project_colors) {
+            BucketKind::Regular => None,  // Default blue for regular tickets
+            kind => Some(super::theme::dialog_bucket_color(kind, self.config.colorblind_mode)),
         };
         self.error_issue = false;
         self.error_hours = false;
+        self.error_start_time = false;
         self.issue_suggestions = Vec::new();
         self.show_suggestions = false;
-        self.validated_issue = Some((entry.issue_key.clone(), entry.issue_summary.clone(), entry.issue_type.clone()));
+        self.autocomplete_selection = None;
+        self.validated_issue = Some(ValidatedIssue {
+            key: entry.issue_key.clone(),
+            summary: entry.issue_summary.clone(),
+            issue_type: entry.issue_type.clone(),
+            status: entry.issue_status.clone(),
+            time_spent: entry.time_spent_total,
+            original_estimate: entry.time_original_estimate,
+        });
         self.show_dialog = true;
     }
 
-    /// Parse category tags like [FE][BE] from the start of a description
-    fn parse_categories_from_description(description: &str, tags: &[String]) -> (Vec<bool>, String) {
-        let mut categories = vec![false; tags.len()];
-        let mut remaining = description.trim();
+    /// Remember the window's placement, saving once it has stopped moving for WINDOW_STATE_SAVE_DELAY
+    fn track_window_state(&mut self, ctx: &egui::Context) {
+        let (outer, inner, native_ppp, maximized, minimized) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (
+                viewport.outer_rect,
+                viewport.inner_rect,
+                viewport.native_pixels_per_point,
+                viewport.maximized.unwrap_or(false),
+                viewport.minimized.unwrap_or(false),
+            )
+        });
+        // Maximized and minimized sizes aren't worth restoring
+        let (Some(outer), Some(inner), Some(native_ppp)) = (outer, inner, native_ppp) else {
+            return;
+        };
+        if maximized || minimized {
+            self.window_state_pending = None;
+            return;
+        }
 
-        // Parse all tags at the start of the description
-        loop {
-            let trimmed = remaining.trim_start();
-            if !trimmed.starts_with('[') {
-                remaining = trimmed;
-                break;
-            }
+        // Rects are in egui points, which the zoom level scales; the viewport wants logical pixels
+        let to_logical = ctx.pixels_per_point() / native_ppp;
+        let current = WindowState {
+            x: outer.min.x * to_logical,
+            y: outer.min.y * to_logical,
+            width: inner.width() * to_logical,
+            height: inner.height() * to_logical,
+        };
+        if self.config.window_state == Some(current) {
+            self.window_state_pending = None;
+            return;
+        }
 
-            if let Some(end) = trimmed.find(']') {
-                let tag = &trimmed[1..end];
-                // Check if this matches one of our tags (case-insensitive)
-                let mut found = false;
-                for (i, cat) in tags.iter().enumerate() {
-                    if tag.eq_ignore_ascii_case(cat) {
-                        categories[i] = true;
-                        found = true;
-                        break;
-                    }
-                }
-                if found {
-                    remaining = &trimmed[end + 1..];
-                    // Skip any separator after the tag (space, dash, etc.)
-                    remaining = remaining.trim_start_matches(|c: char| c == ' ' || c == '-');
+        match self.window_state_pending {
+            Some((pending, since)) if pending == current => {
+                if since.elapsed() >= WINDOW_STATE_SAVE_DELAY {
+                    self.config.window_state = Some(current);
+                    let _ = self.config.save();
+                    self.window_state_pending = None;
                 } else {
-                    // Unknown tag - stop parsing
-                    break;
+                    ctx.request_repaint_after(WINDOW_STATE_SAVE_DELAY - since.elapsed());
                 }
-            } else {
-                break;
             }
+            _ => {
+                self.window_state_pending = Some((current, Instant::now()));
+                ctx.request_repaint_after(WINDOW_STATE_SAVE_DELAY);
+            }
+        }
+    }
+
+    /// Reverse the most recent worklog change (Ctrl+Z)
+    fn undo_last(&mut self) {
+        if self.loading || self.undoing {
+            return;
         }
+        let Some(undo) = self.undo_stack.pop() else {
+            self.status_message = Some(("Nothing to undo".to_string(), false));
+            return;
+        };
+
+        self.undoing = true;
+        let action = undo.action();
+        let entry = undo.into_entry();
+        let is_edit = match action {
+            UndoAction::Delete => {
+                self.delete_worklog(&entry);
+                return;
+            }
+            UndoAction::Update => true,
+            UndoAction::Relog => false,
+        };
+
+        self.loading = true;
+        self.progress = 0.0;
+        self.progress_phase = ProgressPhase::FastStart;
+        self.progress_start = std::time::Instant::now();
 
-        (categories, remaining.to_string())
+        let config = self.config.clone();
+        let tx = self.result_tx.clone();
+        self.runtime.spawn(async move {
+            let old_id = entry.worklog_id.clone();
+            let result: Result<TimeEntry, anyhow::Error> = async {
+                let client = JiraClient::new(&config)?;
+                let start_time = (!entry.start_time.is_empty()).then_some(entry.start_time.as_str());
+                let worklog = if is_edit {
+                    client.update_worklog(&entry.issue_key, &entry.worklog_id, entry.seconds, &entry.description, entry.date, start_time).await?
+                } else {
+                    client.log_time(&entry.issue_key, entry.seconds, entry.date, &entry.description, start_time).await?
+                };
+                Ok(TimeEntry {
+                    worklog_id: worklog.id.clone(),
+                    start_time: extract_time(&worklog.started),
+                    ..entry
+                })
+            }.await;
+
+            match result {
+                Ok(entry) => {
+                    if entry.worklog_id != old_id {
+                        let _ = tx.send(AsyncResult::WorklogRelogged(old_id, entry.worklog_id.clone()));
+                    }
+                    let _ = tx.send(AsyncResult::WorklogSaved("Undone".to_string(), entry, is_edit));
+                }
+                Err(e) => {
+                    if is_offline_error(&e) {
+                        let _ = tx.send(AsyncResult::Offline);
+                    } else {
+                        let _ = tx.send(AsyncResult::Error(format!("Undo failed: {}", e)));
+                    }
+                }
+            }
+        });
     }
 
     fn delete_worklog(&mut self, entry: &TimeEntry) {
@@ -811,10 +2675,8 @@ impl JiraTimeApp {
                     let _ = tx.send(AsyncResult::WorklogDeleted(msg, worklog_id));
                 }
                 Err(e) => {
-                    let err_str = e.to_string().to_lowercase();
-                    if err_str.contains("connection") || err_str.contains("network")
-                       || err_str.contains("error sending request") || err_str.contains("timeout") {
-                        let _ = tx.send(AsyncResult::Offline);
+                    if is_offline_error(&e) {
+                        let _ = tx.send(AsyncResult::Queued(PendingOperation::Delete { issue_key, worklog_id }));
                     } else {
                         let _ = tx.send(AsyncResult::Error(format!("Failed to delete: {}", e)));
                     }
@@ -823,14 +2685,59 @@ impl JiraTimeApp {
         });
     }
 
+    /// Send queued offline changes in order, stopping at the first one that still can't reach Jira
+    fn replay_pending(&mut self) {
+        self.replaying_pending = true;
+        let config = self.config.clone();
+        let operations = self.pending.operations.clone();
+        let jira_domain = self.pending.jira_domain.clone();
+        let tx = self.result_tx.clone();
+
+        self.runtime.spawn(async move {
+            let mut processed = 0;
+            let mut errors = Vec::new();
+            if let Ok(client) = JiraClient::new(&config) {
+                for operation in &operations {
+                    let result = match operation {
+                        PendingOperation::Add { issue_key, seconds, date, description, start_time } => {
+                            client.log_time(issue_key, *seconds, *date, description, start_time.as_deref()).await.map(|_| ())
+                        }
+                        PendingOperation::Edit { issue_key, worklog_id, seconds, date, description, start_time } => {
+                            client.update_worklog(issue_key, worklog_id, *seconds, description, *date, start_time.as_deref()).await.map(|_| ())
+                        }
+                        PendingOperation::Delete { issue_key, worklog_id } => {
+                            client.delete_worklog(issue_key, worklog_id).await
+                        }
+                    };
+                    if let Err(e) = result {
+                        // A new log that timed out may exist already, so it isn't sent a second time
+                        if matches!(operation, PendingOperation::Add { .. }) && is_timeout_error(&e) {
+                            errors.push(format!("{}: no answer from Jira, check whether it was logged", operation.issue_key()));
+                            processed += 1;
+                            continue;
+                        }
+                        if is_offline_error(&e) {
+                            break;
+                        }
+                        // Jira refused it; retrying won't help, so drop it and report
+                        errors.push(format!("{}: {}", operation.issue_key(), e));
+                    }
+                    processed += 1;
+                }
+            }
+            let _ = tx.send(AsyncResult::PendingReplayed(jira_domain, processed, errors));
+        });
+    }
+
     fn save_dialog(&mut self) {
         // Clear previous errors
         self.error_issue = false;
         self.error_hours = false;
+        self.error_start_time = false;
 
         // Validate issue first (it's the first field)
         let is_validated = self.validated_issue.as_ref()
-            .map(|(key, _, _)| key == &self.dialog_issue)
+            .map(|issue| issue.key == self.dialog_issue)
             .unwrap_or(false);
         if self.dialog_issue.is_empty() || !is_validated {
             self.error_issue = true;
@@ -845,8 +2752,22 @@ impl JiraTimeApp {
             }
         };
 
-        // If any errors, don't proceed
-        if self.error_issue || self.error_hours {
+        // Validate start time - an unparseable one would silently become 9:00
+        let start_time = self.dialog_start_time.trim();
+        if !start_time.is_empty() && parse_start_time(start_time).is_none() {
+            self.error_start_time = true;
+        }
+
+        // If any errors, don't proceed - focus the first field that needs fixing
+        if self.error_issue {
+            self.focus_issue_pending = true;
+            return;
+        }
+        if self.error_hours {
+            self.focus_duration_pending = true;
+            return;
+        }
+        if self.error_start_time {
             return;
         }
 
@@ -860,7 +2781,7 @@ impl JiraTimeApp {
         for (i, selected) in self.dialog_categories.iter().enumerate() {
             if *selected {
                 if let Some(tag) = self.config.tags.get(i) {
-                    category_prefix.push_str(&format!("[{}]", tag));
+                    category_prefix.push_str(&format!("[{}]", tag.name));
                 }
             }
         }
@@ -873,23 +2794,44 @@ impl JiraTimeApp {
         } else {
             format!("{} {}", category_prefix, self.dialog_description.trim())
         };
+        // An empty start time uses the configured default
         let user_start_time = if self.dialog_start_time.trim().is_empty() {
-            None
+            Some(self.config.default_start_time.clone())
         } else {
             Some(self.dialog_start_time.clone())
         };
 
         let config = self.config.clone();
         let issue_key = self.dialog_issue.clone();
-        let (issue_summary, issue_type) = self.validated_issue.as_ref()
-            .map(|(_, s, t)| (s.clone(), t.clone()))
+        let (issue_summary, issue_type, issue_status) = self.validated_issue.as_ref()
+            .map(|issue| (issue.summary.clone(), issue.issue_type.clone(), issue.status.clone()))
             .unwrap_or_default();
         let worklog_id = self.dialog_worklog_id.clone();
-        let date = self.selected_date;
-        let tx = self.result_tx.clone();
         let is_edit = self.dialog_mode == DialogMode::Edit;
-        let duration_str = format_duration_with_format(seconds, self.config.time_format);
+        // Edits keep the worklog's own day, which may not be the selected one (schedule view, issue history)
+        let date = if is_edit { self.dialog_worklog_date } else { self.selected_date };
+        let tx = self.result_tx.clone();
+        let duration_str = format_duration_with_format(seconds, self.config.time_format, self.config.decimal_precision);
         let description_clone = description.clone();
+        // Queued instead if Jira can't be reached
+        let pending = if is_edit {
+            PendingOperation::Edit {
+                issue_key: issue_key.clone(),
+                worklog_id: worklog_id.clone(),
+                seconds,
+                date,
+                description: description.clone(),
+                start_time: user_start_time.clone(),
+            }
+        } else {
+            PendingOperation::Add {
+                issue_key: issue_key.clone(),
+                seconds,
+                date,
+                description: description.clone(),
+                start_time: user_start_time.clone(),
+            }
+        };
         self.runtime.spawn(async move {
             let result: Result<(String, TimeEntry), anyhow::Error> = async {
                 let client = JiraClient::new(&config)?;
@@ -902,10 +2844,14 @@ impl JiraTimeApp {
                         issue_key: issue_key.clone(),
                         issue_summary: issue_summary.clone(),
                         issue_type: issue_type.clone(),
+                        issue_status: issue_status.clone(),
                         seconds,
                         description: description_clone,
                         date,
                         start_time,
+                        time_spent_total: 0,
+                        time_original_estimate: 0,
+                        custom_properties: HashMap::new(),
                     };
                     Ok((format!("Updated {} on {}", duration_str, issue_key), entry))
                 } else {
@@ -917,10 +2863,14 @@ impl JiraTimeApp {
                         issue_key: issue_key.clone(),
                         issue_summary: issue_summary.clone(),
                         issue_type: issue_type.clone(),
+                        issue_status: issue_status.clone(),
                         seconds,
                         description: description_clone,
                         date,
                         start_time,
+                        time_spent_total: 0,
+                        time_original_estimate: 0,
+                        custom_properties: HashMap::new(),
                     };
                     Ok((format!("Logged {} to {}", duration_str, issue_key), entry))
                 }
@@ -931,10 +2881,61 @@ impl JiraTimeApp {
                     let _ = tx.send(AsyncResult::WorklogSaved(msg, entry, is_edit));
                 }
                 Err(e) => {
-                    let err_str = e.to_string().to_lowercase();
-                    if err_str.contains("connection") || err_str.contains("network")
-                       || err_str.contains("error sending request") || err_str.contains("timeout") {
-                        let _ = tx.send(AsyncResult::Offline);
+                    if matches!(pending, PendingOperation::Add { .. }) && is_timeout_error(&e) {
+                        // Jira may have created it before the answer was lost; sending it again could log it twice
+                        let _ = tx.send(AsyncResult::Error("Jira didn't answer in time, so the log may have been saved. Refresh to check before logging it again".to_string()));
+                    } else if is_offline_error(&e) {
+                        let _ = tx.send(AsyncResult::Queued(pending));
+                    } else {
+                        let _ = tx.send(AsyncResult::Error(format!("Failed: {}", e)));
+                    }
+                }
+            }
+        });
+    }
+
+    /// Update only the duration of an existing worklog (inline edit in the list view)
+    fn save_inline_duration(&mut self, entry: TimeEntry, seconds: i64) {
+        if seconds == entry.seconds {
+            return;
+        }
+
+        self.loading = true;
+        self.progress = 0.0;
+        self.progress_phase = ProgressPhase::FastStart;
+        self.progress_start = std::time::Instant::now();
+
+        let config = self.config.clone();
+        let tx = self.result_tx.clone();
+        let duration_str = format_duration_with_format(seconds, self.config.time_format, self.config.decimal_precision);
+        self.runtime.spawn(async move {
+            let pending = PendingOperation::Edit {
+                issue_key: entry.issue_key.clone(),
+                worklog_id: entry.worklog_id.clone(),
+                seconds,
+                date: entry.date,
+                description: entry.description.clone(),
+                start_time: (!entry.start_time.is_empty()).then(|| entry.start_time.clone()),
+            };
+            let result: Result<(String, TimeEntry), anyhow::Error> = async {
+                let client = JiraClient::new(&config)?;
+                let start_time = (!entry.start_time.is_empty()).then_some(entry.start_time.as_str());
+                let worklog = client.update_worklog(&entry.issue_key, &entry.worklog_id, seconds, &entry.description, entry.date, start_time).await?;
+                let updated_entry = TimeEntry {
+                    seconds,
+                    start_time: extract_time(&worklog.started),
+                    ..entry
+                };
+                Ok((format!("Updated {} on {}", duration_str, updated_entry.issue_key), updated_entry))
+            }.await;
+
+            match result {
+                Ok((msg, entry)) => {
+                    let _ = tx.send(AsyncResult::WorklogSaved(msg, entry, true));
+                }
+                Err(e) => {
+                    if is_offline_error(&e) {
+                        let _ = tx.send(AsyncResult::Queued(pending));
                     } else {
                         let _ = tx.send(AsyncResult::Error(format!("Failed: {}", e)));
                     }
@@ -982,15 +2983,27 @@ impl JiraTimeApp {
                 });
                 ui.end_row();
 
-                ui.label("Email:");
-                ui.add(
-                    egui::TextEdit::singleline(&mut self.settings_email)
-                        .hint_text("you@company.com")
-                        .desired_width(350.0)
-                );
+                ui.label("Authentication:");
+                egui::ComboBox::from_id_salt("setup_auth_method")
+                    .selected_text(self.settings_auth_method.label())
+                    .show_ui(ui, |ui| {
+                        for method in [AuthMethod::Basic, AuthMethod::Bearer] {
+                            ui.selectable_value(&mut self.settings_auth_method, method, method.label());
+                        }
+                    });
                 ui.end_row();
 
-                ui.label("API Token:");
+                if self.settings_auth_method == AuthMethod::Basic {
+                    ui.label("Email:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings_email)
+                            .hint_text("you@company.com")
+                            .desired_width(350.0)
+                    );
+                    ui.end_row();
+                }
+
+                ui.label(if self.settings_auth_method == AuthMethod::Bearer { "Access Token:" } else { "API Token:" });
                 ui.add(
                     egui::TextEdit::singleline(&mut self.settings_token)
                         .password(true)
@@ -1008,11 +3021,63 @@ impl JiraTimeApp {
     }
 
     fn render_main(&mut self, ui: &mut egui::Ui) {
+        // Alt+Left / Alt+Right walk the week history (only when no dialog is open)
+        let any_dialog_open = self.show_dialog || self.show_settings || self.show_delete_confirm || self.show_reschedule_dialog || self.show_export_range || self.annotation_edit.is_some();
+        if !any_dialog_open {
+            let (back, forward) = ui.input(|i| (
+                i.modifiers.alt && i.key_pressed(egui::Key::ArrowLeft),
+                i.modifiers.alt && i.key_pressed(egui::Key::ArrowRight),
+            ));
+            if back {
+                self.navigate_back();
+            } else if forward {
+                self.navigate_forward();
+            }
+
+            // Ctrl+L (Cmd+L on macOS) jumps straight to the issue field, like a browser address bar
+            if ui.input(|i| i.modifiers.command && i.key_pressed(egui::Key::L)) {
+                self.open_add_dialog();
+            }
+
+            // Plain keys only act when no text field has focus
+            if ui.memory(|m| m.focused().is_none()) {
+                let (previous, next, add, undo) = ui.input(|i| (
+                    i.modifiers.is_none() && i.key_pressed(egui::Key::ArrowLeft),
+                    i.modifiers.is_none() && i.key_pressed(egui::Key::ArrowRight),
+                    i.modifiers.is_none() && i.key_pressed(egui::Key::N),
+                    i.modifiers.command && !i.modifiers.shift && i.key_pressed(egui::Key::Z),
+                ));
+                if undo {
+                    self.undo_last();
+                } else if previous {
+                    self.navigate_to_week(self.week_data.week_start - Duration::days(7));
+                } else if next {
+                    self.navigate_to_week(self.week_data.week_start + Duration::days(7));
+                } else if add {
+                    self.open_add_dialog();
+                }
+            }
+        }
+
         // Header with week navigation
         ui.horizontal(|ui| {
             // Week navigation styled like a button but pill-shaped
             let (button_bg, button_text) = super::theme::button_colors();
 
+            // Back/forward through previously viewed weeks
+            let history_button = |ui: &mut egui::Ui, icon: &str, enabled: bool, tooltip: &str| {
+                ui.add_enabled(enabled, egui::Button::new(RichText::new(icon).size(14.0).color(button_text)).frame(false))
+                    .on_hover_text(tooltip)
+                    .clicked()
+            };
+            if history_button(ui, egui_phosphor::regular::ARROW_LEFT, !self.navigation_history.is_empty(), "Back (Alt+Left)") {
+                self.navigate_back();
+            }
+            if history_button(ui, egui_phosphor::regular::ARROW_RIGHT, !self.navigation_forward.is_empty(), "Forward (Alt+Right)") {
+                self.navigate_forward();
+            }
+            ui.add_space(4.0);
+
             egui::Frame::none()
                 .fill(button_bg)
                 .rounding(egui::Rounding::same(12.0))  // Pill-shaped (fully rounded)
@@ -1027,16 +3092,17 @@ impl JiraTimeApp {
                             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
                         }
                         if left_arrow.clicked() {
-                            let new_week = self.week_data.week_start - Duration::days(7);
-                            self.selected_date = new_week;
-                            self.load_week(new_week);
+                            self.navigate_to_week(self.week_data.week_start - Duration::days(7));
                         }
 
                         ui.add_space(4.0);
 
-                        // Date text with min width to prevent resizing
-                        let start = self.week_data.week_start;
-                        let end = start + Duration::days(4);
+                        // Date text (Mon-Fri, wherever the week starts) with min width to prevent resizing
+                        let workdays: Vec<NaiveDate> = self.week_data.all_days().into_iter()
+                            .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+                            .collect();
+                        let start = workdays[0];
+                        let end = workdays[workdays.len() - 1];
                         let date_text = format!("{} - {}", start.format("%b %-d"), end.format("%b %-d, %Y"));
                         // Min width for longest possible text like "Sep 29 - Oct 3, 2025"
                         ui.allocate_ui_with_layout(
@@ -1057,38 +3123,63 @@ impl JiraTimeApp {
                             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
                         }
                         if right_arrow.clicked() {
-                            let new_week = self.week_data.week_start + Duration::days(7);
-                            self.selected_date = new_week;
-                            self.load_week(new_week);
+                            self.navigate_to_week(self.week_data.week_start + Duration::days(7));
                         }
                     });
                 });
 
+            // Active sprint badge
+            let sprints = self.sprints_in_week();
+            if !sprints.is_empty() {
+                ui.add_space(8.0);
+                let accent = Color32::from_rgb(19, 152, 244);
+                egui::Frame::none()
+                    .stroke(egui::Stroke::new(1.0, accent))
+                    .rounding(egui::Rounding::same(8.0))
+                    .inner_margin(egui::Margin::symmetric(6.0, 1.0))
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(sprints.join(", ")).size(12.0).color(accent));
+                    })
+                    .response
+                    .on_hover_text("Active sprint");
+            }
+
             // Weekly total (only show if > 0) - white bold for times to stand out
             ui.add_space(16.0);
-            let week_total: i64 = self.week_data.entries.iter().map(|e| e.seconds).sum();
+            let week_total: i64 = self.week_data.total_seconds();
             if week_total > 0 {
-                let week_total_str = crate::api::format_duration_with_format(week_total, self.config.time_format);
+                let week_total_str = crate::api::format_duration_with_format(week_total, self.config.time_format, self.config.decimal_precision);
                 ui.label(RichText::new(week_total_str).size(14.0).color(Color32::WHITE).family(crate::ui::theme::bold_family()));
             }
 
+            // Time left to reach today's target (only while viewing today, hidden once met)
+            let today = Local::now().date_naive();
+            if self.selected_date == today {
+                let target_seconds = (self.config.daily_target_hours * 3600.0) as i64;
+                let remaining_seconds = (target_seconds - self.week_data.seconds_for_day(today)).max(0);
+                if remaining_seconds > 0 {
+                    let remaining_color = if remaining_seconds > 2 * 3600 {
+                        Color32::from_rgb(152, 195, 121)  // Green
+                    } else {
+                        Color32::from_rgb(0xe5, 0xaa, 0x00)  // Amber
+                    };
+                    ui.add_space(12.0);
+                    ui.label(RichText::new(format!("{} left today", format_duration_with_format(remaining_seconds, self.config.time_format, self.config.decimal_precision))).size(14.0).color(remaining_color));
+                }
+            }
+
             // View mode dropdown (icon + chevron)
             ui.add_space(16.0);
             let view_menu_id = ui.make_persistent_id("view_mode_menu");
-            let (current_icon, other_icon, other_label, other_mode) = match self.config.view_mode {
-                ViewMode::List => (
-                    egui_phosphor::regular::LIST,
-                    egui_phosphor::regular::SQUARES_FOUR,
-                    "Schedule view",
-                    ViewMode::Schedule,
-                ),
-                ViewMode::Schedule => (
-                    egui_phosphor::regular::SQUARES_FOUR,
-                    egui_phosphor::regular::LIST,
-                    "List view",
-                    ViewMode::List,
-                ),
-            };
+            let view_modes = [
+                (ViewMode::List, egui_phosphor::regular::LIST, "List view"),
+                (ViewMode::IssueGrouped, egui_phosphor::regular::STACK, "Group by issue"),
+                (ViewMode::Schedule, egui_phosphor::regular::SQUARES_FOUR, "Schedule view"),
+                (ViewMode::Month, egui_phosphor::regular::CALENDAR_DOTS, "Month view"),
+            ];
+            let current_icon = view_modes.iter()
+                .find(|(mode, _, _)| *mode == self.config.view_mode)
+                .map_or(egui_phosphor::regular::LIST, |(_, icon, _)| *icon);
 
             let icon_color = Color32::from_rgb(160, 160, 152);
             let hover_color = Color32::WHITE;
@@ -1107,12 +3198,17 @@ impl JiraTimeApp {
                 ui.set_min_width(140.0);
                 ui.style_mut().spacing.button_padding = egui::vec2(12.0, 8.0);
 
-                let menu_text = format!("{} {}", other_icon, other_label);
-                if ui.add(egui::Button::new(
-                    RichText::new(menu_text).size(14.0)
-                ).frame(false)).clicked() {
-                    self.config.view_mode = other_mode;
-                    let _ = self.config.save();
+                for (mode, icon, label) in view_modes {
+                    if mode == self.config.view_mode {
+                        continue;
+                    }
+                    let menu_text = format!("{} {}", icon, label);
+                    if ui.add(egui::Button::new(
+                        RichText::new(menu_text).size(14.0)
+                    ).frame(false)).clicked() {
+                        self.config.view_mode = mode;
+                        let _ = self.config.save();
+                    }
                 }
             });
 
@@ -1124,7 +3220,10 @@ impl JiraTimeApp {
                 let snap_label = match self.config.snap_interval {
                     crate::config::SnapInterval::OneMinute => "1m",
                     crate::config::SnapInterval::FiveMinutes => "5m",
+                    crate::config::SnapInterval::SixMinutes => "6m",
+                    crate::config::SnapInterval::TenMinutes => "10m",
                     crate::config::SnapInterval::FifteenMinutes => "15m",
+                    crate::config::SnapInterval::ThirtyMinutes => "30m",
                 };
                 let snap_text = format!("Snap to {} {}", snap_label, egui_phosphor::regular::CARET_DOWN);
                 let text_size = ui.fonts(|f| f.layout_no_wrap(snap_text.clone(), font_id.clone(), icon_color).size());
@@ -1144,7 +3243,10 @@ impl JiraTimeApp {
                     for (interval, label) in [
                         (SnapInterval::OneMinute, "1m"),
                         (SnapInterval::FiveMinutes, "5m"),
+                        (SnapInterval::SixMinutes, "6m"),
+                        (SnapInterval::TenMinutes, "10m"),
                         (SnapInterval::FifteenMinutes, "15m"),
+                        (SnapInterval::ThirtyMinutes, "30m"),
                     ] {
                         if ui.add(egui::Button::new(
                             RichText::new(label).size(14.0)
@@ -1185,39 +3287,178 @@ impl JiraTimeApp {
                 ui.painter().text(settings_rect.center(), egui::Align2::CENTER_CENTER, settings_icon, font_id.clone(), settings_col);
                 if settings_response.on_hover_text("Settings").clicked() {
                     // Reset settings to current config values
+                    self.settings_profiles = self.config.profiles_with_active();
+                    self.settings_active_profile = self.active_profile_index;
+                    self.connection_test_result = None;
                     self.settings_domain = self.config.jira_domain.trim_end_matches(".atlassian.net").to_string();
                     self.settings_email = self.config.email.clone();
                     self.settings_token = String::new();
+                    self.masked_token = self.config.api_token.as_deref().map(views::masked_token).unwrap_or_default();
+                    self.settings_auth_method = self.config.auth_method;
                     self.settings_font_scale = self.config.font_scale;
-                    self.settings_tags = self.config.tags.join(", ");
+                    self.settings_tags = self.config.tags.clone();
+                    self.settings_new_tag = String::new();
+                    self.tag_drag_idx = None;
                     self.settings_time_format = self.config.time_format;
                     self.settings_clock_format = self.config.clock_format;
                     self.settings_show_start_time = self.config.show_start_time;
+                    self.settings_show_status_bar = self.config.show_status_bar;
+                    self.settings_decimal_precision = self.config.decimal_precision;
+                    self.settings_min_schedule_block_px = self.config.min_schedule_block_px;
+                    self.settings_show_block_label_threshold_px = self.config.show_block_label_threshold_px;
+                    self.settings_default_start_time = self.config.default_start_time.clone();
+                    self.settings_daily_target_hours = self.config.daily_target_hours;
+                    self.settings_update_channel = self.config.update_channel;
+                    self.settings_export_directory = self.config.export_directory.clone().unwrap_or_default();
+                    self.settings_connect_timeout_secs = self.config.connect_timeout_secs;
+                    self.settings_request_timeout_secs = self.config.request_timeout_secs;
+                    self.settings_max_retries = self.config.max_retries;
+                    self.settings_colorblind_mode = self.config.colorblind_mode;
+                    self.settings_weekend_display = self.config.weekend_display;
+                    self.settings_week_start_day = self.config.week_start_day;
+                    self.settings_link_behavior = self.config.link_behavior;
+                    self.settings_quick_actions = self.config.quick_actions.clone();
+                    self.settings_tag_auto_rules = self.config.tag_auto_rules.clone();
+                    self.settings_issue_type_colors = Self::issue_type_color_rows(&self.config);
+                    self.settings_project_colors = self.config.project_colors.clone();
                     self.show_settings = true;
                 }
 
                 ui.add_space(12.0);
 
+                // User-defined quick actions (right-to-left, so reverse to keep the configured order)
+                let mut triggered_action = None;
+                for action in self.config.quick_actions.iter().rev() {
+                    let icon = super::theme::quick_action_icon(&action.icon);
+                    let icon_size = ui.fonts(|f| f.layout_no_wrap(icon.clone(), font_id.clone(), Color32::WHITE).size());
+                    let (action_rect, action_response) = ui.allocate_exact_size(icon_size + egui::vec2(8.0, 4.0), egui::Sense::click());
+                    let action_col = if action_response.hovered() { hover_color } else { text_color };
+                    ui.painter().text(action_rect.center(), egui::Align2::CENTER_CENTER, &icon, font_id.clone(), action_col);
+                    if action_response.on_hover_text(&action.label).clicked() {
+                        triggered_action = Some(action.action.clone());
+                    }
+                    ui.add_space(12.0);
+                }
+                if let Some(action) = triggered_action {
+                    self.run_quick_action(ui.ctx(), &action);
+                }
+
                 // Reload button
                 let reload_icon = egui_phosphor::regular::CLOUD_ARROW_DOWN;
                 let icon_size = ui.fonts(|f| f.layout_no_wrap(reload_icon.to_string(), font_id.clone(), Color32::WHITE).size());
                 let (reload_rect, reload_response) = ui.allocate_exact_size(icon_size + egui::vec2(8.0, 4.0), egui::Sense::click());
                 let reload_col = if reload_response.hovered() { hover_color } else { text_color };
                 ui.painter().text(reload_rect.center(), egui::Align2::CENTER_CENTER, reload_icon, font_id.clone(), reload_col);
-                if reload_response.on_hover_text("Sync with Jira").clicked() {
+                // Badge with the number of offline changes waiting to sync
+                let pending_count = self.pending.operations.len();
+                let reload_tooltip = if pending_count > 0 {
+                    let badge_center = reload_rect.right_top() + egui::vec2(-2.0, 3.0);
+                    ui.painter().circle_filled(badge_center, 7.0, Color32::from_rgb(0xe5, 0xaa, 0x00));
+                    ui.painter().text(badge_center, egui::Align2::CENTER_CENTER, pending_count.min(99).to_string(), egui::FontId::proportional(10.0), Color32::BLACK);
+                    format!("Sync with Jira ({} offline change(s) waiting)", pending_count)
+                } else {
+                    "Sync with Jira".to_string()
+                };
+                if reload_response.on_hover_text(reload_tooltip).clicked() {
                     self.refresh_data();
                 }
 
-                ui.add_space(12.0);
+                ui.add_space(12.0);
+
+                // Summarize button (only when a self-hosted summary endpoint is configured)
+                let summary_enabled = self.config.ai_summary_endpoint.as_deref()
+                    .is_some_and(summary::is_local_endpoint);
+                if summary_enabled && !self.summary_unavailable {
+                    let summary_icon = egui_phosphor::regular::SPARKLE;
+                    let icon_size = ui.fonts(|f| f.layout_no_wrap(summary_icon.to_string(), font_id.clone(), Color32::WHITE).size());
+                    let (summary_rect, summary_response) = ui.allocate_exact_size(icon_size + egui::vec2(8.0, 4.0), egui::Sense::click());
+                    let summary_col = if summary_response.hovered() { hover_color } else { text_color };
+                    ui.painter().text(summary_rect.center(), egui::Align2::CENTER_CENTER, summary_icon, font_id.clone(), summary_col);
+                    if summary_response.on_hover_text("Summarize this week").clicked() {
+                        self.summarize_week();
+                    }
+
+                    ui.add_space(12.0);
+                }
+
+                // Weekly report email (only when a mail server is configured)
+                if self.config.smtp_config.is_some() {
+                    let report_icon = egui_phosphor::regular::ENVELOPE;
+                    let icon_size = ui.fonts(|f| f.layout_no_wrap(report_icon.to_string(), font_id.clone(), Color32::WHITE).size());
+                    let (report_rect, report_response) = ui.allocate_exact_size(icon_size + egui::vec2(8.0, 4.0), egui::Sense::click());
+                    let report_col = if report_response.hovered() && !self.report_sending { hover_color } else { text_color };
+                    ui.painter().text(report_rect.center(), egui::Align2::CENTER_CENTER, report_icon, font_id.clone(), report_col);
+                    let tooltip = if self.report_sending { "Sending report..." } else { "Send weekly report" };
+                    if report_response.on_hover_text(tooltip).clicked() {
+                        self.send_weekly_report();
+                    }
+
+                    ui.add_space(12.0);
+                }
+
+                // Export button: left-click picks JSON or CSV, right-click exports a custom range
+                let export_icon = egui_phosphor::regular::EXPORT;
+                let icon_size = ui.fonts(|f| f.layout_no_wrap(export_icon.to_string(), font_id.clone(), Color32::WHITE).size());
+                let (export_rect, export_response) = ui.allocate_exact_size(icon_size + egui::vec2(8.0, 4.0), egui::Sense::click());
+                let export_col = if export_response.hovered() { hover_color } else { text_color };
+                ui.painter().text(export_rect.center(), egui::Align2::CENTER_CENTER, export_icon, font_id, export_col);
+                if export_response.secondary_clicked() {
+                    self.open_export_range_dialog();
+                }
+                let export_menu_id = ui.make_persistent_id("export_format_menu");
+                let export_response = export_response.on_hover_text("Export week (right-click for a custom range)");
+                if export_response.clicked() {
+                    ui.memory_mut(|mem| mem.toggle_popup(export_menu_id));
+                }
+
+                let mut export_format = None;
+                let mut copy_markdown = false;
+                egui::popup::popup_below_widget(ui, export_menu_id, &export_response, egui::PopupCloseBehavior::CloseOnClick, |ui| {
+                    ui.set_min_width(140.0);
+                    ui.style_mut().spacing.button_padding = egui::vec2(12.0, 8.0);
+
+                    if ui.add(egui::Button::new(
+                        RichText::new(format!("{} JSON", egui_phosphor::regular::BRACKETS_CURLY)).size(14.0)
+                    ).frame(false)).clicked() {
+                        export_format = Some(ExportFormat::Json);
+                    }
+                    if ui.add(egui::Button::new(
+                        RichText::new(format!("{} CSV", egui_phosphor::regular::FILE_CSV)).size(14.0)
+                    ).frame(false)).clicked() {
+                        export_format = Some(ExportFormat::Csv);
+                    }
+                    if ui.add(egui::Button::new(
+                        RichText::new(format!("{} ICS (calendar)", egui_phosphor::regular::CALENDAR_BLANK)).size(14.0)
+                    ).frame(false)).clicked() {
+                        export_format = Some(ExportFormat::Ics);
+                    }
+                    if ui.add(egui::Button::new(
+                        RichText::new(format!("{} HTML report", egui_phosphor::regular::FILE_HTML)).size(14.0)
+                    ).frame(false)).clicked() {
+                        export_format = Some(ExportFormat::Html);
+                    }
+                    if ui.add(egui::Button::new(
+                        RichText::new(format!("{} Copy as Markdown", egui_phosphor::regular::CLIPBOARD_TEXT)).size(14.0)
+                    ).frame(false)).clicked() {
+                        copy_markdown = true;
+                    }
+                });
+
+                if copy_markdown {
+                    ui.ctx().copy_text(export::week_markdown_table(&self.week_data, self.config.time_format, self.config.decimal_precision));
+                    self.status_message = Some(("Copied week as a Markdown table".to_string(), false));
+                }
 
-                // Export button (JSON icon)
-                let export_icon = egui_phosphor::regular::BRACKETS_CURLY;
-                let icon_size = ui.fonts(|f| f.layout_no_wrap(export_icon.to_string(), font_id.clone(), Color32::WHITE).size());
-                let (export_rect, export_response) = ui.allocate_exact_size(icon_size + egui::vec2(8.0, 4.0), egui::Sense::click());
-                let export_col = if export_response.hovered() { hover_color } else { text_color };
-                ui.painter().text(export_rect.center(), egui::Align2::CENTER_CENTER, export_icon, font_id, export_col);
-                if export_response.on_hover_text("Export week to JSON").clicked() {
-                    match export::export_week(&self.week_data, None) {
+                if let Some(format) = export_format {
+                    let annotations = self.config.export_include_annotations.then_some(&self.annotations.local_annotations);
+                    let export_directory = self.config.export_directory.as_deref();
+                    let result = match format {
+                        ExportFormat::Json => export::export_week(&self.week_data, None, &self.config.tags, annotations, export_directory),
+                        ExportFormat::Csv => export::export_week_csv(&self.week_data, None, annotations, export_directory),
+                        ExportFormat::Ics => export::export_week_ics(&self.week_data, None, export_directory),
+                        ExportFormat::Html => export::export_week_html(&self.week_data, None, &self.config.issue_type_colors, self.config.time_format, self.config.decimal_precision, export_directory),
+                    };
+                    match result {
                         Ok(path) => {
                             self.status_message = Some((format!("Exported to {}", path.display()), false));
                         }
@@ -1231,12 +3472,36 @@ impl JiraTimeApp {
 
         ui.add_space(8.0);
 
-        // Show offline message if we're offline
-        if self.is_offline {
+        // Offline with a saved copy of the week: a banner, then the week as usual
+        if let Some(fetched_at) = self.offline_data_from.filter(|_| self.is_offline) {
+            let amber = Color32::from_rgb(0xe5, 0xaa, 0x00);
+            let mut retry = false;
+            egui::Frame::none()
+                .stroke(egui::Stroke::new(1.0, amber.gamma_multiply(0.5)))
+                .rounding(6.0)
+                .inner_margin(egui::Margin::symmetric(10.0, 6.0))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(egui_phosphor::regular::WIFI_SLASH).size(14.0).color(amber));
+                        ui.label(RichText::new(format!("Offline - cached data from {}", fetched_at.format("%a %-d %b, %H:%M")))
+                            .size(14.0)
+                            .color(Color32::from_rgb(200, 200, 210)));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            retry = ui.button(format!("{} Retry", egui_phosphor::regular::ARROWS_CLOCKWISE)).clicked();
+                        });
+                    });
+                });
+            ui.add_space(8.0);
+            if retry {
+                self.is_offline = false;
+                self.refresh_data();
+            }
+        } else if self.is_offline {
+            // Nothing saved for this week: a full offline message instead
             ui.add_space(40.0);
             ui.vertical_centered(|ui| {
                 ui.label(
-                    RichText::new(format!("{}", egui_phosphor::regular::WIFI_SLASH))
+                    RichText::new(egui_phosphor::regular::WIFI_SLASH)
                         .size(34.0)
                         .color(Color32::from_rgb(224, 108, 117))
                 );
@@ -1270,16 +3535,89 @@ impl JiraTimeApp {
             return;
         }
 
+        if self.week_slide.is_some() {
+            self.render_week_slide(ui);
+        } else {
+            self.render_week_content(ui);
+        }
+    }
+
+    /// Render the week content offset by the slide animation
+    fn render_week_slide(&mut self, ui: &mut egui::Ui) {
+        let Some(slide) = &mut self.week_slide else {
+            return;
+        };
+        let rect = ui.available_rect_before_wrap();
+        let width = rect.width();
+
+        // Outgoing: 0 -> off-screen; incoming: off-screen on the other side -> 0
+        let (from, to) = if slide.incoming {
+            (slide.direction * width, 0.0)
+        } else {
+            (0.0, -slide.direction * width)
+        };
+        let offset = if slide.started {
+            ui.ctx().animate_value_with_time(slide.id(), to, WEEK_SLIDE_SECONDS)
+        } else {
+            // Jump to the start, wherever the previous slide left this phase's value
+            ui.ctx().animate_value_with_time(slide.id(), from, 0.0);
+            from
+        };
+        let finished = slide.started && (offset - to).abs() < 0.5;
+        slide.started = true;
+
+        let mut child = ui.new_child(
+            egui::UiBuilder::new()
+                .max_rect(rect.translate(egui::vec2(offset, 0.0)))
+                .layout(*ui.layout())
+        );
+        child.set_clip_rect(rect.intersect(ui.clip_rect()));
+        child.disable();
+
+        if let Some((week_data, selected_date)) = slide.outgoing.take() {
+            // Show the previous week while it slides out
+            let current_week = std::mem::replace(&mut self.week_data, week_data);
+            let current_date = std::mem::replace(&mut self.selected_date, selected_date);
+            self.render_week_content(&mut child);
+            let week_data = std::mem::replace(&mut self.week_data, current_week);
+            self.selected_date = current_date;
+            if let Some(slide) = &mut self.week_slide {
+                if !finished {
+                    slide.outgoing = Some((week_data, selected_date));
+                }
+            }
+        } else if slide.incoming {
+            self.render_week_content(&mut child);
+            if finished {
+                self.week_slide = None;
+            }
+        } else {
+            // Slid out, still loading
+            child.add_space(80.0);
+            child.vertical_centered(|ui| {
+                ui.spinner();
+            });
+        }
+
+        ui.allocate_rect(rect, egui::Sense::hover());
+    }
+
+    fn render_week_content(&mut self, ui: &mut egui::Ui) {
         // Render view based on view_mode
         match self.config.view_mode {
-            ViewMode::List => {
-                // Day tabs with view mode toggle (only in List mode)
+            ViewMode::List | ViewMode::IssueGrouped => {
+                self.schedule_view_entered = false;
+                // Day tabs with view mode toggle (only in the list modes)
                 let (clicked_day, view_toggled) = views::render_day_tabs(
                     ui,
                     &self.week_data,
                     self.selected_date,
                     self.config.time_format,
+                    self.config.decimal_precision,
                     self.config.list_view_mode,
+                    &self.config.tags,
+                    self.config.weekend_display,
+                    self.config.daily_target_hours,
                 );
                 if let Some(day) = clicked_day {
                     self.selected_date = day;
@@ -1294,42 +3632,144 @@ impl JiraTimeApp {
 
                 ui.add_space(8.0);
 
+                if views::render_week_summary(ui, &self.week_data, &self.config.tags, self.config.time_format, self.config.decimal_precision, self.config.week_summary_collapsed) {
+                    self.config.week_summary_collapsed = !self.config.week_summary_collapsed;
+                    let _ = self.config.save();
+                }
+
                 // Entry list for selected day (sorted by start time)
                 let mut day_entries: Vec<TimeEntry> = self.week_data.entries_for_day(self.selected_date)
                     .into_iter()
                     .cloned()
                     .collect();
                 day_entries.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+                if !day_entries.is_empty() {
+                    views::render_day_distribution(ui, &day_entries, self.config.time_format, self.config.decimal_precision, self.config.colorblind_mode, &self.config.project_colors);
+                    ui.add_space(8.0);
+                }
                 let base_url = format!("https://{}", self.config.jira_domain);
-                let (edit_idx, delete_idx, add_clicked) = views::render_entry_list(ui, &day_entries, &base_url, self.config.time_format, self.config.clock_format, self.config.show_start_time, self.config.list_view_mode);
-                if let Some(idx) = edit_idx {
+                let list_result = views::render_entry_list(ui, &day_entries, &base_url, self.config.time_format, self.config.decimal_precision, self.config.clock_format, self.config.show_start_time, self.config.list_view_mode, self.config.colorblind_mode, &self.config.project_colors, &mut self.inline_edit_state, &self.new_entry_worklog_ids, &self.config.issue_type_colors, self.config.link_behavior, &self.annotations.local_annotations, (self.config.view_mode == ViewMode::IssueGrouped).then_some(&mut self.expanded_issue_groups));
+                // Drop finished fade-ins, keep repainting while any are running
+                self.new_entry_worklog_ids.retain(|_, added| added.elapsed().as_secs_f32() < views::NEW_ENTRY_FADE_SECONDS);
+                if !self.new_entry_worklog_ids.is_empty() {
+                    ui.ctx().request_repaint();
+                }
+                if let Some(idx) = list_result.edit_index {
                     let entry = day_entries[idx].clone();
                     self.open_edit_dialog(&entry);
                 }
-                if let Some(idx) = delete_idx {
+                if let Some(idx) = list_result.delete_index {
                     let entry = day_entries[idx].clone();
                     self.pending_delete = Some(entry);
                     self.show_delete_confirm = true;
                 }
-                if add_clicked {
+                if list_result.add_clicked {
                     self.open_add_dialog();
                 }
+                if let Some((idx, seconds)) = list_result.duration_edit {
+                    let entry = day_entries[idx].clone();
+                    self.save_inline_duration(entry, seconds);
+                }
+                if let Some(idx) = list_result.details_index {
+                    self.issue_details = Some(day_entries[idx].issue_key.clone());
+                }
+                if let Some(idx) = list_result.duplicate_index {
+                    let entry = day_entries[idx].clone();
+                    self.open_duplicate_dialog(&entry);
+                }
+                if let Some(idx) = list_result.calendar_index {
+                    let opened = export::export_entry_ics(&day_entries[idx])
+                        .and_then(|path| open::that(&path).map_err(|e| format!("Failed to open calendar file: {}", e)));
+                    if let Err(e) = opened {
+                        self.status_message = Some((e, true));
+                    }
+                }
+                if let Some(idx) = list_result.note_index {
+                    let worklog_id = day_entries[idx].worklog_id.clone();
+                    let text = self.annotations.get(&worklog_id).unwrap_or_default().to_string();
+                    self.annotation_edit = Some((worklog_id, text));
+                }
+            }
+            ViewMode::Month => {
+                self.schedule_view_entered = false;
+                let month = self.selected_date.with_day(1).unwrap_or(self.selected_date);
+                if self.month_failed.is_some_and(|failed| failed != month) {
+                    self.month_failed = None;
+                }
+                if self.month_requested != Some(month) && self.month_failed != Some(month) {
+                    self.load_month(month);
+                }
+
+                // The loaded week has any edits made since the month was fetched
+                let loaded_start = self.week_data.week_start;
+                let loaded_end = loaded_start + Duration::days(6);
+                let month_entries = self.month_data.as_ref()
+                    .filter(|(m, _)| *m == month)
+                    .map(|(_, entries)| entries.as_slice())
+                    .unwrap_or_default();
+                let week_data = &self.week_data;
+                let day_seconds = |day: NaiveDate| {
+                    if day >= loaded_start && day <= loaded_end && !self.loading {
+                        week_data.seconds_for_day(day)
+                    } else {
+                        month_entries.iter().filter(|e| e.date == day).map(|e| e.seconds).sum()
+                    }
+                };
+                let target_seconds = (self.config.daily_target_hours * 3600.0) as i64;
+                let month_result = views::render_month_heatmap(
+                    ui,
+                    month,
+                    day_seconds,
+                    self.config.week_start_day,
+                    target_seconds,
+                    self.config.time_format,
+                    self.config.decimal_precision,
+                    self.month_loading,
+                );
+
+                if let Some(day) = month_result.clicked_day {
+                    self.config.view_mode = ViewMode::List;
+                    let _ = self.config.save();
+                    self.navigate_to_week(week_start(day, self.config.week_start_day));
+                    self.selected_date = day;
+                } else if month_result.month_delta != 0 {
+                    let months = chrono::Months::new(1);
+                    let target = if month_result.month_delta < 0 { month.checked_sub_months(months) } else { month.checked_add_months(months) };
+                    // Follow with the week too, so the other views stay on the same dates
+                    if let Some(target) = target {
+                        self.navigate_to_week(week_start(target, self.config.week_start_day));
+                        self.selected_date = target;
+                    }
+                }
             }
             ViewMode::Schedule => {
                 // Schedule view - render timeline grid
                 let base_url = format!("https://{}", self.config.jira_domain);
+                let hour_heat = self.recent_hour_heatmap();
                 let dialog_open = self.show_dialog || self.show_delete_confirm || self.show_reschedule_dialog;
                 let schedule_result = views::render_schedule_view(
                     ui,
                     &self.week_data,
                     &base_url,
                     self.config.time_format,
+                    self.config.decimal_precision,
                     self.config.clock_format,
                     self.config.schedule_start_hour,
                     self.config.schedule_end_hour,
                     self.config.snap_interval,
                     dialog_open,
+                    !self.schedule_view_entered,
+                    self.config.colorblind_mode,
+                    &self.config.project_colors,
+                    self.config.min_column_width,
+                    (self.config.min_schedule_block_px, self.config.show_block_label_threshold_px),
+                    hour_heat.as_ref(),
+                    &mut self.schedule_hover_started,
+                    &self.config.issue_type_colors,
+                    self.config.weekend_display,
+                    &self.team_entries,
                 );
+                self.schedule_view_entered = true;
                 // Only process schedule interactions when no dialog is open
                 // (prevents clicks in dialog from registering on entries behind it)
                 if !self.show_dialog && !self.show_delete_confirm && !self.show_reschedule_dialog {
@@ -1341,6 +3781,8 @@ impl JiraTimeApp {
                         self.dialog_start_time = start_time;
                     } else if let Some(entry) = schedule_result.edit_entry {
                         self.open_edit_dialog(&entry);
+                    } else if let Some(entry) = schedule_result.duplicate_entry {
+                        self.open_duplicate_dialog(&entry);
                     }
                     if let Some(entry) = schedule_result.delete_entry {
                         self.pending_delete = Some(entry);
@@ -1384,10 +3826,14 @@ impl JiraTimeApp {
                                 issue_key: entry_clone.issue_key.clone(),
                                 issue_summary: entry_clone.issue_summary.clone(),
                                 issue_type: entry_clone.issue_type.clone(),
+                                issue_status: entry_clone.issue_status.clone(),
                                 seconds: entry_clone.seconds,
                                 description: entry_clone.description.clone(),
                                 date: entry_clone.date,
                                 start_time,
+                                time_spent_total: entry_clone.time_spent_total,
+                                time_original_estimate: entry_clone.time_original_estimate,
+                                custom_properties: entry_clone.custom_properties.clone(),
                             };
                             Ok((format!("Moved to {}", new_time), updated_entry, true))
                         }.await;
@@ -1397,9 +3843,7 @@ impl JiraTimeApp {
                                 let _ = tx.send(AsyncResult::WorklogSaved(msg, entry, is_edit));
                             }
                             Err(e) => {
-                                let err_str = e.to_string().to_lowercase();
-                                if err_str.contains("connection") || err_str.contains("network")
-                                   || err_str.contains("error sending request") || err_str.contains("timeout") {
+                                if is_offline_error(&e) {
                                     let _ = tx.send(AsyncResult::Offline);
                                 } else {
                                     let _ = tx.send(AsyncResult::Error(format!("Move failed: {}", e)));
@@ -1428,6 +3872,7 @@ impl JiraTimeApp {
                     let tx = self.result_tx.clone();
                     let new_time = new_start_time.clone();
                     let entry_clone = entry.clone();
+                    let (time_format, decimal_precision) = (self.config.time_format, self.config.decimal_precision);
 
                     self.runtime.spawn(async move {
                         let result: Result<(String, TimeEntry, bool), anyhow::Error> = async {
@@ -1446,12 +3891,16 @@ impl JiraTimeApp {
                                 issue_key: entry_clone.issue_key.clone(),
                                 issue_summary: entry_clone.issue_summary.clone(),
                                 issue_type: entry_clone.issue_type.clone(),
+                                issue_status: entry_clone.issue_status.clone(),
                                 seconds: new_seconds,
                                 description: entry_clone.description.clone(),
                                 date: entry_clone.date,
                                 start_time,
+                                time_spent_total: entry_clone.time_spent_total,
+                                time_original_estimate: entry_clone.time_original_estimate,
+                                custom_properties: entry_clone.custom_properties.clone(),
                             };
-                            let duration_str = crate::api::format_duration_with_format(new_seconds, crate::config::TimeFormat::Decimal);
+                            let duration_str = format_duration_with_format(new_seconds, time_format, decimal_precision);
                             Ok((format!("Resized to {}", duration_str), updated_entry, true))
                         }.await;
 
@@ -1460,9 +3909,7 @@ impl JiraTimeApp {
                                 let _ = tx.send(AsyncResult::WorklogSaved(msg, entry, is_edit));
                             }
                             Err(e) => {
-                                let err_str = e.to_string().to_lowercase();
-                                if err_str.contains("connection") || err_str.contains("network")
-                                   || err_str.contains("error sending request") || err_str.contains("timeout") {
+                                if is_offline_error(&e) {
                                     let _ = tx.send(AsyncResult::Offline);
                                 } else {
                                     let _ = tx.send(AsyncResult::Error(format!("Resize failed: {}", e)));
@@ -1483,6 +3930,46 @@ impl JiraTimeApp {
         ui.label(RichText::new("Jira Connection").color(section_color).strong());
         ui.add_space(8.0);
 
+        // Profiles: the fields below edit the active one
+        let mut make_active = None;
+        let mut remove_profile = None;
+        egui::Grid::new("profiles_grid")
+            .num_columns(4)
+            .spacing([10.0, 10.0])
+            .show(ui, |ui| {
+                for (idx, profile) in self.settings_profiles.iter_mut().enumerate() {
+                    let is_active = idx == self.settings_active_profile;
+                    ui.add(egui::TextEdit::singleline(&mut profile.name)
+                        .hint_text("Profile name")
+                        .desired_width(140.0));
+                    ui.label(RichText::new(&profile.jira_domain).size(12.0).color(Color32::from_rgb(150, 150, 150)));
+                    if is_active {
+                        ui.label(RichText::new(format!("{} Active", egui_phosphor::regular::CHECK)).color(accent));
+                    } else if ui.button("Make active").clicked() {
+                        make_active = Some(idx);
+                    }
+                    if ui.add_enabled(!is_active, egui::Button::new(egui_phosphor::regular::TRASH)).on_hover_text("Remove").clicked() {
+                        remove_profile = Some(idx);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(idx) = make_active {
+            self.select_settings_profile(idx);
+        }
+        if let Some(idx) = remove_profile {
+            self.settings_profiles.remove(idx);
+            if idx < self.settings_active_profile {
+                self.settings_active_profile -= 1;
+            }
+        }
+        if ui.button(format!("{} Add profile", egui_phosphor::regular::PLUS)).clicked() {
+            let name = format!("Profile {}", self.settings_profiles.len() + 1);
+            self.settings_profiles.push(ConfigProfile { name, ..Default::default() });
+            self.select_settings_profile(self.settings_profiles.len() - 1);
+        }
+        ui.add_space(8.0);
+
         egui::Grid::new("jira_grid")
             .num_columns(2)
             .spacing([20.0, 10.0])
@@ -1496,89 +3983,478 @@ impl JiraTimeApp {
                 });
                 ui.end_row();
 
-                ui.label("Email");
-                ui.add(egui::TextEdit::singleline(&mut self.settings_email)
-                    .desired_width(350.0));
-                ui.end_row();
+                ui.label("Authentication");
+                egui::ComboBox::from_id_salt("auth_method")
+                    .selected_text(self.settings_auth_method.label())
+                    .show_ui(ui, |ui| {
+                        for method in [AuthMethod::Basic, AuthMethod::Bearer] {
+                            ui.selectable_value(&mut self.settings_auth_method, method, method.label());
+                        }
+                    });
+                ui.end_row();
+
+                // Personal access tokens identify the user on their own
+                if self.settings_auth_method == AuthMethod::Basic {
+                    ui.label("Email");
+                    ui.add(egui::TextEdit::singleline(&mut self.settings_email)
+                        .desired_width(350.0));
+                    ui.end_row();
+                }
+
+                ui.label(if self.settings_auth_method == AuthMethod::Bearer { "Access token" } else { "API token" });
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings_token)
+                            .password(true)
+                            .hint_text(if self.masked_token.is_empty() { "Not set" } else { self.masked_token.as_str() })
+                            .desired_width(350.0)
+                    );
+                    if ui.add_enabled(!self.connection_testing, egui::Button::new("Test connection")).clicked() {
+                        self.test_connection();
+                    }
+                });
+                ui.end_row();
+
+                if self.connection_testing || self.connection_test_result.is_some() {
+                    ui.label("");
+                    ui.horizontal(|ui| match &self.connection_test_result {
+                        _ if self.connection_testing => {
+                            ui.spinner();
+                            ui.label(RichText::new("Connecting...").size(14.0));
+                        }
+                        Some(Ok(name)) => {
+                            ui.label(RichText::new(format!("{} Connected as {}", egui_phosphor::regular::CHECK, name))
+                                .size(14.0)
+                                .color(Color32::from_rgb(152, 195, 121)));
+                        }
+                        Some(Err(error)) => {
+                            ui.label(RichText::new(format!("{} {}", egui_phosphor::regular::X, error))
+                                .size(14.0)
+                                .color(Color32::from_rgb(224, 108, 117)));
+                        }
+                        None => {}
+                    });
+                    ui.end_row();
+                }
+
+                ui.label("");
+                let link = ui.add(egui::Label::new(
+                    RichText::new("Generate API token at Atlassian")
+                        .size(14.0)
+                        .color(accent)
+                ).sense(egui::Sense::click()));
+                if link.hovered() {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                }
+                if link.clicked() {
+                    let _ = open::that("https://id.atlassian.com/manage-profile/security/api-tokens");
+                }
+                ui.end_row();
+            });
+
+        ui.add_space(20.0);
+
+        // === Display ===
+        ui.label(RichText::new("Display").color(section_color).strong());
+        ui.add_space(8.0);
+
+        egui::Grid::new("display_grid")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .show(ui, |ui| {
+                ui.label("Font scale");
+                ui.horizontal(|ui| {
+                    ui.add(egui::Slider::new(&mut self.settings_font_scale, 0.75..=2.0).show_value(false));
+                    ui.label(format!("{:.0}%", self.settings_font_scale * 100.0));
+                });
+                ui.end_row();
+
+                ui.label("Duration format");
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut self.settings_time_format, TimeFormat::HoursMinutes, "3h 15m");
+                    ui.radio_value(&mut self.settings_time_format, TimeFormat::Decimal, "3.25h");
+                });
+                ui.end_row();
+
+                ui.label("Decimals");
+                ui.add_enabled_ui(self.settings_time_format == TimeFormat::Decimal, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.radio_value(&mut self.settings_decimal_precision, 1, "1 (3.3h)");
+                        ui.radio_value(&mut self.settings_decimal_precision, 2, "2 (3.25h)");
+                        ui.radio_value(&mut self.settings_decimal_precision, 3, "3 (3.267h)");
+                    });
+                });
+                ui.end_row();
+
+                ui.label("Clock format");
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut self.settings_clock_format, ClockFormat::Hour24, "14:30");
+                    ui.radio_value(&mut self.settings_clock_format, ClockFormat::Hour12, "2:30pm");
+                });
+                ui.end_row();
+
+                ui.label("Color vision");
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut self.settings_colorblind_mode, ColorblindMode::None, "Standard");
+                    ui.radio_value(&mut self.settings_colorblind_mode, ColorblindMode::Deuteranopia, "Deuteranopia");
+                    ui.radio_value(&mut self.settings_colorblind_mode, ColorblindMode::Protanopia, "Protanopia");
+                });
+                ui.end_row();
+
+                ui.label("Status bar");
+                ui.checkbox(&mut self.settings_show_status_bar, "Show totals and sync time at the bottom");
+                ui.end_row();
+
+                ui.label("Issue links");
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut self.settings_link_behavior, LinkBehavior::OpenBrowser, "Open in browser");
+                    ui.radio_value(&mut self.settings_link_behavior, LinkBehavior::OpenInApp, "Show details");
+                });
+                ui.end_row();
+
+                ui.label("Weekends");
+                egui::ComboBox::from_id_salt("weekend_display")
+                    .selected_text(self.settings_weekend_display.label())
+                    .show_ui(ui, |ui| {
+                        for mode in [WeekendDisplay::Auto, WeekendDisplay::AlwaysShow, WeekendDisplay::AlwaysHide] {
+                            ui.selectable_value(&mut self.settings_weekend_display, mode, mode.label());
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Week starts on");
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut self.settings_week_start_day, Weekday::Mon, "Monday");
+                    ui.radio_value(&mut self.settings_week_start_day, Weekday::Sun, "Sunday");
+                });
+                ui.end_row();
+            });
+
+        ui.add_space(20.0);
+
+        // === Schedule View ===
+        ui.label(RichText::new("Schedule View").color(section_color).strong());
+        ui.add_space(8.0);
+
+        egui::Grid::new("schedule_grid")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .show(ui, |ui| {
+                ui.label("Minimum block height");
+                ui.add(egui::Slider::new(&mut self.settings_min_schedule_block_px, 10.0..=60.0).suffix("px"));
+                ui.end_row();
+
+                ui.label("Hide labels below");
+                ui.add(egui::Slider::new(&mut self.settings_show_block_label_threshold_px, 10.0..=60.0).suffix("px"));
+                ui.end_row();
+
+                ui.label("Preview");
+                views::render_schedule_block_preview(
+                    ui,
+                    &[self.settings_min_schedule_block_px, self.settings_show_block_label_threshold_px],
+                    self.settings_show_block_label_threshold_px,
+                    self.settings_colorblind_mode,
+                    &self.settings_project_colors,
+                );
+                ui.end_row();
+            });
+
+        ui.add_space(20.0);
+
+        // === Time Entry ===
+        ui.label(RichText::new("Time Entry").color(section_color).strong());
+        ui.add_space(8.0);
+
+        egui::Grid::new("entry_grid")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .show(ui, |ui| {
+                ui.label("Start time field");
+                ui.checkbox(&mut self.settings_show_start_time, "Show in dialogs");
+                ui.end_row();
+
+                ui.label("Default start time");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.settings_default_start_time)
+                        .desired_width(150.0)
+                        .hint_text("9am")
+                );
+                ui.end_row();
+
+                ui.label("Daily target");
+                ui.add(
+                    egui::DragValue::new(&mut self.settings_daily_target_hours)
+                        .range(0.0..=24.0)
+                        .speed(0.25)
+                        .suffix("h")
+                );
+                ui.end_row();
+
+                ui.label("Category tags");
+                self.render_tag_chips_editor(ui);
+                ui.end_row();
+            });
+
+        ui.add_space(20.0);
+
+        // === Tag Rules ===
+        ui.label(RichText::new("Tag Rules").color(section_color).strong());
+        ui.add_space(8.0);
+
+        let mut remove_rule = None;
+        egui::Grid::new("tag_auto_rules_grid")
+            .num_columns(3)
+            .spacing([10.0, 10.0])
+            .show(ui, |ui| {
+                for (idx, rule) in self.settings_tag_auto_rules.iter_mut().enumerate() {
+                    ui.add(egui::TextEdit::singleline(&mut rule.project_prefix)
+                        .hint_text("INFRA-")
+                        .desired_width(100.0));
+                    ui.horizontal_wrapped(|ui| {
+                        for tag in &self.settings_tags {
+                            let mut selected = rule.applies_tag(&tag.name);
+                            if ui.toggle_value(&mut selected, tag.label()).changed() {
+                                if selected {
+                                    rule.tags.push(tag.name.clone());
+                                } else {
+                                    rule.tags.retain(|t| !t.eq_ignore_ascii_case(&tag.name));
+                                }
+                            }
+                        }
+                    });
+                    if ui.button(egui_phosphor::regular::TRASH).on_hover_text("Remove").clicked() {
+                        remove_rule = Some(idx);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(idx) = remove_rule {
+            self.settings_tag_auto_rules.remove(idx);
+        }
+        if ui.button(format!("{} Add rule", egui_phosphor::regular::PLUS)).clicked() {
+            self.settings_tag_auto_rules.push(TagAutoRule::new("", Vec::new()));
+        }
+        ui.label(RichText::new("Issues whose key starts with the prefix get these tags ticked when picked in the dialog")
+            .size(12.0)
+            .color(Color32::from_rgb(150, 150, 150)));
+
+        ui.add_space(20.0);
+
+        // === Quick Actions ===
+        ui.label(RichText::new("Quick Actions").color(section_color).strong());
+        ui.add_space(8.0);
+
+        let mut remove_action = None;
+        egui::Grid::new("quick_actions_grid")
+            .num_columns(5)
+            .spacing([10.0, 10.0])
+            .show(ui, |ui| {
+                for (idx, action) in self.settings_quick_actions.iter_mut().enumerate() {
+                    ui.add(egui::TextEdit::singleline(&mut action.icon)
+                        .hint_text("globe")
+                        .desired_width(80.0));
+                    ui.add(egui::TextEdit::singleline(&mut action.label)
+                        .hint_text("Label")
+                        .desired_width(120.0));
+                    let mut kind = action.action.kind_index();
+                    egui::ComboBox::from_id_salt(("quick_action_kind", idx))
+                        .selected_text(QuickActionType::KINDS[kind])
+                        .show_ui(ui, |ui| {
+                            for (i, name) in QuickActionType::KINDS.iter().enumerate() {
+                                ui.selectable_value(&mut kind, i, *name);
+                            }
+                        });
+                    if kind != action.action.kind_index() {
+                        action.action = action.action.with_kind(kind);
+                    }
+                    let hint = match action.action {
+                        QuickActionType::OpenUrl(_) => "https://...",
+                        QuickActionType::CopyTemplate(_) => "Worked {week_total} this week",
+                        QuickActionType::RunScript(_) => "./report.sh {date}",
+                    };
+                    ui.add(egui::TextEdit::singleline(action.action.value_mut())
+                        .hint_text(hint)
+                        .desired_width(220.0));
+                    if ui.button(egui_phosphor::regular::TRASH).on_hover_text("Remove").clicked() {
+                        remove_action = Some(idx);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(idx) = remove_action {
+            self.settings_quick_actions.remove(idx);
+        }
+        if ui.button(format!("{} Add action", egui_phosphor::regular::PLUS)).clicked() {
+            self.settings_quick_actions.push(QuickAction {
+                icon: "globe".to_string(),
+                label: String::new(),
+                action: QuickActionType::OpenUrl(String::new()),
+            });
+        }
+        ui.label(RichText::new("Templates and scripts can use {date}, {week_start}, {week_end}, {day_total} and {week_total}")
+            .size(12.0)
+            .color(Color32::from_rgb(150, 150, 150)));
+
+        ui.add_space(20.0);
+
+        // === Issue Type Colors ===
+        ui.label(RichText::new("Issue Type Colors").color(section_color).strong());
+        ui.add_space(8.0);
+
+        let mut remove_type = None;
+        egui::Grid::new("issue_type_colors_grid")
+            .num_columns(3)
+            .spacing([10.0, 10.0])
+            .show(ui, |ui| {
+                for (idx, (name, rgb)) in self.settings_issue_type_colors.iter_mut().enumerate() {
+                    let built_in = idx < DEFAULT_ISSUE_TYPE_COLORS.len();
+                    if built_in {
+                        ui.label(name.as_str());
+                    } else {
+                        ui.add(egui::TextEdit::singleline(name)
+                            .hint_text("Spike")
+                            .desired_width(120.0));
+                    }
+                    ui.color_edit_button_srgb(rgb);
+                    if built_in {
+                        if *rgb != default_issue_type_color(name) && ui.button(egui_phosphor::regular::ARROW_COUNTER_CLOCKWISE).on_hover_text("Reset").clicked() {
+                            *rgb = default_issue_type_color(name);
+                        }
+                    } else if ui.button(egui_phosphor::regular::TRASH).on_hover_text("Remove").clicked() {
+                        remove_type = Some(idx);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(idx) = remove_type {
+            self.settings_issue_type_colors.remove(idx);
+        }
+        if ui.button(format!("{} Add issue type", egui_phosphor::regular::PLUS)).clicked() {
+            self.settings_issue_type_colors.push((String::new(), default_issue_type_color("Task")));
+        }
+
+        ui.add_space(20.0);
 
-                ui.label("API token");
-                ui.add(
-                    egui::TextEdit::singleline(&mut self.settings_token)
-                        .password(true)
-                        .hint_text("Leave blank to keep existing")
-                        .desired_width(350.0)
-                );
-                ui.end_row();
+        // === Project Colors ===
+        ui.label(RichText::new("Project Colors").color(section_color).strong());
+        ui.label(RichText::new("Card accent per project key; Meeting, Support and Admin tickets in these projects get the bucket colors").size(12.0).color(Color32::from_rgb(150, 150, 150)));
+        ui.add_space(8.0);
 
-                ui.label("");
-                let link = ui.add(egui::Label::new(
-                    RichText::new("Generate API token at Atlassian")
-                        .size(14.0)
-                        .color(accent)
-                ).sense(egui::Sense::click()));
-                if link.hovered() {
-                    ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
-                }
-                if link.clicked() {
-                    let _ = open::that("https://id.atlassian.com/manage-profile/security/api-tokens");
+        let mut remove_project = None;
+        egui::Grid::new("project_colors_grid")
+            .num_columns(3)
+            .spacing([10.0, 10.0])
+            .show(ui, |ui| {
+                for (idx, (prefix, rgb)) in self.settings_project_colors.iter_mut().enumerate() {
+                    ui.add(egui::TextEdit::singleline(prefix)
+                        .hint_text("PROJ")
+                        .desired_width(120.0));
+                    ui.color_edit_button_srgb(rgb);
+                    if ui.button(egui_phosphor::regular::TRASH).on_hover_text("Remove").clicked() {
+                        remove_project = Some(idx);
+                    }
+                    ui.end_row();
                 }
-                ui.end_row();
             });
+        if let Some(idx) = remove_project {
+            self.settings_project_colors.remove(idx);
+        }
+        if ui.button(format!("{} Add project", egui_phosphor::regular::PLUS)).clicked() {
+            self.settings_project_colors.push((String::new(), [0x13, 0x98, 0xf4]));
+        }
 
         ui.add_space(20.0);
 
-        // === Display ===
-        ui.label(RichText::new("Display").color(section_color).strong());
+        // === Export ===
+        ui.label(RichText::new("Export").color(section_color).strong());
         ui.add_space(8.0);
 
-        egui::Grid::new("display_grid")
+        egui::Grid::new("export_grid")
             .num_columns(2)
             .spacing([20.0, 10.0])
             .show(ui, |ui| {
-                ui.label("Font scale");
-                ui.horizontal(|ui| {
-                    ui.add(egui::Slider::new(&mut self.settings_font_scale, 0.75..=2.0).show_value(false));
-                    ui.label(format!("{:.0}%", self.settings_font_scale * 100.0));
-                });
-                ui.end_row();
-
-                ui.label("Duration format");
+                let default_dir = export::default_export_directory()
+                    .map(|dir| dir.display().to_string())
+                    .unwrap_or_default();
+                ui.label("Export folder");
                 ui.horizontal(|ui| {
-                    ui.radio_value(&mut self.settings_time_format, TimeFormat::HoursMinutes, "3h 15m");
-                    ui.radio_value(&mut self.settings_time_format, TimeFormat::Decimal, "3.25h");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings_export_directory)
+                            .hint_text(&default_dir)
+                            .desired_width(350.0)
+                    );
+                    let folder = if self.settings_export_directory.trim().is_empty() {
+                        default_dir.clone()
+                    } else {
+                        self.settings_export_directory.trim().to_string()
+                    };
+                    if ui.button("Browse…").on_hover_text("Choose the folder").clicked() {
+                        if let Some(picked) = rfd::FileDialog::new().set_directory(&folder).pick_folder() {
+                            self.settings_export_directory = picked.display().to_string();
+                        }
+                    }
+                    if ui.button("Open").on_hover_text("Show the folder in your file manager").clicked() {
+                        let opened = std::fs::create_dir_all(&folder).and_then(|_| open::that(&folder));
+                        if let Err(e) = opened {
+                            self.status_message = Some((format!("Could not open {}: {}", folder, e), true));
+                        }
+                    }
                 });
                 ui.end_row();
 
-                ui.label("Clock format");
-                ui.horizontal(|ui| {
-                    ui.radio_value(&mut self.settings_clock_format, ClockFormat::Hour24, "14:30");
-                    ui.radio_value(&mut self.settings_clock_format, ClockFormat::Hour12, "2:30pm");
-                });
+                ui.label("");
+                ui.label(RichText::new("JSON, CSV and calendar exports are saved here; leave blank for the default")
+                    .size(12.0)
+                    .color(Color32::from_rgb(150, 150, 150)));
                 ui.end_row();
             });
 
         ui.add_space(20.0);
 
-        // === Time Entry ===
-        ui.label(RichText::new("Time Entry").color(section_color).strong());
+        // === Updates ===
+        ui.label(RichText::new("Updates").color(section_color).strong());
         ui.add_space(8.0);
 
-        egui::Grid::new("entry_grid")
+        egui::Grid::new("updates_grid")
             .num_columns(2)
             .spacing([20.0, 10.0])
             .show(ui, |ui| {
-                ui.label("Start time field");
-                ui.checkbox(&mut self.settings_show_start_time, "Show in dialogs");
+                ui.label("Update channel");
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut self.settings_update_channel, UpdateChannel::Stable, "Stable");
+                    ui.radio_value(&mut self.settings_update_channel, UpdateChannel::PreRelease, "Pre-release");
+                });
                 ui.end_row();
+            });
 
-                ui.label("Category tags");
-                ui.add(
-                    egui::TextEdit::multiline(&mut self.settings_tags)
-                        .hint_text("FE, BE, Bugfix, ...")
-                        .desired_width(400.0)
-                        .desired_rows(3)
-                );
-                ui.end_row();
+        ui.add_space(20.0);
+
+        // === Advanced ===
+        egui::CollapsingHeader::new(RichText::new("Advanced").color(section_color).strong())
+            .default_open(false)
+            .show(ui, |ui| {
+                egui::Grid::new("advanced_grid")
+                    .num_columns(2)
+                    .spacing([20.0, 10.0])
+                    .show(ui, |ui| {
+                        ui.label("Connect timeout");
+                        ui.add(egui::DragValue::new(&mut self.settings_connect_timeout_secs).range(1..=120).suffix(" s"));
+                        ui.end_row();
+
+                        ui.label("Request timeout");
+                        ui.add(egui::DragValue::new(&mut self.settings_request_timeout_secs).range(5..=600).suffix(" s"));
+                        ui.end_row();
+
+                        ui.label("Retries on timeout");
+                        ui.add(egui::DragValue::new(&mut self.settings_max_retries).range(0..=5));
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.label(RichText::new("Raise these if requests through a slow proxy or VPN give up too early")
+                            .size(12.0)
+                            .color(Color32::from_rgb(150, 150, 150)));
+                        ui.end_row();
+                    });
             });
 
         ui.add_space(24.0);
@@ -1636,6 +4512,17 @@ impl eframe::App for JiraTimeApp {
         // Check for async results
         self.check_async_results();
 
+        self.track_window_state(ctx);
+
+        // "?" or F1 toggles the keyboard shortcut overlay (unless typing in a field)
+        let toggle_shortcuts = ctx.input(|i| {
+            i.key_pressed(egui::Key::F1)
+                || i.events.iter().any(|e| matches!(e, egui::Event::Text(t) if t == "?"))
+        }) && ctx.memory(|m| m.focused().is_none());
+        if toggle_shortcuts {
+            self.show_shortcuts = !self.show_shortcuts;
+        }
+
         // Handle graceful restart after update
         if self.restart_pending {
             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
@@ -1683,7 +4570,7 @@ impl eframe::App for JiraTimeApp {
             ProgressPhase::Shrinking => {
                 // Shrink to 0 fast (0.2 seconds)
                 let t = (elapsed / 0.2).min(1.0);
-                self.progress = self.progress * (1.0 - t);
+                self.progress *= 1.0 - t;
                 if elapsed >= 0.2 {
                     self.progress_phase = ProgressPhase::Idle;
                     self.progress = 0.0;
@@ -1694,13 +4581,54 @@ impl eframe::App for JiraTimeApp {
 
         // Render the dialog window if open
         if self.show_dialog {
+            // Pre-fills are in place by the first frame, before any typing
+            if self.dialog_opened_input.is_none() {
+                self.dialog_opened_input = Some(self.dialog_input());
+            }
             let title = match self.dialog_mode {
                 DialogMode::Add => "Log time",
                 DialogMode::Edit => "Edit log",
             };
 
-            let mut selected_issue: Option<(String, String, String)> = None;
+            let mut selected_issue: Option<ValidatedIssue> = None;
             let mut close_requested = false;
+            let slash_pressed = ctx.input(|i| i.key_pressed(egui::Key::Slash))
+                && ctx.memory(|m| m.focused().is_none());
+
+            // Arrow keys move through the open dropdown and Enter picks the highlighted row;
+            // consumed here so the issue field neither moves its cursor nor drops focus
+            let mut keyboard_pick = false;
+            let mut selection_moved = false;
+            if self.show_suggestions {
+                let count = self.visible_suggestions().len();
+                let (down, up, enter) = ctx.input_mut(|i| (
+                    i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                    i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                    self.autocomplete_selection.is_some() && i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                ));
+                selection_moved = count > 0 && (down || up);
+                if count > 0 && down {
+                    self.autocomplete_selection = Some(self.autocomplete_selection.map_or(0, |i| (i + 1) % count));
+                } else if count > 0 && up {
+                    self.autocomplete_selection = Some(self.autocomplete_selection.map_or(count - 1, |i| (i + count - 1) % count));
+                }
+                if enter {
+                    if let Some(issue) = self.autocomplete_selection.and_then(|i| self.visible_suggestions().get(i).copied()) {
+                        selected_issue = Some(ValidatedIssue::from_issue(issue));
+                        keyboard_pick = true;
+                    }
+                }
+            }
+
+            // Escape closes the suggestion dropdown first, then the dialog
+            if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+                if self.show_suggestions {
+                    self.show_suggestions = false;
+                    self.autocomplete_selection = None;
+                } else {
+                    close_requested = true;
+                }
+            }
 
             let (content_bg, frame_color, _) = super::theme::dialog_colors();
             let dialog_frame = egui::Frame::none()
@@ -1721,17 +4649,57 @@ impl eframe::App for JiraTimeApp {
                 .show(ctx, |ui| {
                     ui.set_min_width(550.0);
 
+                    // Offer the input from a cancelled add dialog
+                    if matches!(self.dialog_mode, DialogMode::Add) {
+                        if let Some(draft) = &self.dialog_draft {
+                            let mut parts = Vec::new();
+                            if !draft.issue.trim().is_empty() {
+                                parts.push(draft.issue.trim().to_string());
+                            }
+                            if !draft.hours.trim().is_empty() {
+                                parts.push(draft.hours.trim().to_string());
+                            }
+                            let detail = if parts.is_empty() { String::new() } else { format!(" ({})", parts.join(", ")) };
+                            let mut restore = false;
+                            let mut dismiss = false;
+                            ui.horizontal(|ui| {
+                                ui.label(RichText::new(format!("Restore draft?{}", detail)).size(14.0).color(Color32::from_rgb(176, 176, 168)));
+                                if ui.button("Restore").clicked() {
+                                    restore = true;
+                                }
+                                if ui.button("Dismiss").clicked() {
+                                    dismiss = true;
+                                }
+                            });
+                            ui.add_space(12.0);
+                            if restore {
+                                self.restore_dialog_draft();
+                            } else if dismiss {
+                                self.dialog_draft = None;
+                            }
+                        }
+                    }
+
                     // Quick-add buttons at top of Add dialog (only when issue not yet selected)
-                    if matches!(self.dialog_mode, DialogMode::Add) && self.validated_issue.is_none() && !self.weekly_buckets.is_empty() {
+                    let buckets_pending = self.weekly_buckets.is_empty() && self.weekly_buckets_loading;
+                    if matches!(self.dialog_mode, DialogMode::Add) && self.validated_issue.is_none() && buckets_pending {
+                        // Placeholder while this week's bucket tickets are looked up
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(RichText::new("Finding weekly buckets...").size(14.0).color(Color32::from_rgb(150, 150, 150)));
+                        });
+                        ui.add_space(12.0);
+                    } else if matches!(self.dialog_mode, DialogMode::Add) && self.validated_issue.is_none() && !self.weekly_buckets.is_empty() {
                         ui.horizontal(|ui| {
                             let btn_bg = Color32::from_rgb(0x2a, 0x2a, 0x32);
                             let btn_hover = Color32::from_rgb(0x45, 0x45, 0x50);
 
                             // (category, label, accent_color)
+                            let colorblind_mode = self.config.colorblind_mode;
                             let button_config = [
-                                ("MEETING", "Meeting", Color32::from_rgb(0xdc, 0x26, 0x7f)),
-                                ("SUPPORT", "Support", Color32::from_rgb(0xfe, 0x61, 0x00)),
-                                ("ADMIN", "Admin", Color32::from_rgb(0xff, 0xb0, 0x00)),
+                                ("MEETING", "Meeting", super::theme::dialog_bucket_color(BucketKind::Meeting, colorblind_mode)),
+                                ("SUPPORT", "Support", super::theme::dialog_bucket_color(BucketKind::Support, colorblind_mode)),
+                                ("ADMIN", "Admin", super::theme::dialog_bucket_color(BucketKind::Admin, colorblind_mode)),
                             ];
 
                             for (cat, label, accent_color) in button_config {
@@ -1749,8 +4717,10 @@ impl eframe::App for JiraTimeApp {
 
                                     if response.on_hover_text(format!("[{}] {}", issue_key, issue_summary)).clicked() {
                                         self.dialog_issue = issue_key.clone();
-                                        self.validated_issue = Some((issue_key.clone(), issue_summary.clone(), issue_type.clone()));
+                                        self.validated_issue = Some(ValidatedIssue::without_estimate(issue_key, issue_summary, issue_type));
+                                        self.apply_tag_auto_rules();
                                         self.dialog_accent_color = Some(accent_color);
+                                        self.issue_from_clipboard = false;
                                     }
                                 }
                             }
@@ -1758,12 +4728,51 @@ impl eframe::App for JiraTimeApp {
                         ui.add_space(12.0);
                     }
 
+                    // Recently logged issues as one-click chips (Add mode, before an issue is picked)
+                    if matches!(self.dialog_mode, DialogMode::Add) && self.validated_issue.is_none() && !self.recent_issues.is_empty() {
+                        let mut picked = None;
+                        ui.horizontal_wrapped(|ui| {
+                            for (idx, (issue_key, issue_summary, issue_type)) in self.recent_issues.iter().enumerate() {
+                                let response = views::render_issue_chip(ui, issue_key, issue_type, &self.config.issue_type_colors);
+                                if response.on_hover_text(format!("[{}] {}", issue_key, issue_summary)).clicked() {
+                                    picked = Some(idx);
+                                }
+                            }
+                        });
+                        ui.add_space(12.0);
+
+                        if let Some((issue_key, issue_summary, issue_type)) = picked.and_then(|idx| self.recent_issues.get(idx).cloned()) {
+                            // Keep the estimate bar when this week already has the issue's totals
+                            let known = self.week_data.entries.iter().find(|e| e.issue_key == issue_key);
+                            self.validated_issue = Some(match known {
+                                Some(entry) => ValidatedIssue {
+                                    key: issue_key.clone(),
+                                    summary: issue_summary.clone(),
+                                    issue_type: issue_type.clone(),
+                                    status: entry.issue_status.clone(),
+                                    time_spent: entry.time_spent_total,
+                                    original_estimate: entry.time_original_estimate,
+                                },
+                                None => ValidatedIssue::without_estimate(&issue_key, &issue_summary, &issue_type),
+                            });
+                            self.dialog_accent_color = match super::theme::bucket_kind(&issue_key, &issue_summary, &self.config.project_colors) {
+                                BucketKind::Regular => None,
+                                kind => Some(super::theme::dialog_bucket_color(kind, self.config.colorblind_mode)),
+                            };
+                            self.dialog_issue = issue_key;
+                            self.show_suggestions = false;
+                            self.issue_from_clipboard = false;
+                            self.apply_tag_auto_rules();
+                            self.focus_duration_pending = true;
+                        }
+                    }
+
                     // Use Grid for proper label/input alignment
                     let mut issue_response_opt: Option<egui::Response> = None;
 
                     // Check if issue is validated (either pre-selected from quick buttons or manually selected)
                     let is_validated = self.validated_issue.as_ref()
-                        .map(|(key, _, _)| key == &self.dialog_issue)
+                        .map(|issue| issue.key == self.dialog_issue)
                         .unwrap_or(false);
 
                     egui::Grid::new("log_time_grid")
@@ -1774,10 +4783,10 @@ impl eframe::App for JiraTimeApp {
                             ui.label("Issue");
                             if is_validated {
                                 // Show colored issue summary
-                                if let Some((key, summary, _)) = &self.validated_issue {
+                                if let Some(issue) = &self.validated_issue {
                                     let accent = self.dialog_accent_color.unwrap_or(Color32::from_rgb(0x13, 0x98, 0xf4));
                                     ui.add(egui::Label::new(
-                                        RichText::new(format!("[{}] {}", key, summary)).size(14.0).color(accent)
+                                        RichText::new(format!("[{}] {}", issue.key, issue.summary)).size(14.0).color(accent)
                                     ).truncate());
                                 }
                             } else {
@@ -1796,6 +4805,7 @@ impl eframe::App for JiraTimeApp {
                                     let issue_response = issue_frame.show(ui, |ui| {
                                         ui.add(
                                             egui::TextEdit::singleline(&mut self.dialog_issue)
+                                                .id(egui::Id::new(ISSUE_FIELD_ID))
                                                 .desired_width(350.0)
                                                 .hint_text("Type to search issues...")
                                         )
@@ -1805,6 +4815,12 @@ impl eframe::App for JiraTimeApp {
                                         ui.spinner();
                                     }
 
+                                    // "/" focuses the issue field when no other field has focus
+                                    if (self.focus_issue_pending || slash_pressed) && !self.focus_duration_pending {
+                                        issue_response.request_focus();
+                                    }
+                                    self.focus_issue_pending = false;
+
                                     // Handle focus and text changes for autocomplete
                                     if issue_response.gained_focus() {
                                         self.show_suggestions = !self.issue_suggestions.is_empty();
@@ -1818,6 +4834,15 @@ impl eframe::App for JiraTimeApp {
                                     if issue_response.changed() {
                                         // Clear error when user types
                                         self.error_issue = false;
+                                        self.issue_from_clipboard = false;
+                                        self.issue_from_draft = false;
+                                        self.autocomplete_selection = None;
+                                        // Normalize key-like input ("proj 123" -> "PROJ-123") so search and matching see the real key
+                                        let known_projects: Vec<&str> = self.recent_issues.iter().map(|(key, _, _)| key.as_str())
+                                            .chain(self.week_data.entries.iter().map(|e| e.issue_key.as_str()))
+                                            .filter_map(|key| key.split_once('-').map(|(project, _)| project))
+                                            .collect();
+                                        self.dialog_issue = normalize_issue_key(&self.dialog_issue, &known_projects);
                                         // Invalidate validation when text changes
                                         self.validated_issue = None;
                                         self.last_search_time = Instant::now();
@@ -1825,11 +4850,9 @@ impl eframe::App for JiraTimeApp {
                                         // Check if the typed text matches a suggestion exactly
                                         let typed_upper = self.dialog_issue.to_uppercase();
                                         if let Some(issue) = self.issue_suggestions.iter().find(|i| i.key == typed_upper) {
-                                            let issue_type = issue.fields.issue_type.as_ref()
-                                                .map(|t| t.name.clone())
-                                                .unwrap_or_else(|| "Task".to_string());
-                                            self.validated_issue = Some((issue.key.clone(), issue.fields.summary.clone(), issue_type));
+                                            self.validated_issue = Some(ValidatedIssue::from_issue(issue));
                                             self.dialog_issue = issue.key.clone();
+                                            self.apply_tag_auto_rules();
                                         }
                                     }
 
@@ -1846,14 +4869,43 @@ impl eframe::App for JiraTimeApp {
                             }
                             ui.end_row();
 
+                            if self.issue_from_clipboard {
+                                ui.label("");
+                                ui.label(RichText::new("Pasted from clipboard").size(12.0).color(Color32::from_rgb(150, 150, 150)));
+                                ui.end_row();
+                            }
+
+                            // How much of the estimate is used, and whether this entry will go over
+                            if let Some(issue) = self.validated_issue.as_ref().filter(|i| is_validated && i.original_estimate > 0) {
+                                if self.dialog_mode == DialogMode::Add {
+                                    let adding = parse_duration(&self.dialog_hours).unwrap_or(0);
+                                    ui.label("");
+                                    views::render_estimate_progress(ui, issue.time_spent, issue.original_estimate, adding, 350.0);
+                                    ui.end_row();
+                                }
+                            }
+
                             // Start time field (optional, controlled by settings)
                             if self.config.show_start_time {
                                 ui.label("Start");
-                                ui.add(
-                                    egui::TextEdit::singleline(&mut self.dialog_start_time)
-                                        .desired_width(150.0)
-                                        .hint_text("9am")
-                                );
+                                let start_frame = if self.error_start_time {
+                                    egui::Frame::none()
+                                        .stroke(egui::Stroke::new(2.0, Color32::from_rgb(0xff, 0x44, 0x44)))
+                                        .rounding(4.0)
+                                        .inner_margin(2.0)
+                                } else {
+                                    egui::Frame::none()
+                                };
+                                let start_response = start_frame.show(ui, |ui| {
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.dialog_start_time)
+                                            .desired_width(150.0)
+                                            .hint_text("9am")
+                                    )
+                                }).inner;
+                                if start_response.changed() {
+                                    self.error_start_time = false;
+                                }
                                 ui.end_row();
                             }
 
@@ -1868,16 +4920,54 @@ impl eframe::App for JiraTimeApp {
                             } else {
                                 egui::Frame::none()
                             };
-                            let hours_response = hours_frame.show(ui, |ui| {
-                                ui.add(
-                                    egui::TextEdit::singleline(&mut self.dialog_hours)
-                                        .desired_width(150.0)
-                                        .hint_text("1h 30m")
-                                )
+                            let hours_response = ui.horizontal(|ui| {
+                                let response = hours_frame.show(ui, |ui| {
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.dialog_hours)
+                                            .desired_width(150.0)
+                                            .hint_text("1h 30m")
+                                    )
+                                }).inner;
+
+                                // Stopwatch: offered while the duration is empty, fills it in when stopped
+                                match self.dialog_timer {
+                                    TimerState::Idle => {
+                                        if self.dialog_mode == DialogMode::Add && self.dialog_hours.trim().is_empty()
+                                            && ui.button(format!("{} Start timer", egui_phosphor::regular::TIMER)).clicked()
+                                        {
+                                            self.dialog_timer = TimerState::Running { start: Instant::now() };
+                                        }
+                                    }
+                                    TimerState::Running { start } => {
+                                        let elapsed = start.elapsed().as_secs();
+                                        ui.label(RichText::new(format!("{}:{:02}:{:02}", elapsed / 3600, elapsed / 60 % 60, elapsed % 60))
+                                            .family(super::theme::bold_family())
+                                            .color(Color32::WHITE));
+                                        if ui.button(format!("{} Stop", egui_phosphor::regular::STOP)).clicked() {
+                                            // Nearest whole minute, at least one - Jira rejects shorter worklogs
+                                            let seconds = ((elapsed as i64 + 30) / 60).max(1) * 60;
+                                            self.dialog_hours = format_duration_with_format(seconds, self.config.time_format, self.config.decimal_precision);
+                                            self.error_hours = false;
+                                            self.dialog_timer = TimerState::Idle;
+                                        } else {
+                                            ui.ctx().request_repaint_after(std::time::Duration::from_secs(1));
+                                        }
+                                    }
+                                }
+                                response
                             }).inner;
                             if hours_response.changed() {
                                 self.error_hours = false;
                             }
+                            if self.focus_duration_pending {
+                                hours_response.request_focus();
+                                self.focus_duration_pending = false;
+                            }
+                            // Enter in the duration field submits (single-line edits drop focus on Enter)
+                            let enter_pressed = ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            if hours_response.lost_focus() && enter_pressed && !self.show_suggestions && !self.loading {
+                                self.save_dialog();
+                            }
                             ui.end_row();
                         });
 
@@ -1889,10 +4979,41 @@ impl eframe::App for JiraTimeApp {
                             .rounding(egui::Rounding::same(4.0))
                             .inner_margin(egui::Margin::same(4.0))
                             .show(ui, |ui| {
+                                // Project chips when results span several projects (filters client-side)
+                                let mut projects: Vec<&str> = Vec::new();
+                                for issue in &self.issue_suggestions {
+                                    if !projects.contains(&issue.project_key()) {
+                                        projects.push(issue.project_key());
+                                    }
+                                }
+                                if projects.len() > 1 {
+                                    let mut chip_clicked = None;
+                                    ui.horizontal_wrapped(|ui| {
+                                        ui.spacing_mut().item_spacing.x = 6.0;
+                                        if ui.selectable_label(self.suggestion_project_filter.is_none(), RichText::new("All").size(12.0)).clicked() {
+                                            chip_clicked = Some(None);
+                                        }
+                                        for project in &projects {
+                                            let selected = self.suggestion_project_filter.as_deref() == Some(*project);
+                                            if ui.selectable_label(selected, RichText::new(*project).size(12.0)).clicked() {
+                                                chip_clicked = Some(Some(project.to_string()));
+                                            }
+                                        }
+                                    });
+                                    ui.separator();
+                                    if let Some(filter) = chip_clicked {
+                                        self.suggestion_project_filter = filter;
+                                        self.autocomplete_selection = None;
+                                        // Clicking a chip takes focus from the issue field; hand it back
+                                        self.focus_issue_pending = true;
+                                    }
+                                }
+
+                                let accent = Color32::from_rgb(0x13, 0x98, 0xf4);
                                 egui::ScrollArea::vertical()
                                     .max_height(200.0)
                                     .show(ui, |ui| {
-                                        for issue in &self.issue_suggestions {
+                                        for (idx, issue) in self.visible_suggestions().into_iter().enumerate() {
                                             let text = format!("{} - {}", issue.key, issue.fields.summary);
                                             let display_text = if text.len() > 70 {
                                                 format!("{}...", &text[..67])
@@ -1900,16 +5021,18 @@ impl eframe::App for JiraTimeApp {
                                                 text
                                             };
 
+                                            let highlighted = self.autocomplete_selection == Some(idx);
+                                            let label = RichText::new(&display_text).size(14.0);
                                             let response = ui.selectable_label(
-                                                false,
-                                                RichText::new(&display_text).size(14.0)
+                                                highlighted,
+                                                if highlighted { label.color(accent) } else { label }
                                             );
+                                            if highlighted && selection_moved {
+                                                response.scroll_to_me(None);
+                                            }
 
                                             if response.clicked() {
-                                                let issue_type = issue.fields.issue_type.as_ref()
-                                                    .map(|t| t.name.clone())
-                                                    .unwrap_or_else(|| "Task".to_string());
-                                                selected_issue = Some((issue.key.clone(), issue.fields.summary.clone(), issue_type));
+                                                selected_issue = Some(ValidatedIssue::from_issue(issue));
                                             }
                                         }
                                     });
@@ -1918,16 +5041,31 @@ impl eframe::App for JiraTimeApp {
 
                     // Description outside the grid for more room
                     ui.add_space(15.0);
-                    ui.label("Description");
+                    ui.horizontal(|ui| {
+                        ui.label("Description");
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.toggle_value(&mut self.dialog_description_preview, RichText::new("Preview").size(12.0))
+                                .on_hover_text("Show the description as it will look in Jira");
+                        });
+                    });
 
                     // Category tags as small, minimal chips - blue text by default, white on blue when selected
                     ui.horizontal_wrapped(|ui| {
                         ui.spacing_mut().item_spacing.x = 6.0;
+                        let padding = egui::vec2(6.0, 3.0);
+
+                        // Shrink chip text if the widest tag can't fit on a row by itself
+                        let max_text_width = ui.available_width() - padding.x * 2.0;
+                        let widest = self.config.tags.iter()
+                            .map(|tag| ui.fonts(|f| f.layout_no_wrap(tag.label(), egui::FontId::proportional(TAG_CHIP_FONT_SIZE), Color32::WHITE).size().x))
+                            .fold(0.0_f32, f32::max);
+                        let font_size = tag_chip_font_size(widest, max_text_width);
+
                         for (i, tag) in self.config.tags.iter().enumerate() {
                             let selected = self.dialog_categories.get(i).copied().unwrap_or(false);
-                            let font_id = egui::FontId::proportional(13.0);
-                            let text_size = ui.fonts(|f| f.layout_no_wrap(tag.to_string(), font_id.clone(), Color32::WHITE).size());
-                            let padding = egui::vec2(6.0, 3.0);
+                            let font_id = egui::FontId::proportional(font_size);
+                            let label = tag.label();
+                            let text_size = ui.fonts(|f| f.layout_no_wrap(label.clone(), font_id.clone(), Color32::WHITE).size());
                             let button_size = text_size + padding * 2.0;
 
                             let (rect, response) = ui.allocate_exact_size(button_size, egui::Sense::click());
@@ -1942,7 +5080,7 @@ impl eframe::App for JiraTimeApp {
                             if selected {
                                 ui.painter().rect_filled(rect, egui::Rounding::same(3.0), bg_color);
                             }
-                            ui.painter().text(rect.center(), egui::Align2::CENTER_CENTER, tag.as_str(), font_id, text_color);
+                            ui.painter().text(rect.center(), egui::Align2::CENTER_CENTER, &label, font_id, text_color);
 
                             // Set pointer cursor
                             if response.hovered() {
@@ -1959,15 +5097,35 @@ impl eframe::App for JiraTimeApp {
 
                     // Calculate max height for description - leave room for buttons below
                     // Use a reasonable max that keeps dialog within typical window bounds
-                    let max_desc_height = (ctx.screen_rect().height() - 400.0).max(100.0).min(300.0);
+                    let max_desc_height = (ctx.screen_rect().height() - 400.0).clamp(100.0, 300.0);
+
+                    // Grow from 5 to 10 rows while focused; only shrink back if the text fits in 5
+                    let desc_id = egui::Id::new("dialog_description");
+                    let desc_focused = ui.memory(|mem| mem.has_focus(desc_id));
+                    let fits_collapsed = self.dialog_description.lines().count() <= 5;
+                    let target_rows = if desc_focused || !fits_collapsed { 10.0 } else { 5.0 };
+                    let rows = ctx.animate_value_with_time(desc_id.with("rows"), target_rows, 0.2);
+                    let row_height = ui.text_style_height(&egui::TextStyle::Body);
 
                     egui::ScrollArea::vertical()
                         .max_height(max_desc_height)
                         .show(ui, |ui| {
+                            if self.dialog_description_preview {
+                                ui.set_min_height(5.0 * row_height);
+                                ui.set_width(ui.available_width());
+                                if self.dialog_description.trim().is_empty() {
+                                    ui.label(RichText::new("Nothing to preview").color(Color32::from_rgb(150, 150, 150)));
+                                } else {
+                                    views::render_markdown_preview(ui, &self.dialog_description);
+                                }
+                                return;
+                            }
                             ui.add(
                                 egui::TextEdit::multiline(&mut self.dialog_description)
+                                    .id(desc_id)
                                     .desired_width(ui.available_width())
                                     .desired_rows(5)
+                                    .min_size(egui::vec2(0.0, rows * row_height))
                                     .hint_text("What did you work on?")
                             );
                         });
@@ -2033,13 +5191,21 @@ impl eframe::App for JiraTimeApp {
                 });
 
             // Handle issue selection (after window closure for borrow checker)
-            if let Some((key, summary, issue_type)) = selected_issue {
-                self.dialog_issue = key.clone();
-                self.validated_issue = Some((key, summary, issue_type));
+            if let Some(issue) = selected_issue {
+                self.dialog_issue = issue.key.clone();
+                self.validated_issue = Some(issue);
+                self.apply_tag_auto_rules();
                 self.show_suggestions = false;
+                self.autocomplete_selection = None;
+                self.issue_from_clipboard = false;
+                if keyboard_pick {
+                    self.focus_duration_pending = true;
+                }
             }
             if close_requested || !dialog_open {
+                self.stash_dialog_draft();
                 self.show_dialog = false;
+                self.dialog_timer = TimerState::Idle;
             }
         }
 
@@ -2063,7 +5229,7 @@ impl eframe::App for JiraTimeApp {
                 .show(ctx, |ui| {
                     self.render_settings_with_colors(ui, frame_color, frame_text);
                 });
-            if !settings_open {
+            if !settings_open || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
                 self.show_settings = false;
             }
         }
@@ -2099,7 +5265,7 @@ impl eframe::App for JiraTimeApp {
                             ui.label("-");
                             ui.add(egui::Label::new(&entry.issue_summary).truncate());
                         });
-                        ui.label(format_duration_with_format(entry.seconds, self.config.time_format));
+                        ui.label(format_duration_with_format(entry.seconds, self.config.time_format, self.config.decimal_precision));
                     }
 
                     ui.add_space(20.0);
@@ -2132,7 +5298,7 @@ impl eframe::App for JiraTimeApp {
                         let cancel_bg = if cancel_response.hovered() { btn_hover } else { btn_bg };
                         ui.painter().rect_filled(cancel_rect, rounding, cancel_bg);
                         ui.painter().text(cancel_rect.center(), egui::Align2::CENTER_CENTER, cancel_text, font_id, text_color);
-                        if cancel_response.clicked() {
+                        if cancel_response.clicked() || ui.input(|i| i.key_pressed(egui::Key::Escape)) {
                             cancel_delete = true;
                         }
                     });
@@ -2150,6 +5316,199 @@ impl eframe::App for JiraTimeApp {
             }
         }
 
+        if let Some(issue_key) = self.issue_details.clone() {
+            self.render_issue_details(ctx, &issue_key);
+        }
+
+        if self.annotation_edit.is_some() {
+            self.render_annotation_editor(ctx);
+        }
+
+        // Render keyboard shortcut overlay
+        if self.show_shortcuts {
+            let (content_bg, frame_color, _) = super::theme::dialog_colors();
+            let dialog_frame = egui::Frame::none()
+                .fill(content_bg)
+                .stroke(egui::Stroke::new(2.0, frame_color))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(20.0));
+
+            let command = if cfg!(target_os = "macos") { "Cmd" } else { "Ctrl" };
+            let shortcuts = [
+                (format!("{}+L", command), "Log time (focuses the issue field)"),
+                ("/".to_string(), "Focus the issue field in the log time dialog"),
+                ("Up / Down".to_string(), "Move through issue suggestions (Enter picks one)"),
+                ("Enter".to_string(), "Save (in the duration field)"),
+                ("N".to_string(), "Log time"),
+                (format!("{}+Z", command), "Undo the last add, edit or delete"),
+                ("Escape".to_string(), "Close suggestions, then the dialog (or settings, or delete confirmation)"),
+                ("Left / Right".to_string(), "Previous / next week"),
+                ("Alt+Left / Alt+Right".to_string(), "Back / forward through viewed weeks"),
+                (format!("{}+Mousewheel", command), "Zoom the interface"),
+                ("? or F1".to_string(), "Show or hide this list"),
+            ];
+
+            let mut shortcuts_open = true;
+            egui::Window::new("Keyboard shortcuts")
+                .open(&mut shortcuts_open)
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .frame(dialog_frame)
+                .show(ctx, |ui| {
+                    egui::Grid::new("shortcuts_grid")
+                        .num_columns(2)
+                        .spacing([24.0, 8.0])
+                        .show(ui, |ui| {
+                            for (keys, action) in &shortcuts {
+                                ui.label(RichText::new(keys).size(14.0).strong());
+                                ui.label(RichText::new(*action).size(14.0));
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            if !shortcuts_open || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+                self.show_shortcuts = false;
+            }
+        }
+
+        // Render weekly summary dialog
+        if self.show_summary {
+            let (content_bg, frame_color, _) = super::theme::dialog_colors();
+            let dialog_frame = egui::Frame::none()
+                .fill(content_bg)
+                .stroke(egui::Stroke::new(2.0, frame_color))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(20.0));
+
+            let mut summary_dialog_open = true;
+            egui::Window::new("Week summary")
+                .open(&mut summary_dialog_open)
+                .collapsible(false)
+                .resizable(true)
+                .default_width(500.0)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .frame(dialog_frame)
+                .show(ctx, |ui| {
+                    if self.summary_text.is_empty() && self.summary_streaming {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(RichText::new("Summarizing...").size(14.0));
+                        });
+                    } else {
+                        egui::ScrollArea::vertical()
+                            .max_height(300.0)
+                            .show(ui, |ui| {
+                                ui.label(RichText::new(self.summary_text.trim()).size(14.0));
+                            });
+                    }
+                });
+
+            if self.summary_streaming {
+                ctx.request_repaint_after(std::time::Duration::from_millis(100));
+            }
+            if !summary_dialog_open {
+                self.show_summary = false;
+            }
+        }
+
+        // Render custom range export dialog
+        if self.show_export_range {
+            let mut do_preview = false;
+            let mut do_export = false;
+
+            let (content_bg, frame_color, _) = super::theme::dialog_colors();
+            let dialog_frame = egui::Frame::none()
+                .fill(content_bg)
+                .stroke(egui::Stroke::new(2.0, frame_color))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(20.0));
+
+            let range = self.export_range_dates();
+            // The preview only counts while it matches the entered dates
+            let preview = self.export_range_preview.as_ref()
+                .filter(|(start, end, _)| range == Some((*start, *end)));
+
+            let mut export_dialog_open = true;
+            egui::Window::new("Custom export")
+                .open(&mut export_dialog_open)
+                .collapsible(false)
+                .resizable(false)
+                .default_width(360.0)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .frame(dialog_frame)
+                .show(ctx, |ui| {
+                    egui::Grid::new("export_range_grid")
+                        .num_columns(2)
+                        .spacing([12.0, 8.0])
+                        .show(ui, |ui| {
+                            ui.label("From");
+                            ui.add(egui::TextEdit::singleline(&mut self.export_range_start)
+                                .desired_width(150.0)
+                                .hint_text("YYYY-MM-DD"));
+                            ui.end_row();
+
+                            ui.label("To");
+                            ui.add(egui::TextEdit::singleline(&mut self.export_range_end)
+                                .desired_width(150.0)
+                                .hint_text("YYYY-MM-DD"));
+                            ui.end_row();
+                        });
+
+                    ui.add_space(10.0);
+
+                    if self.export_range_loading {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(RichText::new("Fetching worklogs...").size(14.0));
+                        });
+                    } else if let Some((_, _, entries)) = preview {
+                        let total: i64 = entries.iter().map(|e| e.seconds).sum();
+                        ui.label(RichText::new(format!(
+                            "{} entries, {} total",
+                            entries.len(),
+                            format_duration_with_format(total, self.config.time_format, self.config.decimal_precision)
+                        )).size(14.0));
+                    } else if range.is_none() {
+                        ui.label(RichText::new("Enter a start date on or before the end date").size(14.0).color(Color32::from_rgb(150, 150, 150)));
+                    }
+
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        let can_preview = range.is_some() && !self.export_range_loading;
+                        if ui.add_enabled(can_preview, egui::Button::new("Preview")).clicked() {
+                            do_preview = true;
+                        }
+                        if ui.add_enabled(preview.is_some(), egui::Button::new("Export")).clicked() {
+                            do_export = true;
+                        }
+                    });
+                });
+
+            if do_preview {
+                self.load_export_range();
+            }
+            if do_export {
+                if let Some((start, end, entries)) = &self.export_range_preview {
+                    let annotations = self.config.export_include_annotations.then_some(&self.annotations.local_annotations);
+                    match export::export_range(entries, *start, *end, annotations, self.config.export_directory.as_deref()) {
+                        Ok(path) => {
+                            self.status_message = Some((format!("Exported to {}", path.display()), false));
+                        }
+                        Err(e) => {
+                            self.status_message = Some((format!("Export failed: {}", e), true));
+                        }
+                    }
+                }
+                self.show_export_range = false;
+            }
+            if !export_dialog_open {
+                self.show_export_range = false;
+            }
+        }
+
         // Render reschedule dialog
         if self.show_reschedule_dialog {
             let mut do_save = false;
@@ -2257,7 +5616,7 @@ impl eframe::App for JiraTimeApp {
                     let is_clone = self.reschedule_is_clone;
                     let new_date = self.reschedule_date;
                     let new_time = self.reschedule_time.clone();
-                    let duration_str = format_duration_with_format(new_seconds, self.config.time_format);
+                    let duration_str = format_duration_with_format(new_seconds, self.config.time_format, self.config.decimal_precision);
 
                     self.runtime.spawn(async move {
                         let result: Result<(String, TimeEntry, bool), anyhow::Error> = async {
@@ -2266,15 +5625,20 @@ impl eframe::App for JiraTimeApp {
                                 // Clone: create new worklog on new date
                                 let worklog = client.log_time(&entry.issue_key, new_seconds, new_date, &entry.description, Some(&new_time)).await?;
                                 let start_time = extract_time(&worklog.started);
+                                let custom_properties = worklog.custom_properties();
                                 let new_entry = TimeEntry {
                                     worklog_id: worklog.id,
                                     issue_key: entry.issue_key.clone(),
                                     issue_summary: entry.issue_summary.clone(),
                                     issue_type: entry.issue_type.clone(),
+                                    issue_status: entry.issue_status.clone(),
                                     seconds: new_seconds,
                                     description: entry.description.clone(),
                                     date: new_date,
                                     start_time,
+                                    time_spent_total: entry.time_spent_total,
+                                    time_original_estimate: entry.time_original_estimate,
+                                    custom_properties,
                                 };
                                 Ok((format!("Copied {} to {}", duration_str, new_date.format("%a")), new_entry, false))
                             } else {
@@ -2286,10 +5650,14 @@ impl eframe::App for JiraTimeApp {
                                     issue_key: entry.issue_key.clone(),
                                     issue_summary: entry.issue_summary.clone(),
                                     issue_type: entry.issue_type.clone(),
+                                    issue_status: entry.issue_status.clone(),
                                     seconds: new_seconds,
                                     description: entry.description.clone(),
                                     date: new_date,
                                     start_time,
+                                    time_spent_total: entry.time_spent_total,
+                                    time_original_estimate: entry.time_original_estimate,
+                                    custom_properties: entry.custom_properties.clone(),
                                 };
                                 Ok((format!("Moved to {}", new_time), updated_entry, true))
                             }
@@ -2300,9 +5668,7 @@ impl eframe::App for JiraTimeApp {
                                 let _ = tx.send(AsyncResult::WorklogSaved(msg, entry, is_edit));
                             }
                             Err(e) => {
-                                let err_str = e.to_string().to_lowercase();
-                                if err_str.contains("connection") || err_str.contains("network")
-                                   || err_str.contains("error sending request") || err_str.contains("timeout") {
+                                if is_offline_error(&e) {
                                     let _ = tx.send(AsyncResult::Offline);
                                 } else {
                                     let _ = tx.send(AsyncResult::Error(format!("Failed: {}", e)));
@@ -2360,6 +5726,12 @@ impl eframe::App for JiraTimeApp {
                 });
         }
 
+        // With the status bar shown, confirmations go there and only errors use the toast
+        let status_bar = self.state == AppState::Main && self.config.show_status_bar;
+        if status_bar {
+            self.render_status_bar(ctx);
+        }
+
         egui::CentralPanel::default().frame(
             egui::Frame::none().inner_margin(egui::Margin::symmetric(12.0, 0.0))
         ).show(ctx, |ui| {
@@ -2388,7 +5760,7 @@ impl eframe::App for JiraTimeApp {
             let mut dismiss_message = false;
             let mut copy_message: Option<String> = None;
             if !self.loading {
-                if let Some((msg, is_error)) = &self.status_message {
+                if let Some((msg, is_error)) = self.status_message.as_ref().filter(|(_, is_error)| *is_error || !status_bar) {
                     let bg_color = if *is_error {
                         Color32::from_rgb(224, 108, 117)  // Red background
                     } else {
@@ -2446,3 +5818,55 @@ impl eframe::App for JiraTimeApp {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(worklog_id: &str) -> TimeEntry {
+        TimeEntry {
+            worklog_id: worklog_id.to_string(),
+            issue_key: "PROJ-1".to_string(),
+            issue_summary: String::new(),
+            issue_type: "Task".to_string(),
+            issue_status: String::new(),
+            seconds: 3600,
+            description: String::new(),
+            date: NaiveDate::from_ymd_opt(2026, 10, 12).unwrap(),
+            start_time: "09:00".to_string(),
+            time_spent_total: 0,
+            time_original_estimate: 0,
+            custom_properties: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn tag_chip_font_shrinks_only_when_the_widest_tag_overflows() {
+        assert_eq!(tag_chip_font_size(100.0, 300.0), TAG_CHIP_FONT_SIZE);
+        assert_eq!(tag_chip_font_size(0.0, 0.0), TAG_CHIP_FONT_SIZE);
+        assert_eq!(tag_chip_font_size(130.0, 100.0), 10.0);
+        assert_eq!(tag_chip_font_size(1000.0, 100.0), TAG_CHIP_MIN_FONT_SIZE);
+    }
+
+    #[test]
+    fn undo_action_follows_the_kind_of_change() {
+        assert_eq!(UndoEntry::Added(entry("1")).action(), UndoAction::Delete);
+        assert_eq!(UndoEntry::Edited(entry("1")).action(), UndoAction::Update);
+        assert_eq!(UndoEntry::Deleted(entry("1")).action(), UndoAction::Relog);
+    }
+
+    #[test]
+    fn relogged_worklog_id_is_remapped_in_older_changes() {
+        let mut stack = UndoStack::default();
+        stack.push(UndoEntry::Edited(entry("1")));
+        stack.push(UndoEntry::Edited(entry("2")));
+        stack.push(UndoEntry::Added(entry("1")));
+        stack.remap_worklog_id("1", "9");
+
+        let ids: Vec<String> = stack.entries.iter().map(|undo| undo.clone().into_entry().worklog_id).collect();
+        assert_eq!(ids, ["9", "2", "9"]);
+        // Still an update of the (renumbered) worklog, never a second log
+        assert_eq!(stack.pop().map(|undo| undo.action()), Some(UndoAction::Delete));
+        assert_eq!(stack.pop().map(|undo| undo.action()), Some(UndoAction::Update));
+    }
+}
@@ -1,5 +1,8 @@
 use egui::{Color32, FontFamily, FontId, Rounding, Stroke, Style, TextStyle, Visuals};
 
+use crate::api::TimeEntry;
+use crate::config::{ColorblindMode, TagDefinition};
+
 /// Font family for filled Phosphor icons
 pub fn phosphor_fill_family() -> FontFamily {
     FontFamily::Name("phosphor-fill".into())
@@ -161,3 +164,157 @@ pub fn dialog_colors() -> (Color32, Color32, Color32) {
         Color32::from_rgb(176, 176, 168),    // frame text - warm gray
     )
 }
+
+/// Color for a category tag: configured tags cycle through a fixed palette,
+/// unknown tags get a stable color derived from their name
+pub fn tag_color(tag: &str, tags: &[TagDefinition]) -> Color32 {
+    const PALETTE: [Color32; 6] = [
+        Color32::from_rgb(0x13, 0x98, 0xf4),  // Blue
+        Color32::from_rgb(0x65, 0xba, 0x43),  // Green
+        Color32::from_rgb(0xe5, 0xaa, 0x00),  // Yellow
+        Color32::from_rgb(0xe8, 0x28, 0x71),  // Pink
+        Color32::from_rgb(0xa0, 0x6c, 0xd5),  // Purple
+        Color32::from_rgb(0xec, 0x71, 0x1b),  // Orange
+    ];
+    let index = tags.iter()
+        .position(|t| t.name.eq_ignore_ascii_case(tag))
+        .unwrap_or_else(|| tag.bytes().map(|b| b as usize).sum());
+    PALETTE[index % PALETTE.len()]
+}
+
+/// Project colors entry for an issue key's project (e.g. "TIM" for "TIM-42"), if configured
+fn project_color_entry<'a>(issue_key: &str, project_colors: &'a [(String, [u8; 3])]) -> Option<&'a [u8; 3]> {
+    let project = issue_key.split('-').next().unwrap_or(issue_key);
+    project_colors.iter()
+        .find(|(prefix, _)| prefix.eq_ignore_ascii_case(project))
+        .map(|(_, rgb)| rgb)
+}
+
+/// Weekly bucket category of a ticket (tickets in a `project_colors` project named after Meetings/Support/Admin)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BucketKind {
+    Meeting,
+    Support,
+    Admin,
+    Regular,
+}
+
+pub fn bucket_kind(issue_key: &str, issue_summary: &str, project_colors: &[(String, [u8; 3])]) -> BucketKind {
+    if project_color_entry(issue_key, project_colors).is_none() {
+        return BucketKind::Regular;
+    }
+    let summary_upper = issue_summary.to_uppercase();
+    if summary_upper.contains("MEETING") {
+        BucketKind::Meeting
+    } else if summary_upper.contains("SUPPORT") {
+        BucketKind::Support
+    } else if summary_upper.contains("ADMIN") {
+        BucketKind::Admin
+    } else {
+        BucketKind::Regular
+    }
+}
+
+/// Accent color for entry cards and schedule blocks
+pub fn bucket_accent_color(kind: BucketKind, mode: ColorblindMode) -> Color32 {
+    match mode {
+        ColorblindMode::None => match kind {
+            BucketKind::Meeting => Color32::from_rgb(0xe8, 0x28, 0x71),  // Pink/magenta
+            BucketKind::Support => Color32::from_rgb(0xec, 0x71, 0x1b),  // Orange
+            BucketKind::Admin => Color32::from_rgb(0xe5, 0xaa, 0x00),    // Yellow/gold
+            BucketKind::Regular => Color32::from_rgb(0x13, 0x98, 0xf4),  // Blue
+        },
+        // Okabe-Ito colors stay distinct for both red- and green-weak vision
+        ColorblindMode::Deuteranopia | ColorblindMode::Protanopia => match kind {
+            BucketKind::Meeting => Color32::from_rgb(0xcc, 0x79, 0xa7),  // Reddish purple
+            BucketKind::Support => Color32::from_rgb(0xe6, 0x9f, 0x00),  // Orange
+            BucketKind::Admin => Color32::from_rgb(0xf0, 0xe4, 0x42),    // Yellow
+            BucketKind::Regular => Color32::from_rgb(0x56, 0xb4, 0xe9),  // Sky blue
+        },
+    }
+}
+
+/// Accent color for an entry card or schedule block: the bucket color for bucket tickets,
+/// else the color configured for its project, else the default blue
+pub fn accent_color_for_entry(entry: &TimeEntry, project_colors: &[(String, [u8; 3])], mode: ColorblindMode) -> Color32 {
+    match bucket_kind(&entry.issue_key, &entry.issue_summary, project_colors) {
+        BucketKind::Regular => project_color_entry(&entry.issue_key, project_colors)
+            .map(|&[r, g, b]| Color32::from_rgb(r, g, b))
+            .unwrap_or_else(|| bucket_accent_color(BucketKind::Regular, mode)),
+        kind => bucket_accent_color(kind, mode),
+    }
+}
+
+/// Accent color for weekly bucket tickets in the log dialog (quick-add buttons, issue label)
+pub fn dialog_bucket_color(kind: BucketKind, mode: ColorblindMode) -> Color32 {
+    match (kind, mode) {
+        (BucketKind::Meeting, ColorblindMode::None) => Color32::from_rgb(0xdc, 0x26, 0x7f),  // Pink
+        (BucketKind::Support, ColorblindMode::None) => Color32::from_rgb(0xfe, 0x61, 0x00),  // Orange
+        (BucketKind::Admin, ColorblindMode::None) => Color32::from_rgb(0xff, 0xb0, 0x00),    // Yellow
+        _ => bucket_accent_color(kind, mode),
+    }
+}
+
+/// Stripe overlay for accent stripes so bucket types differ by pattern, not only color
+/// Meeting = "/", Support = "\", Admin = horizontal, Regular = plain
+pub fn paint_bucket_pattern(painter: &egui::Painter, rect: egui::Rect, kind: BucketKind, mode: ColorblindMode) {
+    if mode == ColorblindMode::None || kind == BucketKind::Regular {
+        return;
+    }
+
+    let painter = painter.with_clip_rect(rect.intersect(painter.clip_rect()));
+    let stroke = Stroke::new(1.5, Color32::from_black_alpha(160));
+    let spacing = 5.0;
+    let span = rect.width() + rect.height();
+    let mut offset = 0.0;
+    while offset < span {
+        let (start, end) = match kind {
+            BucketKind::Meeting => (
+                egui::pos2(rect.min.x + offset - rect.height(), rect.max.y),
+                egui::pos2(rect.min.x + offset, rect.min.y),
+            ),
+            BucketKind::Support => (
+                egui::pos2(rect.min.x + offset - rect.height(), rect.min.y),
+                egui::pos2(rect.min.x + offset, rect.max.y),
+            ),
+            _ => (
+                egui::pos2(rect.min.x, rect.min.y + offset),
+                egui::pos2(rect.max.x, rect.min.y + offset),
+            ),
+        };
+        painter.line_segment([start, end], stroke);
+        offset += spacing;
+    }
+}
+
+/// Look up a phosphor icon by name for user-defined toolbar buttons
+/// Accepts "globe", "GLOBE" or "terminal-window"; a single character is used as-is
+pub fn quick_action_icon(name: &str) -> String {
+    use egui_phosphor::regular as icons;
+    let key = name.trim().to_uppercase().replace(['-', ' '], "_");
+    let icon = match key.as_str() {
+        "GLOBE" => icons::GLOBE,
+        "LINK" => icons::LINK,
+        "TERMINAL" => icons::TERMINAL,
+        "TERMINAL_WINDOW" => icons::TERMINAL_WINDOW,
+        "CLIPBOARD" | "CLIPBOARD_TEXT" => icons::CLIPBOARD_TEXT,
+        "COPY" => icons::COPY,
+        "ROCKET" => icons::ROCKET,
+        "STAR" => icons::STAR,
+        "LIGHTNING" => icons::LIGHTNING,
+        "CALENDAR" => icons::CALENDAR,
+        "BUG" => icons::BUG,
+        "CODE" => icons::CODE,
+        "CHAT" | "CHAT_CIRCLE" => icons::CHAT_CIRCLE,
+        "FOLDER" => icons::FOLDER,
+        "FILE" | "FILE_TEXT" => icons::FILE_TEXT,
+        "GIT_BRANCH" => icons::GIT_BRANCH,
+        "CLOCK" => icons::CLOCK,
+        "HOUSE" => icons::HOUSE,
+        "ENVELOPE" => icons::ENVELOPE,
+        "PLAY" => icons::PLAY,
+        _ if name.trim().chars().count() == 1 => return name.trim().to_string(),
+        _ => icons::LIGHTNING,
+    };
+    icon.to_string()
+}
@@ -1,15 +1,30 @@
-use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Timelike, Weekday};
+use directories::ProjectDirs;
 use egui::{Color32, RichText, Ui};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+use timebox_core::week_start;
 
-use crate::api::{TimeEntry, format_duration_with_format};
-use crate::config::{TimeFormat, ClockFormat, ListViewMode};
-use super::theme::{day_tab_colors, day_tab_text_colors, entry_colors};
+use crate::api::{TimeEntry, format_duration, format_duration_with_format, markdown_to_adf, parse_duration};
+use crate::config::{issue_type_color, parse_categories_from_description, TimeFormat, ClockFormat, ColorblindMode, LinkBehavior, ListViewMode, TagDefinition, WeekendDisplay};
+use super::theme::{accent_color_for_entry, bucket_kind, day_tab_colors, day_tab_text_colors, entry_colors, paint_bucket_pattern};
+
+/// How long a newly added entry takes to fade in (seconds)
+pub const NEW_ENTRY_FADE_SECONDS: f32 = 0.5;
+
+/// How long the pointer rests on a schedule block before its details tooltip appears
+const SCHEDULE_TOOLTIP_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
 
 /// Result from schedule view interactions
 #[derive(Default)]
 pub struct ScheduleResult {
     pub edit_entry: Option<TimeEntry>,
     pub delete_entry: Option<TimeEntry>,
+    pub duplicate_entry: Option<TimeEntry>,  // "Duplicate log" from the block's context menu
     pub add_at: Option<(NaiveDate, String)>,  // (date, start_time "HH:MM")
     // Drag move completed - entry moved to new time (optimistic update)
     pub drag_move: Option<(TimeEntry, String)>,  // (entry, new_start_time "HH:MM")
@@ -29,12 +44,15 @@ enum IssueTypeIcon {
 }
 
 /// Get the icon style for an issue type
-fn issue_type_icon(issue_type: &str) -> IssueTypeIcon {
+/// The square color comes from `issue_type_colors` (matched case-insensitively), else the built-in default
+fn issue_type_icon(issue_type: &str, issue_type_colors: &HashMap<String, [u8; 3]>) -> IssueTypeIcon {
+    let [r, g, b] = issue_type_color(issue_type, issue_type_colors);
+    let color = Color32::from_rgb(r, g, b);
     match issue_type.to_lowercase().as_str() {
-        "bug" => IssueTypeIcon::OnSquareBlack(egui_phosphor::fill::BUG, Color32::from_rgb(0xe5, 0x4d, 0x42)),  // Black bug on red square
-        "story" => IssueTypeIcon::OnSquare(egui_phosphor::fill::BOOKMARK_SIMPLE, Color32::from_rgb(0x65, 0xba, 0x43)),  // White bookmark on green square
-        "epic" => IssueTypeIcon::OnSquare(egui_phosphor::fill::LIGHTNING, Color32::from_rgb(0x90, 0x4e, 0xe2)),  // White lightning on purple square
-        _ => IssueTypeIcon::OnSquare(egui_phosphor::fill::CHECK_FAT, Color32::from_rgb(0x42, 0x9c, 0xd6)),  // White check on blue square (Task)
+        "bug" => IssueTypeIcon::OnSquareBlack(egui_phosphor::fill::BUG, color),  // Black bug on red square
+        "story" => IssueTypeIcon::OnSquare(egui_phosphor::fill::BOOKMARK_SIMPLE, color),  // White bookmark on green square
+        "epic" => IssueTypeIcon::OnSquare(egui_phosphor::fill::LIGHTNING, color),  // White lightning on purple square
+        _ => IssueTypeIcon::OnSquare(egui_phosphor::fill::CHECK_FAT, color),  // White check on blue square (Task)
     }
 }
 
@@ -64,6 +82,74 @@ fn render_issue_type_icon(ui: &mut Ui, icon_style: IssueTypeIcon, size: f32) {
     );
 }
 
+/// Clickable chip with an issue's type icon and key, styled like the dialog's quick-add buttons
+pub fn render_issue_chip(ui: &mut Ui, issue_key: &str, issue_type: &str, issue_type_colors: &HashMap<String, [u8; 3]>) -> egui::Response {
+    let font_id = egui::FontId::proportional(13.0);
+    let icon_size = 12.0;
+    let padding = egui::vec2(10.0, 5.0);
+    let text_size = ui.fonts(|f| f.layout_no_wrap(issue_key.to_string(), font_id.clone(), Color32::WHITE).size());
+    let chip_size = egui::vec2(icon_size + 6.0 + text_size.x, text_size.y.max(icon_size)) + padding * 2.0;
+    let (rect, response) = ui.allocate_exact_size(chip_size, egui::Sense::click());
+
+    let bg = if response.hovered() { Color32::from_rgb(0x45, 0x45, 0x50) } else { Color32::from_rgb(0x2a, 0x2a, 0x32) };
+    let painter = ui.painter();
+    painter.rect_filled(rect, 6.0, bg);
+
+    let (icon, square_color, icon_color) = match issue_type_icon(issue_type, issue_type_colors) {
+        IssueTypeIcon::OnSquare(icon, color) => (icon, color, Color32::WHITE),
+        IssueTypeIcon::OnSquareBlack(icon, color) => (icon, color, Color32::BLACK),
+    };
+    let icon_rect = egui::Rect::from_center_size(
+        egui::pos2(rect.min.x + padding.x + icon_size / 2.0, rect.center().y),
+        egui::vec2(icon_size, icon_size),
+    );
+    painter.rect_filled(icon_rect, 3.0, square_color);
+    painter.text(icon_rect.center(), egui::Align2::CENTER_CENTER, icon, egui::FontId::new(icon_size * 0.75, super::theme::phosphor_fill_family()), icon_color);
+    painter.text(
+        egui::pos2(icon_rect.max.x + 6.0, rect.center().y),
+        egui::Align2::LEFT_CENTER,
+        issue_key,
+        font_id,
+        Color32::from_rgb(200, 200, 210),
+    );
+    response
+}
+
+/// Pill color for statuses worth flagging on an entry: gray once done, blue while in progress
+fn status_pill_color(status: &str) -> Option<Color32> {
+    if status.eq_ignore_ascii_case("Done") {
+        Some(Color32::from_rgb(0x5a, 0x5a, 0x64))
+    } else if status.eq_ignore_ascii_case("In Progress") {
+        Some(Color32::from_rgb(0x2f, 0x6f, 0xc4))
+    } else {
+        None
+    }
+}
+
+/// Paint a status pill with its left edge centered vertically on `left_center`, returns its width
+fn paint_status_pill(painter: &egui::Painter, left_center: egui::Pos2, status: &str, color: Color32, font_size: f32) -> f32 {
+    let galley = painter.layout_no_wrap(status.to_uppercase(), egui::FontId::proportional(font_size), Color32::WHITE);
+    let pill_rect = egui::Rect::from_min_size(
+        egui::pos2(left_center.x, left_center.y - galley.size().y / 2.0 - 1.0),
+        galley.size() + egui::vec2(10.0, 2.0),
+    );
+    painter.rect_filled(pill_rect, pill_rect.height() / 2.0, color);
+    painter.galley(pill_rect.min + egui::vec2(5.0, 1.0), galley, Color32::WHITE);
+    pill_rect.width()
+}
+
+/// Status pill after an entry's issue key in the list (nothing for other statuses)
+fn render_status_pill(ui: &mut Ui, status: &str) {
+    let Some(color) = status_pill_color(status) else {
+        return;
+    };
+    let font_size = 10.0;
+    let text_width = ui.fonts(|f| f.layout_no_wrap(status.to_uppercase(), egui::FontId::proportional(font_size), Color32::WHITE).size().x);
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(text_width + 10.0, 16.0), egui::Sense::hover());
+    paint_status_pill(ui.painter(), rect.left_center(), status, color, font_size);
+    response.on_hover_text(format!("Issue status: {}", status));
+}
+
 /// Format a time string "HH:MM" according to clock format
 fn format_clock_time(time_24: &str, clock_format: ClockFormat) -> String {
     match clock_format {
@@ -91,12 +177,23 @@ fn format_clock_time(time_24: &str, clock_format: ClockFormat) -> String {
 }
 
 /// Represents a cached week of data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeekData {
     pub week_start: NaiveDate,
     pub entries: Vec<TimeEntry>,
 }
 
+/// A week as last fetched from Jira, kept in memory and on disk for showing while offline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedWeek {
+    pub fetched_at: DateTime<Local>,
+    #[serde(flatten)]
+    pub week: WeekData,
+}
+
+/// Weeks kept on disk per Jira site; the least recently fetched are removed first
+const CACHED_WEEKS_KEPT: usize = 26;
+
 impl WeekData {
     pub fn new(week_start: NaiveDate) -> Self {
         Self {
@@ -122,46 +219,395 @@ impl WeekData {
     pub fn all_days(&self) -> Vec<NaiveDate> {
         (0..7).map(|i| self.week_start + Duration::days(i)).collect()
     }
+
+    /// The copy of `week_start` last saved by `save_cached` for this Jira site
+    pub fn load_cached(jira_domain: &str, week_start: NaiveDate) -> Option<CachedWeek> {
+        let contents = fs::read_to_string(Self::cache_path(jira_domain, week_start).ok()?).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Save this week (fetched from Jira at `fetched_at`) to the cache directory, replacing any older copy
+    /// and pruning the site's folder to the `CACHED_WEEKS_KEPT` most recently fetched weeks
+    pub fn save_cached(&self, jira_domain: &str, fetched_at: DateTime<Local>) -> Result<()> {
+        let path = Self::cache_path(jira_domain, self.week_start)?;
+        let Some(dir) = path.parent() else {
+            anyhow::bail!("Invalid cache path");
+        };
+        fs::create_dir_all(dir)?;
+        let cached = CachedWeek { fetched_at, week: self.clone() };
+        fs::write(&path, serde_json::to_string(&cached)?)?;
+
+        let mut files: Vec<(std::time::SystemTime, PathBuf)> = fs::read_dir(dir)?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+            .collect();
+        files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+        for (_, old) in files.into_iter().skip(CACHED_WEEKS_KEPT) {
+            let _ = fs::remove_file(old);
+        }
+        Ok(())
+    }
+
+    /// weeks/<site>/<week start>.json in the cache directory, so profiles never show each other's weeks
+    fn cache_path(jira_domain: &str, week_start: NaiveDate) -> Result<PathBuf> {
+        let proj_dirs = ProjectDirs::from("com", "tzankich", "timebox")
+            .context("Could not determine cache directory")?;
+        let site = crate::config::site_file_name(jira_domain);
+        Ok(proj_dirs.cache_dir().join("weeks").join(site).join(format!("{}.json", week_start.format("%Y-%m-%d"))))
+    }
+
+    /// Append another fetch result's entries, skipping worklogs already present
+    pub fn merge(&mut self, other: WeekData) {
+        for entry in other.entries {
+            if !self.entries.iter().any(|e| e.worklog_id == entry.worklog_id) {
+                self.entries.push(entry);
+            }
+        }
+    }
+
+    /// Get total seconds logged in the week
+    pub fn total_seconds(&self) -> i64 {
+        self.entries.iter().map(|e| e.seconds).sum()
+    }
+
+    /// Get total seconds logged between two dates (inclusive)
+    pub fn total_seconds_for_range(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        self.entries.iter()
+            .filter(|e| e.date >= start && e.date <= end)
+            .map(|e| e.seconds)
+            .sum()
+    }
+
+    /// Entries whose "[TAG]" prefix (read like the entry dialog does) includes `tag`
+    pub fn filter_by_tag(&self, tag: &str, tags: &[TagDefinition]) -> Vec<&TimeEntry> {
+        let Some(index) = tags.iter().position(|t| t.name.eq_ignore_ascii_case(tag)) else {
+            return Vec::new();
+        };
+        self.entries.iter()
+            .filter(|e| parse_categories_from_description(&e.description, tags).0[index])
+            .collect()
+    }
+
+    /// Total seconds per tag for the week, most-used first (see `tag_totals_of`)
+    pub fn tag_totals(&self, tags: &[TagDefinition]) -> Vec<(String, i64)> {
+        tag_totals_of(&self.entries, tags)
+    }
+
+    /// Seconds logged on entries that `tag_totals` doesn't count anywhere (no known tag prefix)
+    pub fn untagged_seconds(&self, tags: &[TagDefinition]) -> i64 {
+        self.entries.iter()
+            .filter(|e| !parse_categories_from_description(&e.description, tags).0.contains(&true))
+            .map(|e| e.seconds)
+            .sum()
+    }
+}
+
+/// Week statistics above the entry list, shown once every workday (Mon-Fri) of the week has an entry:
+/// total, average per worked day, busiest issue and time per tag
+/// Returns true when the chevron was clicked (the caller flips and saves `collapsed`)
+pub fn render_week_summary(ui: &mut Ui, week_data: &WeekData, tags: &[TagDefinition], time_format: TimeFormat, decimal_precision: u8, collapsed: bool) -> bool {
+    let days = week_data.all_days();
+    let all_workdays_logged = days.iter()
+        .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+        .all(|d| week_data.seconds_for_day(*d) > 0);
+    if !all_workdays_logged {
+        return false;
+    }
+
+    let secondary_color = Color32::from_rgb(150, 150, 150);
+    let format = |seconds: i64| format_duration_with_format(seconds, time_format, decimal_precision);
+    let mut toggled = false;
+
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing.x = 6.0;
+        let chevron = if collapsed { egui_phosphor::regular::CARET_RIGHT } else { egui_phosphor::regular::CARET_DOWN };
+        let header = ui.add(egui::Label::new(
+            RichText::new(format!("{} Week summary", chevron)).size(13.0).color(secondary_color)
+        ).sense(egui::Sense::click()));
+        if header.hovered() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+        }
+        toggled = header.clicked();
+        if collapsed {
+            return;
+        }
+
+        let total = week_data.total_seconds();
+        let worked_days = days.iter().filter(|d| week_data.seconds_for_day(**d) > 0).count().max(1) as i64;
+        let mut per_issue: Vec<(&str, i64)> = Vec::new();
+        for entry in &week_data.entries {
+            match per_issue.iter_mut().find(|(key, _)| *key == entry.issue_key) {
+                Some((_, secs)) => *secs += entry.seconds,
+                None => per_issue.push((&entry.issue_key, entry.seconds)),
+            }
+        }
+
+        let stat = |ui: &mut Ui, label: &str, value: String| {
+            ui.label(RichText::new(label).size(13.0).color(secondary_color));
+            ui.label(RichText::new(value).size(13.0).color(Color32::WHITE));
+        };
+        stat(ui, "Total", format(total));
+        stat(ui, "· Average", format!("{}/day", format(total / worked_days)));
+        if let Some((key, secs)) = per_issue.iter().max_by_key(|(_, secs)| *secs) {
+            stat(ui, "· Busiest", format!("{} ({})", key, format(*secs)));
+        }
+        let tag_totals = week_data.tag_totals(tags);
+        if !tag_totals.is_empty() {
+            let tally = tag_totals.iter()
+                .map(|(tag, secs)| format!("[{}] {}", tag, format(*secs)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            stat(ui, "· Tags", tally);
+        }
+    });
+    ui.add_space(8.0);
+
+    toggled
+}
+
+/// Result from entry list interactions
+#[derive(Default)]
+pub struct EntryListResult {
+    pub edit_index: Option<usize>,
+    pub delete_index: Option<usize>,
+    pub add_clicked: bool,
+    // Inline duration edit confirmed with Enter
+    pub duration_edit: Option<(usize, i64)>,  // (entry index, new seconds)
+    // Issue key clicked while links open in the app, or "View issue history" chosen from the entry menu
+    pub details_index: Option<usize>,
+    // "Private note" chosen from the entry menu
+    pub note_index: Option<usize>,
+    // "Duplicate log" chosen from the entry menu
+    pub duplicate_index: Option<usize>,
+    // "Add to calendar" chosen from the entry menu
+    pub calendar_index: Option<usize>,
 }
 
-/// Returns (edit_index, delete_index, add_clicked) if Edit/Delete/Add was clicked
+/// What was clicked on a single entry card
+#[derive(Default)]
+struct RowActions {
+    edit: bool,
+    delete: bool,
+    details: bool,
+    note: bool,
+    duplicate: bool,
+    calendar: bool,
+    new_seconds: Option<i64>,  // Confirmed inline duration edit (contracted cards only)
+}
+
+/// `inline_edit` holds (worklog_id, edited_text) while a contracted card's duration is being edited
+/// `new_entries` maps just-added worklog ids to when they were added, for the fade-in
+/// `annotations` maps worklog ids to private notes, shown on expanded cards
+/// `expanded_groups` shows the entries under one collapsible header per issue (issue key -> open, default open)
+#[allow(clippy::too_many_arguments)]
 pub fn render_entry_list(
     ui: &mut Ui,
     entries: &[TimeEntry],
     jira_base_url: &str,
     time_format: TimeFormat,
+    decimal_precision: u8,
     clock_format: ClockFormat,
     show_start_time: bool,
     list_view_mode: ListViewMode,
-) -> (Option<usize>, Option<usize>, bool) {
-    let mut edit_index = None;
-    let mut delete_index = None;
-    let mut add_clicked = false;
+    colorblind_mode: ColorblindMode,
+    project_colors: &[(String, [u8; 3])],
+    inline_edit: &mut Option<(String, String)>,
+    new_entries: &HashMap<String, Instant>,
+    issue_type_colors: &HashMap<String, [u8; 3]>,
+    link_behavior: LinkBehavior,
+    annotations: &HashMap<String, String>,
+    expanded_groups: Option<&mut HashMap<String, bool>>,
+) -> EntryListResult {
+    let mut result = EntryListResult::default();
 
     egui::ScrollArea::vertical().show(ui, |ui| {
         // No extra spacing - cards handle their own gaps
         ui.spacing_mut().item_spacing.y = 0.0;
 
-        for (idx, entry) in entries.iter().enumerate() {
-            let (edit, delete) = match list_view_mode {
-                ListViewMode::Contracted => render_entry_row_contracted(ui, entry, jira_base_url, time_format, clock_format, show_start_time),
-                ListViewMode::Expanded => render_entry_row_expanded(ui, entry, jira_base_url, time_format, clock_format, show_start_time),
-            };
-            if edit {
-                edit_index = Some(idx);
+        let mut render_row = |ui: &mut Ui, idx: usize, entry: &TimeEntry| {
+            // Fade in entries that were just added
+            let alpha = new_entries.get(&entry.worklog_id)
+                .map(|added| (added.elapsed().as_secs_f32() / NEW_ENTRY_FADE_SECONDS).min(1.0))
+                .unwrap_or(1.0);
+            let actions = ui.scope(|ui| {
+                ui.set_opacity(alpha);
+                match list_view_mode {
+                    ListViewMode::Contracted => render_entry_row_contracted(ui, entry, jira_base_url, time_format, decimal_precision, clock_format, show_start_time, colorblind_mode, project_colors, issue_type_colors, link_behavior, inline_edit),
+                    ListViewMode::Expanded => {
+                        let annotation = annotations.get(&entry.worklog_id).map(String::as_str);
+                        render_entry_row_expanded(ui, entry, jira_base_url, time_format, decimal_precision, clock_format, show_start_time, colorblind_mode, project_colors, issue_type_colors, link_behavior, annotation)
+                    }
+                }
+            }).inner;
+            if actions.edit {
+                result.edit_index = Some(idx);
+            }
+            if actions.delete {
+                result.delete_index = Some(idx);
+            }
+            if actions.details {
+                result.details_index = Some(idx);
+            }
+            if actions.note {
+                result.note_index = Some(idx);
+            }
+            if actions.duplicate {
+                result.duplicate_index = Some(idx);
+            }
+            if actions.calendar {
+                result.calendar_index = Some(idx);
+            }
+            if let Some(seconds) = actions.new_seconds {
+                result.duration_edit = Some((idx, seconds));
+            }
+        };
+
+        match expanded_groups {
+            Some(expanded) => {
+                // One group per issue, in the order the issues first appear
+                let mut groups: Vec<Vec<usize>> = Vec::new();
+                for (idx, entry) in entries.iter().enumerate() {
+                    match groups.iter_mut().find(|group| entries[group[0]].issue_key == entry.issue_key) {
+                        Some(group) => group.push(idx),
+                        None => groups.push(vec![idx]),
+                    }
+                }
+                for group in groups {
+                    let first = &entries[group[0]];
+                    let total: i64 = group.iter().map(|&idx| entries[idx].seconds).sum();
+                    let open = expanded.get(&first.issue_key).copied().unwrap_or(true);
+                    if render_issue_group_header(ui, first, total, group.len(), open, time_format, decimal_precision, colorblind_mode, project_colors, issue_type_colors) {
+                        expanded.insert(first.issue_key.clone(), !open);
+                    }
+                    if open {
+                        ui.horizontal_top(|ui| {
+                            ui.add_space(20.0);
+                            ui.vertical(|ui| {
+                                for &idx in &group {
+                                    render_row(ui, idx, &entries[idx]);
+                                }
+                            });
+                        });
+                    }
+                }
             }
-            if delete {
-                delete_index = Some(idx);
+            None => {
+                for (idx, entry) in entries.iter().enumerate() {
+                    render_row(ui, idx, entry);
+                }
             }
         }
 
         // Add button at the end of the list
         if render_add_button(ui, entries.is_empty()) {
-            add_clicked = true;
+            result.add_clicked = true;
         }
     });
 
-    (edit_index, delete_index, add_clicked)
+    result
+}
+
+/// Stacked bar of the day's time per issue, in the entries' accent colors; hover a segment for its total
+pub fn render_day_distribution(ui: &mut Ui, entries: &[TimeEntry], time_format: TimeFormat, decimal_precision: u8, colorblind_mode: ColorblindMode, project_colors: &[(String, [u8; 3])]) {
+    // One segment per issue, in the order the issues first appear
+    let mut segments: Vec<(&TimeEntry, i64)> = Vec::new();
+    for entry in entries {
+        match segments.iter_mut().find(|(first, _)| first.issue_key == entry.issue_key) {
+            Some((_, seconds)) => *seconds += entry.seconds,
+            None => segments.push((entry, entry.seconds)),
+        }
+    }
+    let total: i64 = segments.iter().map(|(_, seconds)| seconds).sum();
+    if total <= 0 {
+        return;
+    }
+
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 8.0), egui::Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(rect, 4.0, Color32::from_rgb(40, 40, 38));
+
+    let hover_x = response.hover_pos().map(|pos| pos.x);
+    let mut hovered = None;
+    let mut x = rect.min.x;
+    for (entry, seconds) in &segments {
+        let width = rect.width() * (*seconds as f32 / total as f32);
+        let segment = egui::Rect::from_min_size(egui::pos2(x, rect.min.y), egui::vec2(width, rect.height()));
+        // 1px gap so neighbouring issues with the same color stay distinguishable
+        let drawn = segment.shrink2(egui::vec2(0.5, 0.0));
+        painter.rect_filled(drawn, 4.0, accent_color_for_entry(entry, project_colors, colorblind_mode));
+        if hover_x.is_some_and(|hx| hx >= segment.min.x && hx < segment.max.x) {
+            hovered = Some((&entry.issue_key, *seconds));
+        }
+        x += width;
+    }
+
+    if let Some((issue_key, seconds)) = hovered {
+        response.on_hover_ui_at_pointer(|ui| {
+            ui.label(RichText::new(issue_key).size(14.0).color(Color32::WHITE).family(super::theme::bold_family()));
+            ui.label(format!(
+                "{} ({:.0}% of the day)",
+                format_duration_with_format(seconds, time_format, decimal_precision),
+                seconds as f32 / total as f32 * 100.0
+            ));
+        });
+    }
+}
+
+/// Header row for one issue in the grouped list: caret, type icon, key, summary and the day's total
+/// Returns true when clicked (to expand or collapse the group)
+#[allow(clippy::too_many_arguments)]
+fn render_issue_group_header(ui: &mut Ui, entry: &TimeEntry, total_seconds: i64, count: usize, open: bool, time_format: TimeFormat, decimal_precision: u8, colorblind_mode: ColorblindMode, project_colors: &[(String, [u8; 3])], issue_type_colors: &HashMap<String, [u8; 3]>) -> bool {
+    let (_bg_color, text_color, secondary_color) = entry_colors();
+    let card_gap = 6.0;
+    let height = 32.0;
+    let padding = 10.0;
+    let icon_size = 14.0;
+
+    let (full_rect, response) = ui.allocate_exact_size(egui::vec2(ui.available_width(), height + card_gap), egui::Sense::click());
+    let rect = egui::Rect::from_min_size(full_rect.min, egui::vec2(full_rect.width(), height));
+    if response.hovered() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+    }
+
+    let painter = ui.painter();
+    let bg = if response.hovered() { Color32::from_rgb(0x28, 0x28, 0x26) } else { Color32::from_rgb(0x1c, 0x1c, 0x1a) };
+    painter.rect_filled(rect, 6.0, bg);
+
+    let caret = if open { egui_phosphor::regular::CARET_DOWN } else { egui_phosphor::regular::CARET_RIGHT };
+    let mut x = rect.min.x + padding;
+    painter.text(egui::pos2(x, rect.center().y), egui::Align2::LEFT_CENTER, caret, egui::FontId::proportional(14.0), secondary_color);
+    x += 20.0;
+
+    let (icon, square_color, icon_color) = match issue_type_icon(&entry.issue_type, issue_type_colors) {
+        IssueTypeIcon::OnSquare(icon, color) => (icon, color, Color32::WHITE),
+        IssueTypeIcon::OnSquareBlack(icon, color) => (icon, color, Color32::BLACK),
+    };
+    let icon_rect = egui::Rect::from_min_size(egui::pos2(x, rect.center().y - icon_size / 2.0), egui::vec2(icon_size, icon_size));
+    painter.rect_filled(icon_rect, 3.0, square_color);
+    painter.text(icon_rect.center(), egui::Align2::CENTER_CENTER, icon, egui::FontId::new(icon_size * 0.75, super::theme::phosphor_fill_family()), icon_color);
+    x = icon_rect.max.x + 8.0;
+
+    let key_font = egui::FontId::new(14.0, super::theme::bold_family());
+    let key_rect = painter.text(egui::pos2(x, rect.center().y), egui::Align2::LEFT_CENTER, &entry.issue_key, key_font, accent_color_for_entry(entry, project_colors, colorblind_mode));
+    x = key_rect.max.x + 10.0;
+
+    let total = format_duration_with_format(total_seconds, time_format, decimal_precision);
+    let logs = if count == 1 { "1 log".to_string() } else { format!("{} logs", count) };
+    let right_font = egui::FontId::proportional(14.0);
+    let total_rect = painter.text(egui::pos2(rect.max.x - padding, rect.center().y), egui::Align2::RIGHT_CENTER, &total, right_font.clone(), text_color);
+    let logs_rect = painter.text(egui::pos2(total_rect.min.x - 10.0, rect.center().y), egui::Align2::RIGHT_CENTER, &logs, egui::FontId::proportional(12.0), secondary_color);
+
+    // Summary fills the space left between the key and the totals, cut short with an ellipsis
+    let summary_width = logs_rect.min.x - 10.0 - x;
+    if summary_width > 20.0 {
+        let mut job = egui::text::LayoutJob::simple_singleline(entry.issue_summary.clone(), egui::FontId::proportional(14.0), secondary_color);
+        job.wrap = egui::text::TextWrapping::truncate_at_width(summary_width);
+        let galley = ui.fonts(|f| f.layout_job(job));
+        painter.galley(egui::pos2(x, rect.center().y - galley.size().y / 2.0), galley, secondary_color);
+    }
+
+    response.clicked()
 }
 
 /// Render the [+] add button at the end of the list
@@ -211,32 +657,51 @@ fn render_add_button(ui: &mut Ui, is_empty: bool) -> bool {
     response.clicked()
 }
 
-/// Returns (edit_clicked, delete_clicked) - Contracted view with single line
-fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str, time_format: TimeFormat, _clock_format: ClockFormat, _show_start_time: bool) -> (bool, bool) {
+/// Whether an issue key click should show the in-app details (Ctrl/Cmd+click always opens the browser)
+fn opens_in_app(link_behavior: LinkBehavior, ui: &Ui) -> bool {
+    link_behavior == LinkBehavior::OpenInApp && !ui.input(|i| i.modifiers.command)
+}
+
+/// "Open in Jira" and "Copy issue key" at the top of an entry card's menu
+fn entry_menu_links(ui: &mut Ui, entry: &TimeEntry, issue_url: &str) {
+    // Always the browser, whatever the issue key link does
+    if ui.add(egui::Button::new(
+        RichText::new(format!("{}  Open in Jira", egui_phosphor::regular::ARROW_SQUARE_OUT))
+            .size(14.0)
+    ).frame(false)).clicked() {
+        let _ = open::that(issue_url);
+    }
+
+    if ui.add(egui::Button::new(
+        RichText::new(format!("{}  Copy issue key", egui_phosphor::regular::CLIPBOARD_TEXT))
+            .size(14.0)
+    ).frame(false)).clicked() {
+        ui.ctx().copy_text(entry.issue_key.clone());
+    }
+}
+
+/// Contracted view with single line
+/// new_seconds is set when an inline duration edit is confirmed
+#[allow(clippy::too_many_arguments)]
+fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str, time_format: TimeFormat, decimal_precision: u8, clock_format: ClockFormat, show_start_time: bool, colorblind_mode: ColorblindMode, project_colors: &[(String, [u8; 3])], issue_type_colors: &HashMap<String, [u8; 3]>, link_behavior: LinkBehavior, inline_edit: &mut Option<(String, String)>) -> RowActions {
     let mut edit_clicked = false;
     let mut delete_clicked = false;
+    let mut details_clicked = false;
+    let mut note_clicked = false;
+    let mut duplicate_clicked = false;
+    let mut calendar_clicked = false;
+    let mut new_seconds = None;
     let (_bg_color, text_color, secondary_color) = entry_colors();
 
     // Accent color based on ticket type
-    let accent_color = if entry.issue_key.starts_with("TIM-") {
-        let summary_upper = entry.issue_summary.to_uppercase();
-        if summary_upper.contains("MEETING") {
-            Color32::from_rgb(0xe8, 0x28, 0x71)  // Pink/magenta
-        } else if summary_upper.contains("SUPPORT") {
-            Color32::from_rgb(0xec, 0x71, 0x1b)  // Orange
-        } else if summary_upper.contains("ADMIN") {
-            Color32::from_rgb(0xe5, 0xaa, 0x00)  // Yellow/gold
-        } else {
-            Color32::from_rgb(0x13, 0x98, 0xf4)  // Blue
-        }
-    } else {
-        Color32::from_rgb(0x13, 0x98, 0xf4)  // Blue for regular tickets
-    };
+    let bucket = bucket_kind(&entry.issue_key, &entry.issue_summary, project_colors);
+    let accent_color = accent_color_for_entry(entry, project_colors, colorblind_mode);
 
     // Card styling
     let card_bg = Color32::from_rgb(0x1c, 0x1c, 0x1a);
     let card_border = Color32::from_rgb(0x28, 0x28, 0x26);
-    let accent_width = 4.0;
+    // Wider stripe when a colorblind mode draws a pattern on it
+    let accent_width = if colorblind_mode == ColorblindMode::None { 4.0 } else { 8.0 };
     let corner_radius = 6.0;
     let card_padding = 10.0;  // More padding left/right
     let card_gap = 6.0;
@@ -308,6 +773,12 @@ fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &s
         egui::vec2(corner_radius, card_rect.height())
     );
     painter.rect_filled(cover_rect, 0.0, bg_color);
+    paint_bucket_pattern(
+        painter,
+        egui::Rect::from_min_size(card_rect.min, egui::vec2(accent_width, card_rect.height())),
+        bucket,
+        colorblind_mode,
+    );
 
     // Content area (after accent stripe)
     let content_left = card_rect.min.x + accent_width + card_padding;
@@ -319,8 +790,8 @@ fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &s
 
     // Single line: Icon + Issue key + Duration pill + Description + Menu
     let issue_url = format!("{}/browse/{}", jira_base_url, entry.issue_key);
-    let icon_style = issue_type_icon(&entry.issue_type);
-    let duration_text = format_duration_with_format(entry.seconds, time_format);
+    let icon_style = issue_type_icon(&entry.issue_type, issue_type_colors);
+    let duration_text = card_duration_text(entry.seconds, time_format, decimal_precision);
 
     // Issue key color - bright gray since we have colored icons now
     let issue_key_color = Color32::from_rgb(200, 200, 192);
@@ -343,16 +814,78 @@ fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &s
             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
         }
         if link_response.clicked() {
-            let _ = open::that(&issue_url);
+            if opens_in_app(link_behavior, ui) {
+                details_clicked = true;
+            } else {
+                let _ = open::that(&issue_url);
+            }
+        }
+        render_status_pill(ui, &entry.issue_status);
+
+        // Duration - white bold for times to stand out; double-click to edit in place
+        match inline_edit.as_mut().filter(|(id, _)| id == &entry.worklog_id) {
+            Some((_, text)) => {
+                let error_color = Color32::from_rgb(0xff, 0x44, 0x44);
+                let is_valid = parse_duration(text).is_some();
+                let frame = if is_valid {
+                    egui::Frame::none()
+                } else {
+                    egui::Frame::none()
+                        .stroke(egui::Stroke::new(2.0, error_color))
+                        .rounding(4.0)
+                        .inner_margin(2.0)
+                };
+                let edit_response = frame.show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(text)
+                            .desired_width(70.0)
+                            .font(egui::FontId::proportional(14.0))
+                            .hint_text("1h 30m")
+                    )
+                }).inner;
+
+                if edit_response.lost_focus() {
+                    let enter = ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if enter && is_valid {
+                        new_seconds = parse_duration(text);
+                        *inline_edit = None;
+                    } else if enter {
+                        // Keep editing so the input can be corrected
+                        edit_response.request_focus();
+                    } else {
+                        // Escape or clicking elsewhere cancels
+                        *inline_edit = None;
+                    }
+                } else if !edit_response.has_focus() {
+                    // First frame of the edit
+                    edit_response.request_focus();
+                }
+            }
+            // Zero-second worklogs (broken ones from Jira) get no duration
+            None if duration_text.is_empty() => {}
+            None => {
+                let duration_response = ui.add(egui::Label::new(
+                    RichText::new(&duration_text)
+                        .size(14.0)
+                        .family(super::theme::bold_family())
+                        .color(Color32::WHITE)
+                ).sense(egui::Sense::click()));
+
+                if duration_response.double_clicked() {
+                    *inline_edit = Some((entry.worklog_id.clone(), exact_duration_text(entry.seconds, time_format, decimal_precision)));
+                }
+            }
         }
 
-        // Duration - white bold for times to stand out
-        ui.add(egui::Label::new(
-            RichText::new(&duration_text)
-                .size(14.0)
-                .family(super::theme::bold_family())
-                .color(Color32::WHITE)
-        ));
+        // Start time (optional, in secondary color) - dropped when the card is too narrow
+        if show_start_time && !entry.start_time.is_empty() && ui.available_width() > 200.0 {
+            let time_text = format_clock_time(&entry.start_time, clock_format);
+            ui.add(egui::Label::new(
+                RichText::new(&time_text)
+                    .size(14.0)
+                    .color(secondary_color)
+            ).truncate());
+        }
 
         // Description
         if has_description {
@@ -383,6 +916,8 @@ fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &s
                 ui.set_min_width(140.0);
                 ui.style_mut().spacing.button_padding = egui::vec2(12.0, 8.0);
 
+                entry_menu_links(ui, entry, &issue_url);
+
                 if ui.add(egui::Button::new(
                     RichText::new(format!("{}  Edit log", egui_phosphor::regular::PENCIL_SIMPLE))
                         .size(14.0)
@@ -390,6 +925,34 @@ fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &s
                     edit_clicked = true;
                 }
 
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  Duplicate log", egui_phosphor::regular::COPY))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    duplicate_clicked = true;
+                }
+
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  View issue history", egui_phosphor::regular::CLOCK_COUNTER_CLOCKWISE))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    details_clicked = true;
+                }
+
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  Private note", egui_phosphor::regular::NOTE_PENCIL))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    note_clicked = true;
+                }
+
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  Add to calendar", egui_phosphor::regular::CALENDAR_PLUS))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    calendar_clicked = true;
+                }
+
                 if ui.add(egui::Button::new(
                     RichText::new(format!("{}  Delete log", egui_phosphor::regular::TRASH))
                         .size(14.0)
@@ -400,35 +963,29 @@ fn render_entry_row_contracted(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &s
         });
     });
 
-    (edit_clicked, delete_clicked)
+    RowActions { edit: edit_clicked, delete: delete_clicked, details: details_clicked, note: note_clicked, duplicate: duplicate_clicked, calendar: calendar_clicked, new_seconds }
 }
 
-/// Returns (edit_clicked, delete_clicked) - Expanded view with wrapped description
-fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str, time_format: TimeFormat, clock_format: ClockFormat, show_start_time: bool) -> (bool, bool) {
+/// Expanded view with wrapped description and the entry's private note, if any
+#[allow(clippy::too_many_arguments)]
+fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str, time_format: TimeFormat, decimal_precision: u8, clock_format: ClockFormat, show_start_time: bool, colorblind_mode: ColorblindMode, project_colors: &[(String, [u8; 3])], issue_type_colors: &HashMap<String, [u8; 3]>, link_behavior: LinkBehavior, annotation: Option<&str>) -> RowActions {
     let mut edit_clicked = false;
     let mut delete_clicked = false;
+    let mut details_clicked = false;
+    let mut note_clicked = false;
+    let mut duplicate_clicked = false;
+    let mut calendar_clicked = false;
     let (_bg_color, text_color, secondary_color) = entry_colors();
 
     // Accent color based on ticket type
-    let accent_color = if entry.issue_key.starts_with("TIM-") {
-        let summary_upper = entry.issue_summary.to_uppercase();
-        if summary_upper.contains("MEETING") {
-            Color32::from_rgb(0xe8, 0x28, 0x71)  // Pink/magenta
-        } else if summary_upper.contains("SUPPORT") {
-            Color32::from_rgb(0xec, 0x71, 0x1b)  // Orange
-        } else if summary_upper.contains("ADMIN") {
-            Color32::from_rgb(0xe5, 0xaa, 0x00)  // Yellow/gold
-        } else {
-            Color32::from_rgb(0x13, 0x98, 0xf4)  // Blue
-        }
-    } else {
-        Color32::from_rgb(0x13, 0x98, 0xf4)  // Blue for regular tickets
-    };
+    let bucket = bucket_kind(&entry.issue_key, &entry.issue_summary, project_colors);
+    let accent_color = accent_color_for_entry(entry, project_colors, colorblind_mode);
 
     // Card styling
     let card_bg = Color32::from_rgb(0x1c, 0x1c, 0x1a);
     let card_border = Color32::from_rgb(0x28, 0x28, 0x26);
-    let accent_width = 4.0;
+    // Wider stripe when a colorblind mode draws a pattern on it
+    let accent_width = if colorblind_mode == ColorblindMode::None { 4.0 } else { 8.0 };
     let corner_radius = 6.0;
     let card_padding = 10.0;
     let card_gap = 6.0;
@@ -442,6 +999,7 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
     let line_height = 24.0;
     let has_description = !entry.description.is_empty();
     let has_summary = !entry.issue_summary.is_empty();
+    let has_estimate = entry.time_original_estimate > 0;
 
     // Calculate description height if present (wrapped text)
     let description_height = if has_description {
@@ -460,10 +1018,26 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
         0.0
     };
 
+    // Private note wraps like the description, with the same indent
+    let annotation_height = annotation.map(|note| {
+        let note_galley = ui.fonts(|f| {
+            f.layout(
+                note.to_string(),
+                egui::FontId::new(14.0, egui::FontFamily::Proportional),
+                text_color,
+                content_width - 20.0,
+            )
+        });
+        note_galley.rect.height().max(line_height)
+    });
+
     // Layout:
     // Line 1: Icon + Issue key + Duration (bold white) + Start time (optional) + Menu dots
     // Line 2: Summary/issue title (context)
     // Line 3+: Description (what you did - detail, dimmer)
+    // Then: Private note (local only, amber italics)
+    // Then: Issue time spent vs original estimate (only when the issue has an estimate)
+    // Last: Custom worklog properties (if any)
     let line_spacing = 4.0;
     let mut content_height = line_height;  // Line 1 always present
 
@@ -475,6 +1049,19 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
         content_height += line_spacing + description_height;  // Line 3+: description
     }
 
+    if let Some(height) = annotation_height {
+        content_height += line_spacing + height;  // Private note
+    }
+
+    if has_estimate {
+        content_height += line_spacing + line_height;  // Spent vs estimate
+    }
+
+    // Custom worklog properties, one "key: value" line each (sorted for a stable layout)
+    let mut custom_properties: Vec<(&String, &String)> = entry.custom_properties.iter().collect();
+    custom_properties.sort();
+    content_height += custom_properties.len() as f32 * (line_spacing + line_height);
+
     let total_height = content_height + card_padding * 2.0;
 
     // Allocate card space with gap - right-clickable for context menu
@@ -534,6 +1121,12 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
         egui::vec2(corner_radius, card_rect.height())
     );
     painter.rect_filled(cover_rect, 0.0, bg_color);
+    paint_bucket_pattern(
+        painter,
+        egui::Rect::from_min_size(card_rect.min, egui::vec2(accent_width, card_rect.height())),
+        bucket,
+        colorblind_mode,
+    );
 
     // Content area (after accent stripe)
     let content_left = card_rect.min.x + accent_width + card_padding;
@@ -546,8 +1139,8 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
     // Line 1: Icon + Issue key + Duration (bold white) + Start time (optional) + Menu dots
     // Matches the contracted layout for visual consistency
     let issue_url = format!("{}/browse/{}", jira_base_url, entry.issue_key);
-    let icon_style = issue_type_icon(&entry.issue_type);
-    let duration_text = format_duration_with_format(entry.seconds, time_format);
+    let icon_style = issue_type_icon(&entry.issue_type, issue_type_colors);
+    let duration_text = card_duration_text(entry.seconds, time_format, decimal_precision);
     let issue_key_color = Color32::from_rgb(200, 200, 192);
 
     child_ui.horizontal(|ui| {
@@ -568,16 +1161,23 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
         }
         if link_response.clicked() {
-            let _ = open::that(&issue_url);
+            if opens_in_app(link_behavior, ui) {
+                details_clicked = true;
+            } else {
+                let _ = open::that(&issue_url);
+            }
         }
+        render_status_pill(ui, &entry.issue_status);
 
-        // Duration - white bold (matching contracted style)
-        ui.add(egui::Label::new(
-            RichText::new(&duration_text)
-                .size(14.0)
-                .family(super::theme::bold_family())
-                .color(Color32::WHITE)
-        ));
+        // Duration - white bold (matching contracted style), none for zero-second worklogs
+        if !duration_text.is_empty() {
+            ui.add(egui::Label::new(
+                RichText::new(&duration_text)
+                    .size(14.0)
+                    .family(super::theme::bold_family())
+                    .color(Color32::WHITE)
+            ));
+        }
 
         // Start time (optional, in secondary color)
         if show_start_time {
@@ -609,6 +1209,8 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
                 ui.set_min_width(140.0);
                 ui.style_mut().spacing.button_padding = egui::vec2(12.0, 8.0);
 
+                entry_menu_links(ui, entry, &issue_url);
+
                 if ui.add(egui::Button::new(
                     RichText::new(format!("{}  Edit log", egui_phosphor::regular::PENCIL_SIMPLE))
                         .size(14.0)
@@ -616,6 +1218,34 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
                     edit_clicked = true;
                 }
 
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  Duplicate log", egui_phosphor::regular::COPY))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    duplicate_clicked = true;
+                }
+
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  View issue history", egui_phosphor::regular::CLOCK_COUNTER_CLOCKWISE))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    details_clicked = true;
+                }
+
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  Private note", egui_phosphor::regular::NOTE_PENCIL))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    note_clicked = true;
+                }
+
+                if ui.add(egui::Button::new(
+                    RichText::new(format!("{}  Add to calendar", egui_phosphor::regular::CALENDAR_PLUS))
+                        .size(14.0)
+                ).frame(false)).clicked() {
+                    calendar_clicked = true;
+                }
+
                 if ui.add(egui::Button::new(
                     RichText::new(format!("{}  Delete log", egui_phosphor::regular::TRASH))
                         .size(14.0)
@@ -652,51 +1282,295 @@ fn render_entry_row_expanded(ui: &mut Ui, entry: &TimeEntry, jira_base_url: &str
         });
     }
 
-    (edit_clicked, delete_clicked)
-}
-
-pub fn week_start(date: NaiveDate) -> NaiveDate {
-    let weekday = date.weekday();
-    let days_from_monday = weekday.num_days_from_monday();
-    date - Duration::days(days_from_monday as i64)
-}
-
-/// Determine if weekends should be shown based on:
-/// - Today is Saturday or Sunday, OR
-/// - Any entry in the week falls on Saturday or Sunday
-pub fn should_show_weekends(week_data: &WeekData) -> bool {
-    let today = Local::now().date_naive();
-
-    // Show if today is a weekend
-    if matches!(today.weekday(), Weekday::Sat | Weekday::Sun) {
-        return true;
+    // Private note (never sent to Jira)
+    if let Some(note) = annotation {
+        child_ui.add_space(line_spacing);
+        child_ui.horizontal(|ui| {
+            ui.add_space(20.0);  // Same indent as summary
+            ui.add(egui::Label::new(
+                RichText::new(note)
+                    .size(14.0)
+                    .italics()
+                    .color(Color32::from_rgb(0xe5, 0xaa, 0x00))
+            ).wrap());
+        });
     }
 
-    // Show if any entry is on a weekend
-    for entry in &week_data.entries {
-        if matches!(entry.date.weekday(), Weekday::Sat | Weekday::Sun) {
-            return true;
-        }
+    // Issue time spent vs original estimate
+    if has_estimate {
+        child_ui.add_space(line_spacing);
+        child_ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            ui.add_space(20.0);  // Same indent as summary
+            ui.add(egui::Label::new(
+                RichText::new(format!("{} spent", format_duration(entry.time_spent_total)))
+                    .size(14.0)
+                    .color(estimate_color(entry.time_spent_total, entry.time_original_estimate))
+            ));
+            ui.add(egui::Label::new(
+                RichText::new(format!("/ {} estimated", format_duration(entry.time_original_estimate)))
+                    .size(14.0)
+                    .color(secondary_color)
+            ));
+            if let Some(over) = estimate_overrun(entry) {
+                ui.add_space(6.0);
+                ui.add(egui::Label::new(
+                    RichText::new(format!("{} +{} over estimate", egui_phosphor::regular::WARNING_CIRCLE, format_duration_with_format(over, time_format, decimal_precision)))
+                        .size(14.0)
+                        .color(estimate_color(entry.seconds, entry.time_original_estimate))
+                ));
+            }
+        });
     }
 
-    false
-}
+    // Custom worklog properties (key: value, secondary color)
+    for (key, value) in custom_properties {
+        child_ui.add_space(line_spacing);
+        child_ui.horizontal(|ui| {
+            ui.add_space(20.0);  // Same indent as summary
+            ui.add(egui::Label::new(
+                RichText::new(format!("{}: {}", key, value))
+                    .size(14.0)
+                    .color(secondary_color)
+            ).truncate());
+        });
+    }
+
+    RowActions { edit: edit_clicked, delete: delete_clicked, details: details_clicked, note: note_clicked, duplicate: duplicate_clicked, calendar: calendar_clicked, new_seconds: None }
+}
+
+/// Color for time spent relative to the original estimate:
+/// green under 80%, amber from 80% up to the estimate, red when over
+pub fn estimate_color(spent: i64, estimate: i64) -> Color32 {
+    if spent > estimate {
+        Color32::from_rgb(0xe5, 0x4d, 0x42)  // Red
+    } else if spent * 5 >= estimate * 4 {
+        Color32::from_rgb(0xe5, 0xaa, 0x00)  // Amber
+    } else {
+        Color32::from_rgb(0x65, 0xba, 0x43)  // Green
+    }
+}
+
+/// How far the entry's own duration runs past its issue's original estimate, once that is more than 20% over
+pub fn estimate_overrun(entry: &TimeEntry) -> Option<i64> {
+    let estimate = entry.time_original_estimate;
+    (estimate > 0 && entry.seconds * 5 > estimate * 6).then_some(entry.seconds - estimate)
+}
+
+/// Duration shown on an entry card - none for zero-second worklogs (broken ones from Jira)
+fn card_duration_text(seconds: i64, time_format: TimeFormat, decimal_precision: u8) -> String {
+    if seconds == 0 {
+        String::new()
+    } else {
+        format_duration_with_format(seconds, time_format, decimal_precision)
+    }
+}
+
+/// Duration text to pre-fill an edit field with: the user's format when it reads back as exactly `seconds`,
+/// otherwise hours and minutes, so saving without changes doesn't round the worklog (1h 7m isn't 1.1h)
+pub fn exact_duration_text(seconds: i64, time_format: TimeFormat, decimal_precision: u8) -> String {
+    let formatted = format_duration_with_format(seconds, time_format, decimal_precision);
+    if parse_duration(&formatted) == Some(seconds) {
+        formatted
+    } else {
+        format_duration(seconds)
+    }
+}
+
+/// Stand-in for a saved token in the settings field: bullets and its last four characters
+/// (always twelve bullets so the length isn't given away; short tokens show none of their own)
+pub fn masked_token(token: &str) -> String {
+    let chars: Vec<char> = token.chars().collect();
+    let tail: String = if chars.len() > 8 { chars[chars.len() - 4..].iter().collect() } else { String::new() };
+    format!("{}{}", "\u{2022}".repeat(12), tail)
+}
+
+/// Estimate bar for the add dialog: time already logged, plus the entry being added as a lighter segment
+pub fn render_estimate_progress(ui: &mut Ui, spent: i64, estimate: i64, adding: i64, width: f32) {
+    let after = spent + adding;
+    let over = after > estimate;
+    let bar_color = if over { estimate_color(after, estimate) } else { estimate_color(spent, estimate) };
+    let secondary_color = Color32::from_rgb(150, 150, 150);
+
+    ui.vertical(|ui| {
+        ui.spacing_mut().item_spacing.y = 4.0;
+
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(width, 6.0), egui::Sense::hover());
+        let painter = ui.painter();
+        painter.rect_filled(rect, 3.0, Color32::from_rgb(40, 40, 38));
+        let fraction = |seconds: i64| (seconds as f32 / estimate as f32).clamp(0.0, 1.0);
+        let spent_width = rect.width() * fraction(spent);
+        let after_width = rect.width() * fraction(after);
+        if after_width > spent_width {
+            let pending = egui::Rect::from_min_max(egui::pos2(rect.min.x + spent_width, rect.min.y), egui::pos2(rect.min.x + after_width, rect.max.y));
+            painter.rect_filled(pending, 3.0, bar_color.gamma_multiply(0.45));
+        }
+        if spent_width > 0.0 {
+            painter.rect_filled(egui::Rect::from_min_size(rect.min, egui::vec2(spent_width, rect.height())), 3.0, bar_color);
+        }
+
+        let remaining = (estimate - spent).max(0);
+        ui.label(RichText::new(format!("{} logged, {} remaining", format_duration(spent), format_duration(remaining)))
+            .size(12.0)
+            .color(secondary_color));
+        if over && adding > 0 {
+            ui.label(RichText::new(format!("This will exceed the estimate by {}", format_duration(after - estimate)))
+                .size(12.0)
+                .color(bar_color));
+        }
+    });
+}
+
+/// Read-only rendering of a Markdown description, from the same ADF that is sent to Jira
+pub fn render_markdown_preview(ui: &mut Ui, markdown: &str) {
+    let doc = markdown_to_adf(markdown);
+    ui.vertical(|ui| {
+        ui.spacing_mut().item_spacing.y = 6.0;
+        for block in adf_children(&doc) {
+            render_adf_block(ui, block, 0);
+        }
+    });
+}
+
+fn adf_children(node: &serde_json::Value) -> &[serde_json::Value] {
+    node.get("content").and_then(|c| c.as_array()).map(Vec::as_slice).unwrap_or_default()
+}
+
+fn render_adf_block(ui: &mut Ui, node: &serde_json::Value, depth: usize) {
+    let secondary_color = Color32::from_rgb(150, 150, 150);
+    match node.get("type").and_then(|t| t.as_str()).unwrap_or("") {
+        "heading" => {
+            let level = node["attrs"]["level"].as_u64().unwrap_or(1) as f32;
+            render_adf_inline(ui, adf_children(node), Some((20.0 - level * 2.0).max(14.0)));
+        }
+        "bulletList" | "orderedList" => {
+            let ordered = node["type"] == "orderedList";
+            for (i, item) in adf_children(node).iter().enumerate() {
+                ui.horizontal_top(|ui| {
+                    ui.add_space(depth as f32 * 16.0 + 4.0);
+                    let marker = if ordered { format!("{}.", i + 1) } else { "•".to_string() };
+                    ui.label(RichText::new(marker).color(secondary_color));
+                    ui.vertical(|ui| {
+                        for child in adf_children(item) {
+                            render_adf_block(ui, child, depth + 1);
+                        }
+                    });
+                });
+            }
+        }
+        "codeBlock" => {
+            let code: String = adf_children(node).iter().filter_map(|t| t["text"].as_str()).collect();
+            egui::Frame::none()
+                .fill(Color32::from_rgb(40, 40, 38))
+                .rounding(4.0)
+                .inner_margin(6.0)
+                .show(ui, |ui| ui.label(RichText::new(code).monospace()));
+        }
+        "blockquote" => {
+            ui.horizontal_top(|ui| {
+                ui.label(RichText::new("│").color(secondary_color));
+                ui.vertical(|ui| {
+                    for child in adf_children(node) {
+                        render_adf_block(ui, child, depth);
+                    }
+                });
+            });
+        }
+        "table" => {
+            egui::Grid::new(ui.next_auto_id()).striped(true).spacing([12.0, 4.0]).show(ui, |ui| {
+                for row in adf_children(node) {
+                    for cell in adf_children(row) {
+                        let header = cell["type"] == "tableHeader";
+                        ui.vertical(|ui| {
+                            for child in adf_children(cell) {
+                                if header {
+                                    render_adf_inline(ui, adf_children(child), None);
+                                } else {
+                                    render_adf_block(ui, child, depth);
+                                }
+                            }
+                        });
+                    }
+                    ui.end_row();
+                }
+            });
+        }
+        // Paragraphs and anything unrecognized render their inline text
+        _ => render_adf_inline(ui, adf_children(node), None),
+    }
+}
+
+/// One wrapped label for a run of ADF text nodes, styled from their marks;
+/// `heading_size` makes the whole run a bold heading
+fn render_adf_inline(ui: &mut Ui, nodes: &[serde_json::Value], heading_size: Option<f32>) {
+    let mut job = egui::text::LayoutJob::default();
+    for node in nodes {
+        let Some(text) = node["text"].as_str() else { continue };
+        let mut rich = RichText::new(text);
+        if let Some(size) = heading_size {
+            rich = rich.size(size).strong();
+        }
+        for mark in node.get("marks").and_then(|m| m.as_array()).map(Vec::as_slice).unwrap_or_default() {
+            rich = match mark["type"].as_str().unwrap_or("") {
+                "strong" => rich.strong(),
+                "em" => rich.italics(),
+                "code" => rich.monospace(),
+                "strike" => rich.strikethrough(),
+                "link" => rich.color(Color32::from_rgb(0x13, 0x98, 0xf4)).underline(),
+                _ => rich,
+            };
+        }
+        rich.append_to(&mut job, ui.style(), egui::FontSelection::Default, egui::Align::Center);
+    }
+    ui.label(job);
+}
+
+/// Determine if weekends should be shown for the displayed week based on:
+/// - Today is Saturday or Sunday and falls in this week, OR
+/// - Any entry dated within this week falls on Saturday or Sunday
+pub fn should_show_weekends(week_data: &WeekData) -> bool {
+    let today = Local::now().date_naive();
+    let week_end = week_data.week_start + Duration::days(6);
+    let in_week = |date: NaiveDate| date >= week_data.week_start && date <= week_end;
+    let is_weekend = |date: NaiveDate| matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+
+    // Today only counts in its own week, so other weeks don't grow empty weekend columns
+    if in_week(today) && is_weekend(today) {
+        return true;
+    }
+
+    week_data.entries.iter().any(|entry| in_week(entry.date) && is_weekend(entry.date))
+}
+
+/// Whether to show Saturday and Sunday, honoring the configured preference
+pub fn show_weekends(week_data: &WeekData, weekend_display: WeekendDisplay) -> bool {
+    match weekend_display {
+        WeekendDisplay::Auto => should_show_weekends(week_data),
+        WeekendDisplay::AlwaysShow => true,
+        WeekendDisplay::AlwaysHide => false,
+    }
+}
 
 /// Render the day tabs with hours status and view mode toggle
 /// Returns (clicked_day, view_mode_toggled)
+#[allow(clippy::too_many_arguments)]
 pub fn render_day_tabs(
     ui: &mut Ui,
     week_data: &WeekData,
     selected_day: NaiveDate,
     time_format: TimeFormat,
+    decimal_precision: u8,
     list_view_mode: ListViewMode,
+    tags: &[TagDefinition],
+    weekend_display: WeekendDisplay,
+    daily_target_hours: f32,
 ) -> (Option<NaiveDate>, bool) {
     let today = Local::now().date_naive();
     let mut clicked_day = None;
     let mut view_mode_toggled = false;
-    let show_weekends = should_show_weekends(week_data);
+    let show_weekends = show_weekends(week_data, weekend_display);
 
-    let (bg_color, border_color, _accent) = day_tab_colors();
+    let (bg_color, border_color, accent) = day_tab_colors();
 
     ui.horizontal(|ui| {
         // Filter days based on whether weekends should be shown
@@ -732,10 +1606,11 @@ pub fn render_day_tabs(
 
             let seconds = week_data.seconds_for_day(day);
             let is_future = day > today;
+            let day_tags = tag_totals_for_day(week_data, day, tags);
 
             // Show "0" for zero duration on past/current days, nothing for future days
             let hours_text = if seconds > 0 {
-                format_duration_with_format(seconds, time_format)
+                format_duration_with_format(seconds, time_format, decimal_precision)
             } else if is_future {
                 String::new()  // Hide "0" on future days
             } else {
@@ -778,8 +1653,53 @@ pub fn render_day_tabs(
                     egui::FontId::new(14.0, super::theme::bold_family()),
                     hours_color,
                 );
+
+                // Up to 3 dots for the day's most-used tags
+                let dot_count = day_tags.len().min(3);
+                let dot_spacing = 8.0;
+                let first_x = rect.center().x - (dot_count as f32 - 1.0) * dot_spacing / 2.0;
+                for (i, (tag, _)) in day_tags.iter().take(3).enumerate() {
+                    painter.circle_filled(
+                        egui::pos2(first_x + i as f32 * dot_spacing, rect.min.y + 56.0),
+                        2.0,
+                        super::theme::tag_color(tag, tags),
+                    );
+                }
+
+                // Progress towards the daily target (none for future days)
+                if !is_future && daily_target_hours > 0.0 {
+                    let progress = (seconds as f32 / (daily_target_hours * 3600.0)).clamp(0.0, 1.0);
+                    let bar_color = if progress >= 1.0 {
+                        Color32::from_rgb(0x65, 0xba, 0x43)  // Green
+                    } else if progress >= 0.5 {
+                        Color32::from_rgb(0xe5, 0xaa, 0x00)  // Amber
+                    } else if is_today {
+                        accent  // Still time left today
+                    } else {
+                        Color32::from_rgb(0xe5, 0x4d, 0x42)  // Red
+                    };
+                    let track = egui::Rect::from_min_size(
+                        egui::pos2(rect.center().x - 20.0, rect.max.y - 5.0),
+                        egui::vec2(40.0, 2.0),
+                    );
+                    painter.rect_filled(track, 1.0, border_color);
+                    let mut fill = track;
+                    fill.set_width(track.width() * progress);
+                    painter.rect_filled(fill, 1.0, bar_color);
+                }
             }
 
+            // Tooltip with per-tag durations (e.g. "FE: 2h, Meetings: 1h")
+            let response = if day_tags.is_empty() {
+                response
+            } else {
+                let tooltip = day_tags.iter()
+                    .map(|(tag, secs)| format!("{}: {}", tag, format_duration_with_format(*secs, time_format, decimal_precision)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                response.on_hover_text(tooltip)
+            };
+
             if response.clicked() {
                 clicked_day = Some(day);
             }
@@ -825,20 +1745,56 @@ pub fn render_day_tabs(
     (clicked_day, view_mode_toggled)
 }
 
+/// Total seconds per tag over some entries, most-used first, with tags read as the entry dialog
+/// reads them (`parse_categories_from_description`) and named as configured
+fn tag_totals_of<'a>(entries: impl IntoIterator<Item = &'a TimeEntry>, tags: &[TagDefinition]) -> Vec<(String, i64)> {
+    let mut seconds = vec![0i64; tags.len()];
+    for entry in entries {
+        let (categories, _) = parse_categories_from_description(&entry.description, tags);
+        for (i, _) in categories.iter().enumerate().filter(|(_, set)| **set) {
+            seconds[i] += entry.seconds;
+        }
+    }
+    let mut totals: Vec<(String, i64)> = tags.iter()
+        .zip(seconds)
+        .filter(|(_, secs)| *secs > 0)
+        .map(|(tag, secs)| (tag.name.clone(), secs))
+        .collect();
+    totals.sort_by_key(|(_, secs)| std::cmp::Reverse(*secs));
+    totals
+}
+
+/// Total seconds per tag for a day, most-used first
+fn tag_totals_for_day(week_data: &WeekData, day: NaiveDate, tags: &[TagDefinition]) -> Vec<(String, i64)> {
+    tag_totals_of(week_data.entries_for_day(day), tags)
+}
+
 /// Render the schedule/timeline view
+#[allow(clippy::too_many_arguments)]
 pub fn render_schedule_view(
     ui: &mut Ui,
     week_data: &WeekData,
-    _jira_base_url: &str,  // Reserved for future use (context menu links)
+    jira_base_url: &str,
     time_format: TimeFormat,
+    decimal_precision: u8,
     clock_format: ClockFormat,
     schedule_start_hour: u8,
     schedule_end_hour: u8,
     snap_interval: crate::config::SnapInterval,
     dialog_open: bool,
+    scroll_to_now: bool,  // Center the current time on first entry into the view
+    colorblind_mode: ColorblindMode,
+    project_colors: &[(String, [u8; 3])],
+    min_column_width: f32,
+    (min_block_px, label_threshold_px): (f32, f32),  // Minimum block height, and the height below which labels are hidden
+    hour_heat: Option<&[f32; 24]>,  // Normalized logged time per hour from recent weeks
+    hover_started: &mut HashMap<String, Instant>,  // worklog_id -> when the pointer came to rest on its block
+    issue_type_colors: &HashMap<String, [u8; 3]>,
+    weekend_display: WeekendDisplay,
+    team: &[(String, Vec<TimeEntry>)],  // Team members' entries (display name, entries), drawn read-only beside ours
 ) -> ScheduleResult {
     let mut result = ScheduleResult::default();
-    let show_weekends = should_show_weekends(week_data);
+    let show_weekends = show_weekends(week_data, weekend_display);
 
     // Filter days based on whether weekends should be shown
     let days: Vec<NaiveDate> = week_data.all_days()
@@ -858,20 +1814,20 @@ pub fn render_schedule_view(
     let mut actual_start_hour = schedule_start_hour;
     let mut actual_end_hour = schedule_end_hour;
 
-    for day in &days {
-        for entry in week_data.entries_for_day(*day) {
-            let entry_start_minutes = parse_time_to_minutes(&entry.start_time);
-            let entry_end_minutes = entry_start_minutes + (entry.seconds / 60) as i32;
+    // Team members' entries count too, so none fall outside the grid
+    let team_entries = team.iter().flat_map(|(_, entries)| entries.iter());
+    for entry in week_data.entries.iter().chain(team_entries).filter(|e| days.contains(&e.date)) {
+        let entry_start_minutes = parse_time_to_minutes(&entry.start_time);
+        let entry_end_minutes = entry_start_minutes + (entry.seconds / 60) as i32;
 
-            let entry_start_hour = (entry_start_minutes / 60) as u8;
-            let entry_end_hour = ((entry_end_minutes + 59) / 60) as u8; // Round up
+        let entry_start_hour = (entry_start_minutes / 60) as u8;
+        let entry_end_hour = ((entry_end_minutes + 59) / 60) as u8; // Round up
 
-            if entry_start_hour < actual_start_hour {
-                actual_start_hour = entry_start_hour;
-            }
-            if entry_end_hour > actual_end_hour {
-                actual_end_hour = entry_end_hour.min(24);
-            }
+        if entry_start_hour < actual_start_hour {
+            actual_start_hour = entry_start_hour;
+        }
+        if entry_end_hour > actual_end_hour {
+            actual_end_hour = entry_end_hour.min(24);
         }
     }
 
@@ -881,7 +1837,8 @@ pub fn render_schedule_view(
 
     // Layout constants
     let hour_label_width = 60.0;
-    let header_height = 32.0;
+    let name_row_height = if team.is_empty() { 0.0 } else { 16.0 };  // Sub-column names under the day headers
+    let header_height = 32.0 + name_row_height;
     let hour_height = 60.0;  // Height per hour
     let grid_line_color = Color32::from_rgb(0x40, 0x40, 0x3c);
     let hour_line_color = Color32::from_rgb(0x50, 0x50, 0x4a);
@@ -889,505 +1846,934 @@ pub fn render_schedule_view(
     let num_hours = (schedule_end_hour - schedule_start_hour) as usize;
     let total_grid_height = num_hours as f32 * hour_height;
 
-    let available_width = ui.available_width();
+    // Columns never shrink below min_column_width - the grid scrolls horizontally instead
     let num_days = days.len();
-    let day_width = (available_width - hour_label_width) / num_days as f32;
-
-    // Fixed day headers (outside ScrollArea)
-    let (header_rect, _) = ui.allocate_exact_size(
-        egui::vec2(available_width, header_height),
-        egui::Sense::hover()
-    );
-
-    let painter = ui.painter();
-
-    for (i, day) in days.iter().enumerate() {
-        let x = header_rect.min.x + hour_label_width + i as f32 * day_width;
-        let col_header_rect = egui::Rect::from_min_size(
-            egui::pos2(x, header_rect.min.y),
-            egui::vec2(day_width, header_height)
+    let day_width = ((ui.available_width() - hour_label_width) / num_days as f32).max(min_column_width);
+    let grid_width = hour_label_width + day_width * num_days as f32;
+    let (own_width, member_width) = team_column_widths(day_width, team.len());
+
+    egui::ScrollArea::horizontal().id_salt("schedule_horizontal").show(ui, |ui| {
+        // Fixed day headers (outside the vertical ScrollArea)
+        let (header_rect, _) = ui.allocate_exact_size(
+            egui::vec2(grid_width, header_height),
+            egui::Sense::hover()
         );
 
-        let is_today = *day == today;
-
-        // Day name
-        let day_name = if is_today {
-            "Today"
-        } else {
-            match day.weekday() {
-                Weekday::Mon => "Mon",
-                Weekday::Tue => "Tue",
-                Weekday::Wed => "Wed",
-                Weekday::Thu => "Thu",
-                Weekday::Fri => "Fri",
-                Weekday::Sat => "Sat",
-                Weekday::Sun => "Sun",
-            }
-        };
+        let painter = ui.painter();
 
-        // Daily total - hide "0" on future days
-        let seconds = week_data.seconds_for_day(*day);
-        let is_future = *day > today;
-        let hours_text = if seconds > 0 {
-            crate::api::format_duration_with_format(seconds, time_format)
-        } else if is_future {
-            String::new()
-        } else {
-            "0".to_string()
-        };
+        for (i, day) in days.iter().enumerate() {
+            let x = header_rect.min.x + hour_label_width + i as f32 * day_width;
+            let col_header_rect = egui::Rect::from_min_size(
+                egui::pos2(x, header_rect.min.y),
+                egui::vec2(day_width, header_height)
+            );
 
-        // Combined: "Mon 6h 30m" left-justified
-        let day_color = Color32::from_rgb(0xb0, 0xb0, 0xa8);
-        let hours_color = Color32::WHITE;  // Bright white for times to stand out
+            let is_today = *day == today;
 
-        let text_left = col_header_rect.min.x + 8.0;
-        let text_y = col_header_rect.center().y;
+            // Day name
+            let day_name = if is_today {
+                "Today"
+            } else {
+                match day.weekday() {
+                    Weekday::Mon => "Mon",
+                    Weekday::Tue => "Tue",
+                    Weekday::Wed => "Wed",
+                    Weekday::Thu => "Thu",
+                    Weekday::Fri => "Fri",
+                    Weekday::Sat => "Sat",
+                    Weekday::Sun => "Sun",
+                }
+            };
 
-        // Day name
-        let day_galley = painter.layout_no_wrap(
-            day_name.to_string(),
-            egui::FontId::proportional(14.0),
-            day_color
-        );
-        let day_width_px = day_galley.rect.width();
-        painter.galley(egui::pos2(text_left, text_y - day_galley.rect.height() / 2.0), day_galley, Color32::WHITE);
+            // Daily total - hide "0" on future days
+            let seconds = week_data.seconds_for_day(*day);
+            let is_future = *day > today;
+            let hours_text = if seconds > 0 {
+                crate::api::format_duration_with_format(seconds, time_format, decimal_precision)
+            } else if is_future {
+                String::new()
+            } else {
+                "0".to_string()
+            };
 
-        // Hours (after day name with space) - bold white for times to stand out
-        painter.text(
-            egui::pos2(text_left + day_width_px + 8.0, text_y),
-            egui::Align2::LEFT_CENTER,
-            &hours_text,
-            egui::FontId::new(14.0, super::theme::bold_family()),
-            hours_color,
-        );
+            // Combined: "Mon 6h 30m" left-justified
+            let day_color = Color32::from_rgb(0xb0, 0xb0, 0xa8);
+            let hours_color = Color32::WHITE;  // Bright white for times to stand out
 
-        // Vertical separator line between columns
-        if i > 0 {
-            painter.line_segment(
-                [
-                    egui::pos2(x, col_header_rect.min.y + 4.0),
-                    egui::pos2(x, col_header_rect.max.y - 4.0),
-                ],
-                egui::Stroke::new(1.0, grid_line_color),
-            );
-        }
-    }
+            let text_left = col_header_rect.min.x + 8.0;
+            let text_y = col_header_rect.min.y + 16.0;
 
-    // Scrollable grid area
-    egui::ScrollArea::vertical().show(ui, |ui| {
-        // Allocate the grid area (without header)
-        let (grid_rect, _) = ui.allocate_exact_size(
-            egui::vec2(available_width, total_grid_height),
-            egui::Sense::hover()
-        );
+            // Day name
+            let day_galley = painter.layout_no_wrap(
+                day_name.to_string(),
+                egui::FontId::proportional(14.0),
+                day_color
+            );
+            let day_width_px = day_galley.rect.width();
+            painter.galley(egui::pos2(text_left, text_y - day_galley.rect.height() / 2.0), day_galley, Color32::WHITE);
+
+            // Hours (after day name with space) - bold white for times to stand out
+            painter.text(
+                egui::pos2(text_left + day_width_px + 8.0, text_y),
+                egui::Align2::LEFT_CENTER,
+                &hours_text,
+                egui::FontId::new(14.0, super::theme::bold_family()),
+                hours_color,
+            );
 
-        let painter = ui.painter();
+            // Sub-column names: ours first, then each team member's
+            if !team.is_empty() {
+                let name_font = egui::FontId::proportional(11.0);
+                let name_y = col_header_rect.max.y - name_row_height / 2.0;
+                let names = std::iter::once(("You", x, own_width))
+                    .chain(team.iter().enumerate().map(|(m, (name, _))| {
+                        (name.as_str(), x + own_width + m as f32 * member_width, member_width)
+                    }));
+                for (name, name_x, width) in names {
+                    let galley = painter.layout_no_wrap(name.to_string(), name_font.clone(), day_color);
+                    let clip = egui::Rect::from_min_size(egui::pos2(name_x, col_header_rect.min.y), egui::vec2(width - 4.0, header_height));
+                    painter.with_clip_rect(clip).galley(egui::pos2(name_x + 4.0, name_y - galley.size().y / 2.0), galley, day_color);
+                }
+            }
 
-        // Highlight current day column with dim background
-        for (i, day) in days.iter().enumerate() {
-            if *day == today {
-                let col_x = grid_rect.min.x + hour_label_width + i as f32 * day_width;
-                let col_rect = egui::Rect::from_min_size(
-                    egui::pos2(col_x, grid_rect.min.y),
-                    egui::vec2(day_width, total_grid_height)
+            // Vertical separator line between columns
+            if i > 0 {
+                painter.line_segment(
+                    [
+                        egui::pos2(x, col_header_rect.min.y + 4.0),
+                        egui::pos2(x, col_header_rect.max.y - 4.0),
+                    ],
+                    egui::Stroke::new(1.0, grid_line_color),
                 );
-                painter.rect_filled(col_rect, 0.0, Color32::from_rgb(0x11, 0x11, 0x10));
-                break;
             }
         }
 
-        // Vertical grid lines for columns
-        for (i, _day) in days.iter().enumerate() {
-            let x = grid_rect.min.x + hour_label_width + i as f32 * day_width;
-            painter.line_segment(
-                [
-                    egui::pos2(x, grid_rect.min.y),
-                    egui::pos2(x, grid_rect.max.y),
-                ],
-                egui::Stroke::new(1.0, grid_line_color),
-            );
+        // Scrollable grid area
+        let mut scroll_area = egui::ScrollArea::vertical().id_salt("schedule");
+        if scroll_to_now {
+            let now = Local::now();
+            let now_hours = now.hour() as f32 + now.minute() as f32 / 60.0;
+            let now_offset = (now_hours - schedule_start_hour as f32) * hour_height - ui.available_height() / 2.0;
+            scroll_area = scroll_area.vertical_scroll_offset(now_offset.clamp(0.0, total_grid_height));
         }
+        scroll_area.show(ui, |ui| {
+            // Allocate the grid area (without header)
+            let (grid_rect, _) = ui.allocate_exact_size(
+                egui::vec2(grid_width, total_grid_height),
+                egui::Sense::hover()
+            );
 
-        // Right edge line
-        painter.line_segment(
-            [
-                egui::pos2(grid_rect.max.x, grid_rect.min.y),
-                egui::pos2(grid_rect.max.x, grid_rect.max.y),
-            ],
-            egui::Stroke::new(1.0, grid_line_color),
-        );
+            let painter = ui.painter();
 
-        // Hour labels and horizontal grid lines
-        for hour_idx in 0..=num_hours {
-            let hour = schedule_start_hour + hour_idx as u8;
-            let y = grid_rect.min.y + hour_idx as f32 * hour_height;
+            // Highlight current day column with dim background
+            for (i, day) in days.iter().enumerate() {
+                if *day == today {
+                    let col_x = grid_rect.min.x + hour_label_width + i as f32 * day_width;
+                    let col_rect = egui::Rect::from_min_size(
+                        egui::pos2(col_x, grid_rect.min.y),
+                        egui::vec2(day_width, total_grid_height)
+                    );
+                    painter.rect_filled(col_rect, 0.0, Color32::from_rgb(0x11, 0x11, 0x10));
+                    break;
+                }
+            }
 
-            // Hour label - aligned with the hour line, smaller and darker
-            if hour_idx < num_hours {
-                let hour_text = format_clock_time(&format!("{:02}:00", hour), clock_format);
-                painter.text(
-                    egui::pos2(grid_rect.min.x + hour_label_width - 8.0, y),
-                    egui::Align2::RIGHT_TOP,
-                    &hour_text,
-                    egui::FontId::proportional(11.0),  // Smaller font for axis labels
-                    Color32::from_rgb(0x70, 0x70, 0x68),  // Darker gray for less prominence
+            // Vertical grid lines for columns
+            for (i, _day) in days.iter().enumerate() {
+                let x = grid_rect.min.x + hour_label_width + i as f32 * day_width;
+                painter.line_segment(
+                    [
+                        egui::pos2(x, grid_rect.min.y),
+                        egui::pos2(x, grid_rect.max.y),
+                    ],
+                    egui::Stroke::new(1.0, grid_line_color),
                 );
             }
 
-            // Horizontal line for full hours
+            // Right edge line
             painter.line_segment(
                 [
-                    egui::pos2(grid_rect.min.x + hour_label_width, y),
-                    egui::pos2(grid_rect.max.x, y),
+                    egui::pos2(grid_rect.max.x, grid_rect.min.y),
+                    egui::pos2(grid_rect.max.x, grid_rect.max.y),
                 ],
-                egui::Stroke::new(1.0, if hour_idx == 0 { hour_line_color } else { grid_line_color }),
+                egui::Stroke::new(1.0, grid_line_color),
             );
 
-            // Draw 15-minute subdivision lines (solid, darker than hour lines)
-            if hour_idx < num_hours {
-                let quarter_color = Color32::from_rgb(0x24, 0x24, 0x22);
-                let quarter_height = hour_height / 4.0;
+            // Hour labels and horizontal grid lines
+            for hour_idx in 0..=num_hours {
+                let y = grid_rect.min.y + hour_idx as f32 * hour_height;
+
+                // Horizontal line for full hours
+                painter.line_segment(
+                    [
+                        egui::pos2(grid_rect.min.x + hour_label_width, y),
+                        egui::pos2(grid_rect.max.x, y),
+                    ],
+                    egui::Stroke::new(1.0, if hour_idx == 0 { hour_line_color } else { grid_line_color }),
+                );
 
-                for quarter in 1..4 {
-                    let quarter_y = y + quarter as f32 * quarter_height;
+                // Draw 15-minute subdivision lines (solid, darker than hour lines)
+                if hour_idx < num_hours {
+                    let quarter_color = Color32::from_rgb(0x24, 0x24, 0x22);
+                    let quarter_height = hour_height / 4.0;
+
+                    for quarter in 1..4 {
+                        let quarter_y = y + quarter as f32 * quarter_height;
+                        painter.line_segment(
+                            [
+                                egui::pos2(grid_rect.min.x + hour_label_width, quarter_y),
+                                egui::pos2(grid_rect.max.x, quarter_y),
+                            ],
+                            egui::Stroke::new(1.0, quarter_color),
+                        );
+                    }
+                }
+            }
+
+            // Render entries as blocks
+            let pixels_per_minute = hour_height / 60.0;
+            let start_minutes = schedule_start_hour as i32 * 60;
+            let end_minutes = schedule_end_hour as i32 * 60;
+
+            // Current-time line across all columns, only when today is in view
+            if days.contains(&today) {
+                let now = Local::now();
+                let now_minutes = (now.hour() * 60 + now.minute()) as i32;
+                if now_minutes >= start_minutes && now_minutes <= end_minutes {
+                    let y = grid_rect.min.y + (now_minutes - start_minutes) as f32 * pixels_per_minute;
+                    let now_color = Color32::from_rgb(0xe5, 0x4d, 0x42);
                     painter.line_segment(
                         [
-                            egui::pos2(grid_rect.min.x + hour_label_width, quarter_y),
-                            egui::pos2(grid_rect.max.x, quarter_y),
+                            egui::pos2(grid_rect.min.x + hour_label_width, y),
+                            egui::pos2(grid_rect.max.x, y),
                         ],
-                        egui::Stroke::new(1.0, quarter_color),
+                        egui::Stroke::new(1.5, now_color),
                     );
+                    painter.circle_filled(egui::pos2(grid_rect.min.x + hour_label_width, y), 3.5, now_color);
                 }
+                ui.ctx().request_repaint_after(std::time::Duration::from_secs(60));
             }
-        }
 
-        // Render entries as blocks
-        let pixels_per_minute = hour_height / 60.0;
-        let start_minutes = schedule_start_hour as i32 * 60;
-        let end_minutes = schedule_end_hour as i32 * 60;
-
-        // First pass: collect all entry rects and render them
-        let mut all_entry_rects: Vec<egui::Rect> = Vec::new();
-
-        // Drag state: (entry, original_start_minutes, original_end_minutes, press_time, original_col_x, drag_mode)
-        // drag_mode: 0=move, 1=resize-top (change start), 2=resize-bottom (change duration)
-        // Use egui memory to persist across frames
-        let drag_id = ui.id().with("schedule_drag");
-        type DragState = (TimeEntry, i32, i32, f64, f32, u8);
-        let grabbed_state: Option<DragState> = ui.ctx().memory(|mem| {
-            mem.data.get_temp::<DragState>(drag_id).clone()
-        });
+            // First pass: collect all entry rects and render them
+            let mut all_entry_rects: Vec<egui::Rect> = Vec::new();
 
-        let current_time = ui.ctx().input(|i| i.time);
-        let long_press_threshold = 0.2; // 200ms for long-press to initiate drag
-        let edge_threshold = 8.0; // pixels from edge to trigger resize mode
+            // Drag state: (entry, original_start_minutes, original_end_minutes, press_time, original_col_x, drag_mode)
+            // drag_mode: 0=move, 1=resize-top (change start), 2=resize-bottom (change duration)
+            // Use egui memory to persist across frames
+            let drag_id = ui.id().with("schedule_drag");
+            type DragState = (TimeEntry, i32, i32, f64, f32, u8);
+            let grabbed_state: Option<DragState> = ui.ctx().memory(|mem| {
+                mem.data.get_temp::<DragState>(drag_id).clone()
+            });
 
-        // Check if we're in drag mode (past threshold)
-        let in_drag_mode = grabbed_state.as_ref().map(|(_, _, _, press_time, _, _)| {
-            current_time - press_time > long_press_threshold
-        }).unwrap_or(false);
+            let current_time = ui.ctx().input(|i| i.time);
+            let long_press_threshold = 0.2; // 200ms for long-press to initiate drag
+            let edge_threshold = 8.0; // pixels from edge to trigger resize mode
 
-        // Get the dragged entry's worklog_id to skip rendering it at original position
-        let dragged_worklog_id = if in_drag_mode {
-            grabbed_state.as_ref().map(|(e, _, _, _, _, _)| e.worklog_id.clone())
-        } else {
-            None
-        };
+            // Check if we're in drag mode (past threshold)
+            let in_drag_mode = grabbed_state.as_ref().map(|(_, _, _, press_time, _, _)| {
+                current_time - press_time > long_press_threshold
+            }).unwrap_or(false);
 
-        for (day_idx, day) in days.iter().enumerate() {
-            let day_entries = week_data.entries_for_day(*day);
-            let col_x = grid_rect.min.x + hour_label_width + day_idx as f32 * day_width;
+            // Get the dragged entry's worklog_id to skip rendering it at original position
+            let dragged_worklog_id = if in_drag_mode {
+                grabbed_state.as_ref().map(|(e, _, _, _, _, _)| e.worklog_id.clone())
+            } else {
+                None
+            };
 
-            for entry in day_entries {
-                // Parse start time
-                let entry_start_minutes = parse_time_to_minutes(&entry.start_time);
-                let entry_end_minutes = entry_start_minutes + (entry.seconds / 60) as i32;
+            let mut hovered_worklog_id: Option<String> = None;
 
-                // Skip if completely outside visible range
-                if entry_end_minutes <= start_minutes || entry_start_minutes >= end_minutes {
-                    continue;
-                }
+            // Context menu opened by right-clicking a block: (entry, pointer position)
+            let menu_id = ui.id().with("schedule_entry_menu");
+            let menu_state: Option<(TimeEntry, egui::Pos2)> = ui.ctx().memory(|mem| mem.data.get_temp(menu_id));
+            let secondary_pressed = ui.ctx().input(|i| i.pointer.button_pressed(egui::PointerButton::Secondary));
 
-                // Clamp to visible range
-                let visible_start = entry_start_minutes.max(start_minutes);
-                let visible_end = entry_end_minutes.min(end_minutes);
+            for (day_idx, day) in days.iter().enumerate() {
+                let day_entries = week_data.entries_for_day(*day);
+                let col_x = grid_rect.min.x + hour_label_width + day_idx as f32 * day_width;
 
-                // Calculate Y position and height
-                let y_start = grid_rect.min.y
-                    + (visible_start - start_minutes) as f32 * pixels_per_minute;
-                let height = (visible_end - visible_start) as f32 * pixels_per_minute;
+                // Side-by-side lanes for overlapping entries, using the drawn (min-height) extent
+                let min_block_minutes = (min_block_px / pixels_per_minute).ceil() as i32;
+                let lanes = overlap_lanes(&day_entries.iter().map(|e| {
+                    let start = parse_time_to_minutes(&e.start_time);
+                    (start, (start + (e.seconds / 60) as i32).max(start + min_block_minutes))
+                }).collect::<Vec<_>>());
 
-                let block_margin = 2.0;
-                // Subtract 2 pixels from height to create visual gap between adjacent blocks
-                let block_rect = egui::Rect::from_min_size(
-                    egui::pos2(col_x + block_margin, y_start),
-                    egui::vec2(day_width - block_margin * 2.0, (height - 2.0).max(20.0))
-                );
+                for (entry, (lane, lane_count)) in day_entries.into_iter().zip(lanes) {
+                    // Parse start time
+                    let entry_start_minutes = parse_time_to_minutes(&entry.start_time);
+                    let entry_end_minutes = entry_start_minutes + (entry.seconds / 60) as i32;
 
-                all_entry_rects.push(block_rect);
+                    // Skip if completely outside visible range
+                    if entry_end_minutes <= start_minutes || entry_start_minutes >= end_minutes {
+                        continue;
+                    }
 
-                // Skip rendering if this entry is being dragged (we'll render it at mouse position)
-                let is_being_dragged = dragged_worklog_id.as_ref() == Some(&entry.worklog_id);
-                if !is_being_dragged {
-                    // Render the entry (paint only)
-                    render_schedule_entry_paint(ui, block_rect, entry, time_format);
-                }
+                    // Clamp to visible range
+                    let visible_start = entry_start_minutes.max(start_minutes);
+                    let visible_end = entry_end_minutes.min(end_minutes);
+
+                    // Calculate Y position and height
+                    let y_start = grid_rect.min.y
+                        + (visible_start - start_minutes) as f32 * pixels_per_minute;
+                    let height = (visible_end - visible_start) as f32 * pixels_per_minute;
+
+                    let block_margin = 2.0;
+                    let lane_width = own_width / lane_count as f32;
+                    // Subtract 2 pixels from height to create visual gap between adjacent blocks
+                    let block_rect = egui::Rect::from_min_size(
+                        egui::pos2(col_x + lane as f32 * lane_width + block_margin, y_start),
+                        egui::vec2(lane_width - block_margin * 2.0, (height - 2.0).max(min_block_px))
+                    );
 
-                // Check if pointer is over this entry manually
-                let pointer_pos = ui.ctx().pointer_hover_pos();
-                let pointer_over_entry = pointer_pos
-                    .map(|pos| block_rect.contains(pos))
-                    .unwrap_or(false);
-
-                // Detect edge proximity for resize cursor
-                let (near_top_edge, near_bottom_edge) = if let Some(pos) = pointer_pos {
-                    if block_rect.contains(pos) {
-                        let dist_from_top = pos.y - block_rect.min.y;
-                        let dist_from_bottom = block_rect.max.y - pos.y;
-                        (dist_from_top < edge_threshold, dist_from_bottom < edge_threshold)
+                    all_entry_rects.push(block_rect);
+
+                    // Skip rendering if this entry is being dragged (we'll render it at mouse position)
+                    let is_being_dragged = dragged_worklog_id.as_ref() == Some(&entry.worklog_id);
+                    if !is_being_dragged {
+                        // Render the entry (paint only)
+                        render_schedule_entry_paint(ui, block_rect, entry, time_format, decimal_precision, colorblind_mode, project_colors, issue_type_colors, label_threshold_px);
+                    }
+
+                    // Check if pointer is over this entry manually
+                    let pointer_pos = ui.ctx().pointer_hover_pos();
+                    let pointer_over_entry = pointer_pos
+                        .map(|pos| block_rect.contains(pos))
+                        .unwrap_or(false);
+
+                    // Detect edge proximity for resize cursor
+                    let (near_top_edge, near_bottom_edge) = if let Some(pos) = pointer_pos {
+                        if block_rect.contains(pos) {
+                            let dist_from_top = pos.y - block_rect.min.y;
+                            let dist_from_bottom = block_rect.max.y - pos.y;
+                            (dist_from_top < edge_threshold, dist_from_bottom < edge_threshold)
+                        } else {
+                            (false, false)
+                        }
                     } else {
                         (false, false)
+                    };
+
+                    // Check if primary button JUST went down this frame
+                    let button_just_pressed = ui.ctx().input(|i| i.pointer.button_pressed(egui::PointerButton::Primary));
+
+                    // Capture entry when click starts on it - store in memory with press time and drag mode
+                    if pointer_over_entry && button_just_pressed && grabbed_state.is_none() && menu_state.is_none() {
+                        let drag_mode: u8 = if near_top_edge {
+                            1 // resize-top
+                        } else if near_bottom_edge {
+                            2 // resize-bottom
+                        } else {
+                            0 // move
+                        };
+                        ui.ctx().memory_mut(|mem| {
+                            mem.data.insert_temp(drag_id, (entry.clone(), entry_start_minutes, entry_end_minutes, current_time, col_x, drag_mode));
+                        });
                     }
-                } else {
-                    (false, false)
-                };
 
-                // Check if primary button JUST went down this frame
-                let button_just_pressed = ui.ctx().input(|i| i.pointer.button_pressed(egui::PointerButton::Primary));
+                    // Show appropriate cursor when hovering over entry (not during drag, not when dialog open)
+                    if pointer_over_entry && !in_drag_mode && !dialog_open {
+                        if near_top_edge || near_bottom_edge {
+                            ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeVertical);
+                        } else {
+                            ui.ctx().set_cursor_icon(egui::CursorIcon::Move);
+                        }
+                    }
 
-                // Capture entry when click starts on it - store in memory with press time and drag mode
-                if pointer_over_entry && button_just_pressed && grabbed_state.is_none() {
-                    let drag_mode: u8 = if near_top_edge {
-                        1 // resize-top
-                    } else if near_bottom_edge {
-                        2 // resize-bottom
-                    } else {
-                        0 // move
-                    };
-                    ui.ctx().memory_mut(|mem| {
-                        mem.data.insert_temp(drag_id, (entry.clone(), entry_start_minutes, entry_end_minutes, current_time, col_x, drag_mode));
-                    });
-                }
+                    // Details tooltip once the pointer has rested on the block
+                    if pointer_over_entry && grabbed_state.is_none() && !dialog_open && menu_state.is_none() {
+                        hovered_worklog_id = Some(entry.worklog_id.clone());
+                        let started = *hover_started.entry(entry.worklog_id.clone()).or_insert_with(Instant::now);
+                        let elapsed = started.elapsed();
+                        if elapsed >= SCHEDULE_TOOLTIP_DELAY {
+                            egui::show_tooltip(
+                                ui.ctx(),
+                                ui.layer_id(),
+                                egui::Id::new("schedule_entry_tooltip").with(&entry.worklog_id),
+                                |ui| render_schedule_tooltip(ui, entry, time_format, decimal_precision, clock_format, issue_type_colors),
+                            );
+                        } else {
+                            ui.ctx().request_repaint_after(SCHEDULE_TOOLTIP_DELAY - elapsed);
+                        }
+                    }
 
-                // Show appropriate cursor when hovering over entry (not during drag, not when dialog open)
-                if pointer_over_entry && !in_drag_mode && !dialog_open {
-                    if near_top_edge || near_bottom_edge {
-                        ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeVertical);
-                    } else {
-                        ui.ctx().set_cursor_icon(egui::CursorIcon::Move);
+                    // Right-click opens the context menu at the pointer
+                    if pointer_over_entry && secondary_pressed && grabbed_state.is_none() && !dialog_open {
+                        if let Some(pos) = pointer_pos {
+                            ui.ctx().memory_mut(|mem| mem.data.insert_temp(menu_id, (entry.clone(), pos)));
+                        }
                     }
                 }
             }
-        }
-
-        // Handle grabbed entry (click, drag, or resize)
-        if let Some((entry, original_start_minutes, original_end_minutes, press_time, original_col_x, drag_mode)) = grabbed_state {
-            let primary_down = ui.ctx().input(|i| i.pointer.button_down(egui::PointerButton::Primary));
-            let primary_released = ui.ctx().input(|i| i.pointer.button_released(egui::PointerButton::Primary));
-            let right_clicked = ui.ctx().input(|i| i.pointer.button_pressed(egui::PointerButton::Secondary));
-            let esc_pressed = ui.ctx().input(|i| i.key_pressed(egui::Key::Escape));
-
-            let held_duration = current_time - press_time;
-            let is_long_press = held_duration > long_press_threshold;
-
-            // Get current pointer position
-            let current_pos = ui.ctx().input(|i| i.pointer.latest_pos()).unwrap_or(egui::Pos2::ZERO);
-
-            // Calculate snapped time at current position
-            let snap = snap_interval.minutes();
-            let relative_y = (current_pos.y - grid_rect.min.y).max(0.0);
-            let hover_minutes = start_minutes + (relative_y / pixels_per_minute) as i32;
-            let snapped_minutes = ((hover_minutes + snap / 2) / snap) * snap; // Round to nearest snap interval
-            let snapped_minutes = snapped_minutes.max(start_minutes).min(end_minutes - snap);
-
-            // Calculate new values based on drag mode
-            let min_duration_minutes = 15; // Minimum 15-minute duration
-            let (new_start_minutes, new_end_minutes) = match drag_mode {
-                1 => {
-                    // Resize-top: change start time, keep end fixed
-                    let clamped_start = snapped_minutes.min(original_end_minutes - min_duration_minutes);
-                    (clamped_start, original_end_minutes)
-                }
-                2 => {
-                    // Resize-bottom: keep start fixed, change end time
-                    let clamped_end = snapped_minutes.max(original_start_minutes + min_duration_minutes);
-                    (original_start_minutes, clamped_end)
-                }
-                _ => {
-                    // Move: shift both start and end by same amount
-                    let duration = original_end_minutes - original_start_minutes;
-                    (snapped_minutes, snapped_minutes + duration)
+            hover_started.retain(|id, _| hovered_worklog_id.as_ref() == Some(id));
+
+            // Team members' blocks: dimmed, and no drag, edit or menu
+            for (day_idx, day) in days.iter().enumerate() {
+                let col_x = grid_rect.min.x + hour_label_width + day_idx as f32 * day_width;
+                for (member_idx, (_, entries)) in team.iter().enumerate() {
+                    let member_x = col_x + own_width + member_idx as f32 * member_width;
+                    for entry in entries.iter().filter(|e| e.date == *day) {
+                        let entry_start_minutes = parse_time_to_minutes(&entry.start_time);
+                        let entry_end_minutes = entry_start_minutes + (entry.seconds / 60) as i32;
+                        if entry_end_minutes <= start_minutes || entry_start_minutes >= end_minutes {
+                            continue;
+                        }
+                        let visible_start = entry_start_minutes.max(start_minutes);
+                        let visible_end = entry_end_minutes.min(end_minutes);
+                        let y_start = grid_rect.min.y + (visible_start - start_minutes) as f32 * pixels_per_minute;
+                        let height = (visible_end - visible_start) as f32 * pixels_per_minute;
+                        let block_rect = egui::Rect::from_min_size(
+                            egui::pos2(member_x + 1.0, y_start),
+                            egui::vec2(member_width - 2.0, (height - 2.0).max(min_block_px))
+                        );
+                        // Keep clicks here from creating entries of our own
+                        all_entry_rects.push(block_rect);
+                        ui.scope(|ui| {
+                            ui.set_opacity(0.5);
+                            render_schedule_entry_paint(ui, block_rect, entry, time_format, decimal_precision, colorblind_mode, project_colors, issue_type_colors, label_threshold_px);
+                        });
+                    }
                 }
-            };
+            }
 
-            let new_hour = new_start_minutes / 60;
-            let new_minute = new_start_minutes % 60;
-            let new_start_time = format!("{:02}:{:02}", new_hour, new_minute);
-            let new_duration_seconds = ((new_end_minutes - new_start_minutes) * 60) as i64;
+            if let Some((entry, pos)) = menu_state {
+                let mut close = false;
+                let menu_response = egui::Area::new(menu_id)
+                    .order(egui::Order::Foreground)
+                    .fixed_pos(pos)
+                    .show(ui.ctx(), |ui| {
+                        egui::Frame::menu(ui.style()).show(ui, |ui| {
+                            ui.set_min_width(140.0);
+                            ui.style_mut().spacing.button_padding = egui::vec2(12.0, 8.0);
+                            let item = |ui: &mut Ui, icon: &str, label: &str| {
+                                ui.add(egui::Button::new(RichText::new(format!("{}  {}", icon, label)).size(14.0)).frame(false)).clicked()
+                            };
+
+                            // Always the browser - blocks have no issue key link to click
+                            if item(ui, egui_phosphor::regular::ARROW_SQUARE_OUT, "Open in Jira") {
+                                let _ = open::that(format!("{}/browse/{}", jira_base_url, entry.issue_key));
+                                close = true;
+                            }
+                            if item(ui, egui_phosphor::regular::CLIPBOARD_TEXT, "Copy issue key") {
+                                ui.ctx().copy_text(entry.issue_key.clone());
+                                close = true;
+                            }
+                            if item(ui, egui_phosphor::regular::PENCIL_SIMPLE, "Edit log") {
+                                result.edit_entry = Some(entry.clone());
+                                close = true;
+                            }
+                            if item(ui, egui_phosphor::regular::COPY, "Duplicate log") {
+                                result.duplicate_entry = Some(entry.clone());
+                                close = true;
+                            }
+                            if item(ui, egui_phosphor::regular::TRASH, "Delete log") {
+                                result.delete_entry = Some(entry.clone());
+                                close = true;
+                            }
+                        });
+                    })
+                    .response;
 
-            // Right-click or Esc cancels drag
-            if right_clicked || esc_pressed {
-                ui.ctx().memory_mut(|mem| {
-                    mem.data.remove::<DragState>(drag_id);
+                // Any click outside the menu (or Escape) dismisses it
+                let clicked_outside = ui.ctx().input(|i| {
+                    i.pointer.any_pressed() && i.pointer.interact_pos().is_some_and(|p| !menu_response.rect.contains(p))
                 });
+                if close || clicked_outside || dialog_open || ui.ctx().input(|i| i.key_pressed(egui::Key::Escape)) {
+                    ui.ctx().memory_mut(|mem| mem.data.remove::<(TimeEntry, egui::Pos2)>(menu_id));
+                }
             }
-            // Primary released
-            else if primary_released {
-                // Clear state
-                ui.ctx().memory_mut(|mem| {
-                    mem.data.remove::<DragState>(drag_id);
-                });
 
-                if is_long_press {
-                    match drag_mode {
-                        1 | 2 => {
-                            // Resize complete
-                            if new_start_minutes != original_start_minutes || new_end_minutes != original_end_minutes {
-                                result.drag_resize = Some((entry, new_start_time, new_duration_seconds));
+            // Handle grabbed entry (click, drag, or resize)
+            if let Some((entry, original_start_minutes, original_end_minutes, press_time, original_col_x, drag_mode)) = grabbed_state {
+                let primary_down = ui.ctx().input(|i| i.pointer.button_down(egui::PointerButton::Primary));
+                let primary_released = ui.ctx().input(|i| i.pointer.button_released(egui::PointerButton::Primary));
+                let right_clicked = ui.ctx().input(|i| i.pointer.button_pressed(egui::PointerButton::Secondary));
+                let esc_pressed = ui.ctx().input(|i| i.key_pressed(egui::Key::Escape));
+
+                let held_duration = current_time - press_time;
+                let is_long_press = held_duration > long_press_threshold;
+
+                // Get current pointer position
+                let current_pos = ui.ctx().input(|i| i.pointer.latest_pos()).unwrap_or(egui::Pos2::ZERO);
+
+                // Calculate snapped time at current position
+                let snap = snap_interval.minutes();
+                let relative_y = (current_pos.y - grid_rect.min.y).max(0.0);
+                let hover_minutes = start_minutes + (relative_y / pixels_per_minute) as i32;
+                let snapped_minutes = ((hover_minutes + snap / 2) / snap) * snap; // Round to nearest snap interval
+                // The bottom edge may stretch to the end of the grid; a start must leave room for one snap
+                let latest_minutes = if drag_mode == 2 { end_minutes } else { end_minutes - snap };
+                let snapped_minutes = snapped_minutes.max(start_minutes).min(latest_minutes);
+
+                // Calculate new values based on drag mode
+                let min_duration_minutes = 15; // Minimum 15-minute duration
+                let (new_start_minutes, new_end_minutes) = match drag_mode {
+                    1 => {
+                        // Resize-top: change start time, keep end fixed
+                        let clamped_start = snapped_minutes.min(original_end_minutes - min_duration_minutes);
+                        (clamped_start, original_end_minutes)
+                    }
+                    2 => {
+                        // Resize-bottom: keep start fixed, change end time
+                        let clamped_end = snapped_minutes.max(original_start_minutes + min_duration_minutes);
+                        (original_start_minutes, clamped_end)
+                    }
+                    _ => {
+                        // Move: shift both start and end by same amount
+                        let duration = original_end_minutes - original_start_minutes;
+                        (snapped_minutes, snapped_minutes + duration)
+                    }
+                };
+
+                let new_hour = new_start_minutes / 60;
+                let new_minute = new_start_minutes % 60;
+                let new_start_time = format!("{:02}:{:02}", new_hour, new_minute);
+                let new_duration_seconds = ((new_end_minutes - new_start_minutes) * 60) as i64;
+
+                // Right-click or Esc cancels drag
+                if right_clicked || esc_pressed {
+                    ui.ctx().memory_mut(|mem| {
+                        mem.data.remove::<DragState>(drag_id);
+                    });
+                }
+                // Primary released
+                else if primary_released {
+                    // Clear state
+                    ui.ctx().memory_mut(|mem| {
+                        mem.data.remove::<DragState>(drag_id);
+                    });
+
+                    if is_long_press {
+                        match drag_mode {
+                            1 | 2 => {
+                                // Resize complete
+                                if new_start_minutes != original_start_minutes || new_end_minutes != original_end_minutes {
+                                    result.drag_resize = Some((entry, new_start_time, new_duration_seconds));
+                                }
                             }
-                        }
-                        _ => {
-                            // Move complete
-                            if new_start_minutes != original_start_minutes {
-                                result.drag_move = Some((entry, new_start_time));
+                            _ => {
+                                // Move complete
+                                if new_start_minutes != original_start_minutes {
+                                    result.drag_move = Some((entry, new_start_time));
+                                }
                             }
                         }
+                    } else {
+                        // Quick click = open edit dialog
+                        result.edit_entry = Some(entry);
                     }
-                } else {
-                    // Quick click = open edit dialog
-                    result.edit_entry = Some(entry);
                 }
-            }
-            // Still holding - render drag preview if past threshold
-            else if primary_down && is_long_press {
-                // Set appropriate cursor (unless dialog is open)
-                if !dialog_open {
-                    match drag_mode {
-                        1 | 2 => ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeVertical),
-                        _ => ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing),
+                // Still holding - render drag preview if past threshold
+                else if primary_down && is_long_press {
+                    // Set appropriate cursor (unless dialog is open)
+                    if !dialog_open {
+                        match drag_mode {
+                            1 | 2 => ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeVertical),
+                            _ => ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing),
+                        }
                     }
-                }
-
-                // Calculate ghost rect at new position
-                let ghost_y = grid_rect.min.y + (new_start_minutes - start_minutes) as f32 * pixels_per_minute;
-                let ghost_height = (new_end_minutes - new_start_minutes) as f32 * pixels_per_minute;
 
-                let block_margin = 2.0;
-                let ghost_rect = egui::Rect::from_min_size(
-                    egui::pos2(original_col_x + block_margin, ghost_y),
-                    egui::vec2(day_width - block_margin * 2.0, (ghost_height - 2.0).max(20.0))
-                );
+                    // Calculate ghost rect at new position
+                    let ghost_rect = drag_ghost_rect(
+                        drag_mode,
+                        original_start_minutes,
+                        (new_start_minutes, new_end_minutes),
+                        (start_minutes, end_minutes),
+                        grid_rect.min.y,
+                        pixels_per_minute,
+                        original_col_x,
+                        own_width,
+                        min_block_px,
+                    );
 
-                // Render the entry at the new position (as ghost)
-                // For bottom-edge resize, show duration; otherwise show start time
-                let display_text = if drag_mode == 2 {
-                    format_duration_with_format(new_duration_seconds, time_format)
-                } else {
-                    new_start_time.clone()
-                };
-                render_schedule_entry_ghost(ui, ghost_rect, &entry, time_format, &display_text);
+                    // Render the entry at the new position (as ghost)
+                    // For bottom-edge resize, show duration; otherwise show start time
+                    let display_text = if drag_mode == 2 {
+                        format_duration_with_format(new_duration_seconds, time_format, decimal_precision)
+                    } else {
+                        new_start_time.clone()
+                    };
+                    render_schedule_entry_ghost(ui, ghost_rect, &entry, time_format, &display_text, colorblind_mode, project_colors);
+                }
             }
-        }
 
-        // Second pass: handle column interactions (only for empty space)
-        // Check if we're currently dragging an existing entry (from memory)
-        let is_dragging_entry: bool = ui.ctx().memory(|mem| {
-            mem.data.get_temp::<DragState>(drag_id).is_some()
-        }) && in_drag_mode;
+            // Second pass: handle column interactions (only for empty space)
+            // Check if we're currently dragging an existing entry (from memory)
+            let is_dragging_entry: bool = ui.ctx().memory(|mem| {
+                mem.data.get_temp::<DragState>(drag_id).is_some()
+            }) && in_drag_mode;
 
-        for (day_idx, day) in days.iter().enumerate() {
-            let col_x = grid_rect.min.x + hour_label_width + day_idx as f32 * day_width;
+            for (day_idx, day) in days.iter().enumerate() {
+                let col_x = grid_rect.min.x + hour_label_width + day_idx as f32 * day_width;
 
-            // Check if pointer is over any entry in this column
-            let pointer_pos = ui.ctx().pointer_hover_pos();
-            let over_entry = pointer_pos.map(|pos| {
-                all_entry_rects.iter().any(|r| r.contains(pos))
-            }).unwrap_or(false);
+                // Check if pointer is over any entry in this column
+                let pointer_pos = ui.ctx().pointer_hover_pos();
+                let over_entry = pointer_pos.map(|pos| {
+                    all_entry_rects.iter().any(|r| r.contains(pos))
+                }).unwrap_or(false);
 
-            // Handle interactions on empty space
-            let col_rect = egui::Rect::from_min_size(
-                egui::pos2(col_x, grid_rect.min.y),
-                egui::vec2(day_width, total_grid_height)
-            );
+                // Handle interactions on empty space (our own sub-column only)
+                let col_rect = egui::Rect::from_min_size(
+                    egui::pos2(col_x, grid_rect.min.y),
+                    egui::vec2(own_width, total_grid_height)
+                );
 
-            let col_response = ui.interact(col_rect, ui.id().with(("day_col", day_idx)), egui::Sense::click_and_drag());
+                let col_response = ui.interact(col_rect, ui.id().with(("day_col", day_idx)), egui::Sense::click_and_drag());
+
+                // Track hover position for ghost preview (only if not over an entry AND not dragging an existing entry)
+                if col_response.hovered() && !over_entry && !is_dragging_entry {
+                    if let Some(pos) = ui.ctx().pointer_hover_pos() {
+                        if pos.y >= grid_rect.min.y && pos.y <= grid_rect.max.y {
+                            let snap = snap_interval.minutes();
+                            let relative_y = pos.y - grid_rect.min.y;
+                            let hover_minutes = start_minutes + (relative_y / pixels_per_minute) as i32;
+                            // Snap to configured interval
+                            let snapped_minutes = ((hover_minutes + snap / 2) / snap) * snap;
+                            let hour = snapped_minutes / 60;
+                            let snapped_minute = snapped_minutes % 60;
+                            let ghost_time = format!("{:02}:{:02}", hour, snapped_minute);
+
+                            // Check if ghost would overlap existing entries (1 hour = 60 mins)
+                            let day_entries = week_data.entries_for_day(*day);
+                            if !check_time_overlap(&day_entries, &ghost_time, 60) {
+                                result.ghost_position = Some((*day, ghost_time.clone()));
+
+                                // Render the ghost preview
+                                let ghost_start_minutes = hour * 60 + snapped_minute;
+                                let ghost_y = grid_rect.min.y + (ghost_start_minutes - start_minutes) as f32 * pixels_per_minute;
+                                let ghost_height = 60.0 * pixels_per_minute; // 1 hour
+
+                                let ghost_rect = egui::Rect::from_min_size(
+                                    egui::pos2(col_x + 2.0, ghost_y),
+                                    egui::vec2(own_width - 4.0, ghost_height)
+                                );
+
+                                // Draw translucent ghost block
+                                let ghost_color = Color32::from_rgba_unmultiplied(0x61, 0xAF, 0xEF, 60);
+                                let ghost_border = Color32::from_rgba_unmultiplied(0x61, 0xAF, 0xEF, 120);
+                                ui.painter().rect(ghost_rect, 4.0, ghost_color, egui::Stroke::new(1.0, ghost_border));
+
+                                // Ghost label
+                                let ghost_label = format!("{} + 1h", ghost_time);
+                                ui.painter().text(
+                                    ghost_rect.center(),
+                                    egui::Align2::CENTER_CENTER,
+                                    ghost_label,
+                                    egui::FontId::proportional(13.0),
+                                    Color32::from_rgba_unmultiplied(255, 255, 255, 150),
+                                );
+                            }
+                        }
+                    }
+                }
+
+                // Single-click on ghost creates entry
+                // Note: we check ghost_position regardless of over_entry because entry visual rects
+                // can extend beyond their time bounds (min height), causing false positives
+                if col_response.clicked() {
+                    if let Some((ghost_day, ref time)) = result.ghost_position {
+                        if *day == ghost_day {
+                            result.ghost_clicked = true;
+                            result.add_at = Some((*day, time.clone()));
+                        }
+                    }
+                }
 
-            // Track hover position for ghost preview (only if not over an entry AND not dragging an existing entry)
-            if col_response.hovered() && !over_entry && !is_dragging_entry {
-                if let Some(pos) = ui.ctx().pointer_hover_pos() {
-                    if pos.y >= grid_rect.min.y && pos.y <= grid_rect.max.y {
+                // Double-click on empty space creates entry (not over existing entry)
+                if col_response.double_clicked() && !over_entry {
+                    if let Some(pos) = col_response.interact_pointer_pos() {
                         let snap = snap_interval.minutes();
                         let relative_y = pos.y - grid_rect.min.y;
-                        let hover_minutes = start_minutes + (relative_y / pixels_per_minute) as i32;
+                        let clicked_minutes = start_minutes + (relative_y / pixels_per_minute) as i32;
                         // Snap to configured interval
-                        let snapped_minutes = ((hover_minutes + snap / 2) / snap) * snap;
+                        let snapped_minutes = ((clicked_minutes + snap / 2) / snap) * snap;
                         let hour = snapped_minutes / 60;
                         let snapped_minute = snapped_minutes % 60;
-                        let ghost_time = format!("{:02}:{:02}", hour, snapped_minute);
+                        let start_time = format!("{:02}:{:02}", hour, snapped_minute);
+                        result.add_at = Some((*day, start_time));
+                    }
+                }
+            }
 
-                        // Check if ghost would overlap existing entries (1 hour = 60 mins)
-                        let day_entries = week_data.entries_for_day(*day);
-                        if !check_time_overlap(&day_entries, &ghost_time, 60) {
-                            result.ghost_position = Some((*day, ghost_time.clone()));
+            // Hour labels - painted last and pinned to the visible left edge so they stay put
+            // while the day columns scroll horizontally
+            let label_left = grid_rect.min.x.max(ui.clip_rect().min.x);
+            let label_rect = egui::Rect::from_min_size(
+                egui::pos2(label_left, grid_rect.min.y),
+                egui::vec2(hour_label_width, total_grid_height)
+            );
+            if label_left > grid_rect.min.x {
+                ui.painter().rect_filled(label_rect, 0.0, ui.visuals().panel_fill);
+            }
+            let cold = Color32::from_rgb(0x70, 0x70, 0x68);  // Darker gray for less prominence
+            let warm = Color32::from_rgb(0xc8, 0xc8, 0xb4);
+            for hour_idx in 0..num_hours {
+                let hour = schedule_start_hour + hour_idx as u8;
+                let y = grid_rect.min.y + hour_idx as f32 * hour_height;
+                let heat = hour_heat.map(|h| h[hour as usize % 24]).unwrap_or(0.0);
+
+                // Faint warm band behind busy hours
+                if heat > 0.0 {
+                    let band = egui::Rect::from_min_size(
+                        egui::pos2(label_rect.min.x, y),
+                        egui::vec2(hour_label_width, hour_height)
+                    );
+                    ui.painter().rect_filled(band, 0.0, warm.gamma_multiply(heat * 0.06));
+                }
 
-                            // Render the ghost preview
-                            let ghost_start_minutes = hour * 60 + snapped_minute;
-                            let ghost_y = grid_rect.min.y + (ghost_start_minutes - start_minutes) as f32 * pixels_per_minute;
-                            let ghost_height = 60.0 * pixels_per_minute; // 1 hour
+                let hour_text = format_clock_time(&format!("{:02}:00", hour), clock_format);
+                ui.painter().text(
+                    egui::pos2(label_rect.max.x - 8.0, y),
+                    egui::Align2::RIGHT_TOP,
+                    &hour_text,
+                    egui::FontId::proportional(11.0),  // Smaller font for axis labels
+                    lerp_color(cold, warm, heat),
+                );
+            }
+        }); // end ScrollArea
+    }); // end horizontal ScrollArea
 
-                            let ghost_rect = egui::Rect::from_min_size(
-                                egui::pos2(col_x + 2.0, ghost_y),
-                                egui::vec2(day_width - 4.0, ghost_height)
-                            );
+    result
+}
 
-                            // Draw translucent ghost block
-                            let ghost_color = Color32::from_rgba_unmultiplied(0x61, 0xAF, 0xEF, 60);
-                            let ghost_border = Color32::from_rgba_unmultiplied(0x61, 0xAF, 0xEF, 120);
-                            ui.painter().rect(ghost_rect, 4.0, ghost_color, egui::Stroke::new(1.0, ghost_border));
-
-                            // Ghost label
-                            let ghost_label = format!("{} + 1h", ghost_time);
-                            ui.painter().text(
-                                ghost_rect.center(),
-                                egui::Align2::CENTER_CENTER,
-                                ghost_label,
-                                egui::FontId::proportional(13.0),
-                                Color32::from_rgba_unmultiplied(255, 255, 255, 150),
-                            );
-                        }
-                    }
+/// Result from month view interactions
+#[derive(Default)]
+pub struct MonthResult {
+    pub clicked_day: Option<NaiveDate>,
+    pub month_delta: i32,  // -1 / +1 when the previous / next month arrow was clicked
+}
+
+/// Month calendar where each day's background deepens with its logged time, like a contribution graph:
+/// empty days stay muted, days at or past `target_seconds` get the full accent color
+/// `day_seconds` gives the logged time for any date; `month` is the first of the month
+#[allow(clippy::too_many_arguments)]
+pub fn render_month_heatmap(
+    ui: &mut Ui,
+    month: NaiveDate,
+    day_seconds: impl Fn(NaiveDate) -> i64,
+    week_start_day: Weekday,
+    target_seconds: i64,
+    time_format: TimeFormat,
+    decimal_precision: u8,
+    loading: bool,
+) -> MonthResult {
+    let mut result = MonthResult::default();
+    let today = Local::now().date_naive();
+    let next_month = month.checked_add_months(chrono::Months::new(1)).unwrap_or(month);
+    let days_in_month = (next_month - month).num_days();
+    let month_total: i64 = (0..days_in_month).map(|i| day_seconds(month + Duration::days(i))).sum();
+
+    let secondary_color = Color32::from_rgb(150, 150, 150);
+    ui.horizontal(|ui| {
+        if ui.add(egui::Button::new(RichText::new(egui_phosphor::regular::CARET_LEFT).size(16.0)).frame(false)).on_hover_text("Previous month").clicked() {
+            result.month_delta = -1;
+        }
+        ui.label(RichText::new(month.format("%B %Y").to_string()).size(17.0).color(Color32::WHITE).family(super::theme::bold_family()));
+        if ui.add(egui::Button::new(RichText::new(egui_phosphor::regular::CARET_RIGHT).size(16.0)).frame(false)).on_hover_text("Next month").clicked() {
+            result.month_delta = 1;
+        }
+        ui.add_space(12.0);
+        if loading {
+            ui.spinner();
+        } else if month_total > 0 {
+            ui.label(RichText::new(format!("{} this month", format_duration_with_format(month_total, time_format, decimal_precision)))
+                .size(14.0)
+                .color(secondary_color));
+        }
+    });
+    ui.add_space(8.0);
+
+    let gap = 4.0;
+    let cell_width = ((ui.available_width() - gap * 6.0) / 7.0).max(36.0);
+    let cell_height = 56.0;
+    let accent = Color32::from_rgb(0x13, 0x98, 0xf4);
+    let empty_bg = Color32::from_rgb(0x1c, 0x1c, 0x1a);
+
+    // Weekday names, starting from the configured first day
+    let first_column = week_start(month, week_start_day);
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = gap;
+        for i in 0..7 {
+            let name = (first_column + Duration::days(i)).format("%a").to_string();
+            let (rect, _) = ui.allocate_exact_size(egui::vec2(cell_width, 18.0), egui::Sense::hover());
+            ui.painter().text(rect.center(), egui::Align2::CENTER_CENTER, name, egui::FontId::proportional(12.0), secondary_color);
+        }
+    });
+
+    // Whole weeks from the one containing the 1st to the one containing the last day
+    let last_day = next_month - Duration::days(1);
+    let weeks = (week_start(last_day, week_start_day) - first_column).num_days() / 7 + 1;
+    ui.spacing_mut().item_spacing.y = gap;
+    for week in 0..weeks {
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = gap;
+            for i in 0..7 {
+                let day = first_column + Duration::days(week * 7 + i);
+                let in_month = day >= month && day < next_month;
+                let sense = if in_month { egui::Sense::click() } else { egui::Sense::hover() };
+                let (rect, response) = ui.allocate_exact_size(egui::vec2(cell_width, cell_height), sense);
+                if !in_month || !ui.is_rect_visible(rect) {
+                    continue;
                 }
-            }
 
-            // Single-click on ghost creates entry
-            // Note: we check ghost_position regardless of over_entry because entry visual rects
-            // can extend beyond their time bounds (min height), causing false positives
-            if col_response.clicked() {
-                if let Some((ghost_day, ref time)) = result.ghost_position {
-                    if *day == ghost_day {
-                        result.ghost_clicked = true;
-                        result.add_at = Some((*day, time.clone()));
-                    }
+                let seconds = day_seconds(day);
+                let intensity = if target_seconds > 0 { (seconds as f32 / target_seconds as f32).clamp(0.0, 1.0) } else { 0.0 };
+                let bg = if seconds > 0 {
+                    // Start a little above empty so short days still read as logged
+                    let t = 0.2 + intensity * 0.8;
+                    let mix = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t) as u8;
+                    Color32::from_rgb(mix(empty_bg.r(), accent.r()), mix(empty_bg.g(), accent.g()), mix(empty_bg.b(), accent.b()))
+                } else {
+                    empty_bg
+                };
+                let stroke = if day == today {
+                    egui::Stroke::new(1.5, Color32::WHITE)
+                } else if response.hovered() {
+                    egui::Stroke::new(1.0, Color32::from_rgb(0x50, 0x50, 0x4a))
+                } else {
+                    egui::Stroke::NONE
+                };
+                let painter = ui.painter();
+                painter.rect(rect, 6.0, bg, stroke);
+                let text_color = if seconds > 0 { Color32::WHITE } else { Color32::from_rgb(112, 112, 104) };
+                painter.text(rect.min + egui::vec2(6.0, 5.0), egui::Align2::LEFT_TOP, day.day().to_string(), egui::FontId::proportional(13.0), text_color);
+                if seconds > 0 {
+                    painter.text(
+                        egui::pos2(rect.center().x, rect.max.y - 8.0),
+                        egui::Align2::CENTER_BOTTOM,
+                        format_duration_with_format(seconds, time_format, decimal_precision),
+                        egui::FontId::new(13.0, super::theme::bold_family()),
+                        Color32::WHITE,
+                    );
                 }
-            }
 
-            // Double-click on empty space creates entry (not over existing entry)
-            if col_response.double_clicked() && !over_entry {
-                if let Some(pos) = col_response.interact_pointer_pos() {
-                    let snap = snap_interval.minutes();
-                    let relative_y = pos.y - grid_rect.min.y;
-                    let clicked_minutes = start_minutes + (relative_y / pixels_per_minute) as i32;
-                    // Snap to configured interval
-                    let snapped_minutes = ((clicked_minutes + snap / 2) / snap) * snap;
-                    let hour = snapped_minutes / 60;
-                    let snapped_minute = snapped_minutes % 60;
-                    let start_time = format!("{:02}:{:02}", hour, snapped_minute);
-                    result.add_at = Some((*day, start_time));
+                if response.hovered() {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                }
+                if response.clicked() {
+                    result.clicked_day = Some(day);
                 }
             }
+        });
+    }
+
+    result
+}
+
+/// Logged minutes per hour of day across `weeks`, normalized so the busiest hour is 1.0
+pub fn compute_hour_heatmap(weeks: &[WeekData]) -> [f32; 24] {
+    let mut minutes = [0.0f32; 24];
+    for entry in weeks.iter().flat_map(|w| w.entries.iter()) {
+        let start = parse_time_to_minutes(&entry.start_time);
+        let end = (start + (entry.seconds / 60) as i32).min(24 * 60);
+        // Spread the entry over the hours it covers
+        let mut t = start.max(0);
+        while t < end {
+            let hour_end = (t / 60 + 1) * 60;
+            let slice_end = hour_end.min(end);
+            minutes[(t / 60) as usize] += (slice_end - t) as f32;
+            t = slice_end;
         }
-    }); // end ScrollArea
+    }
+
+    let max = minutes.iter().cloned().fold(0.0, f32::max);
+    if max > 0.0 {
+        for value in &mut minutes {
+            *value /= max;
+        }
+    }
+    minutes
+}
+
+/// Linear blend between two colors (t in 0.0-1.0)
+fn lerp_color(from: Color32, to: Color32, t: f32) -> Color32 {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgb(mix(from.r(), to.r()), mix(from.g(), to.g()), mix(from.b(), to.b()))
+}
+
+/// Split a day column between our entries and `members` team sub-columns: (own width, width per member)
+/// Each member gets 20-30% of the column while we always keep the largest share
+fn team_column_widths(day_width: f32, members: usize) -> (f32, f32) {
+    if members == 0 {
+        return (day_width, 0.0);
+    }
+    let member_share = (1.0 / (members as f32 + 1.5)).min(0.3);
+    let member_width = day_width * member_share;
+    (day_width - member_width * members as f32, member_width)
+}
+
+/// Rect for a block being dragged, laid out like the blocks in the grid
+/// Resize-bottom keeps the top pinned at the original start so only the bottom edge follows the pointer
+#[allow(clippy::too_many_arguments)]
+fn drag_ghost_rect(
+    drag_mode: u8,
+    original_start_minutes: i32,
+    (new_start_minutes, new_end_minutes): (i32, i32),
+    (start_minutes, end_minutes): (i32, i32),
+    grid_top: f32,
+    pixels_per_minute: f32,
+    col_x: f32,
+    day_width: f32,
+    min_block_px: f32,
+) -> egui::Rect {
+    let top_minutes = if drag_mode == 2 { original_start_minutes } else { new_start_minutes };
+
+    // Clamp to the visible range, as for regular blocks
+    let visible_start = top_minutes.max(start_minutes);
+    let visible_end = new_end_minutes.min(end_minutes);
+
+    let y_start = grid_top + (visible_start - start_minutes) as f32 * pixels_per_minute;
+    let height = (visible_end - visible_start) as f32 * pixels_per_minute;
+
+    let block_margin = 2.0;
+    egui::Rect::from_min_size(
+        egui::pos2(col_x + block_margin, y_start),
+        egui::vec2(day_width - block_margin * 2.0, (height - 2.0).max(min_block_px))
+    )
+}
 
+/// Lay out `[start, end)` minute intervals like a calendar: each entry gets
+/// `(lane, lane_count)`, where `lane_count` is the most entries running at once
+/// in its overlap group. Returned in input order.
+fn overlap_lanes(intervals: &[(i32, i32)]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| intervals[i]);
+
+    let mut result = vec![(0, 1); intervals.len()];
+    let mut group: Vec<usize> = Vec::new();
+    let mut lane_ends: Vec<i32> = Vec::new();
+    let mut group_end = i32::MIN;
+
+    for i in order {
+        let (start, end) = intervals[i];
+        // Nothing in the open group reaches this entry: close it out
+        if start >= group_end {
+            for &g in &group {
+                result[g].1 = lane_ends.len();
+            }
+            group.clear();
+            lane_ends.clear();
+        }
+        // First lane that is free by now, or a new one
+        let lane = match lane_ends.iter().position(|&lane_end| lane_end <= start) {
+            Some(lane) => {
+                lane_ends[lane] = end;
+                lane
+            }
+            None => {
+                lane_ends.push(end);
+                lane_ends.len() - 1
+            }
+        };
+        result[i].0 = lane;
+        group.push(i);
+        group_end = if group.len() == 1 { end } else { group_end.max(end) };
+    }
+    for &g in &group {
+        result[g].1 = lane_ends.len();
+    }
     result
 }
 
@@ -1423,44 +2809,108 @@ fn check_time_overlap(
     false
 }
 
+/// Hover details for a schedule block, which is usually too small to show them
+fn render_schedule_tooltip(ui: &mut Ui, entry: &TimeEntry, time_format: TimeFormat, decimal_precision: u8, clock_format: ClockFormat, issue_type_colors: &HashMap<String, [u8; 3]>) {
+    let (content_bg, frame_color, _) = super::theme::dialog_colors();
+    let (_, text_color, secondary_color) = entry_colors();
+
+    egui::Frame::none()
+        .fill(content_bg)
+        .stroke(egui::Stroke::new(1.0, frame_color))
+        .rounding(egui::Rounding::same(6.0))
+        .inner_margin(egui::Margin::same(10.0))
+        .show(ui, |ui| {
+            ui.set_max_width(300.0);
+            ui.spacing_mut().item_spacing.y = 4.0;
+
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 6.0;
+                render_issue_type_icon(ui, issue_type_icon(&entry.issue_type, issue_type_colors), 12.0);
+                ui.label(RichText::new(&entry.issue_key).size(14.0).color(Color32::WHITE).family(super::theme::bold_family()));
+            });
+
+            if !entry.issue_summary.is_empty() {
+                ui.add(egui::Label::new(RichText::new(&entry.issue_summary).size(14.0).color(text_color)).wrap());
+            }
+
+            let start_minutes = parse_time_to_minutes(&entry.start_time);
+            let end_minutes = start_minutes + (entry.seconds / 60) as i32;
+            let end_time = format!("{:02}:{:02}", (end_minutes / 60) % 24, end_minutes % 60);
+            ui.label(RichText::new(format!(
+                "{}  ·  {} – {}",
+                format_duration_with_format(entry.seconds, time_format, decimal_precision),
+                format_clock_time(&entry.start_time, clock_format),
+                format_clock_time(&end_time, clock_format),
+            )).size(14.0).color(text_color));
+
+            let description = entry.description.trim();
+            if !description.is_empty() {
+                let truncated: String = if description.chars().count() > 150 {
+                    format!("{}…", description.chars().take(150).collect::<String>().trim_end())
+                } else {
+                    description.to_string()
+                };
+                ui.add(egui::Label::new(RichText::new(truncated).size(14.0).color(secondary_color)).wrap());
+            }
+        });
+}
+
+/// Closed outline of a rounded rect, as points for `Shape::dashed_line` (clockwise from the top edge)
+fn rounded_rect_outline(rect: egui::Rect, radius: f32) -> Vec<egui::Pos2> {
+    const ARC_STEPS: usize = 4;
+    let r = radius.min(rect.width() / 2.0).min(rect.height() / 2.0);
+    // Each corner's arc center and starting angle (screen y points down)
+    let corners = [
+        (egui::pos2(rect.max.x - r, rect.min.y + r), -90.0_f32),
+        (egui::pos2(rect.max.x - r, rect.max.y - r), 0.0),
+        (egui::pos2(rect.min.x + r, rect.max.y - r), 90.0),
+        (egui::pos2(rect.min.x + r, rect.min.y + r), 180.0),
+    ];
+    let mut points = Vec::with_capacity(4 * (ARC_STEPS + 1) + 1);
+    for (center, start) in corners {
+        for step in 0..=ARC_STEPS {
+            let angle = (start + 90.0 * step as f32 / ARC_STEPS as f32).to_radians();
+            points.push(center + r * egui::vec2(angle.cos(), angle.sin()));
+        }
+    }
+    points.push(points[0]);
+    points
+}
+
 /// Paint a single entry block in the schedule view (no interaction - that's handled by caller)
+#[allow(clippy::too_many_arguments)]
 fn render_schedule_entry_paint(
     ui: &mut Ui,
     rect: egui::Rect,
     entry: &crate::api::TimeEntry,
     time_format: TimeFormat,
+    decimal_precision: u8,
+    colorblind_mode: ColorblindMode,
+    project_colors: &[(String, [u8; 3])],
+    issue_type_colors: &HashMap<String, [u8; 3]>,
+    label_threshold_px: f32,  // Shorter blocks show only the stripe and background
 ) {
     let painter = ui.painter();
 
     // Accent color based on ticket type
-    let accent_color = if entry.issue_key.starts_with("TIM-") {
-        let summary_upper = entry.issue_summary.to_uppercase();
-        if summary_upper.contains("MEETING") {
-            Color32::from_rgb(0xe8, 0x28, 0x71)  // Pink/magenta
-        } else if summary_upper.contains("SUPPORT") {
-            Color32::from_rgb(0xec, 0x71, 0x1b)  // Orange
-        } else if summary_upper.contains("ADMIN") {
-            Color32::from_rgb(0xe5, 0xaa, 0x00)  // Yellow/gold
-        } else {
-            Color32::from_rgb(0x13, 0x98, 0xf4)  // Blue
-        }
-    } else {
-        Color32::from_rgb(0x13, 0x98, 0xf4)  // Blue for regular tickets
-    };
+    let bucket = bucket_kind(&entry.issue_key, &entry.issue_summary, project_colors);
+    let accent_color = accent_color_for_entry(entry, project_colors, colorblind_mode);
 
     // Draw block background
     let block_bg = Color32::from_rgb(0x1c, 0x1c, 0x1a);
     let corner_radius = 4.0;
 
-    painter.rect(
-        rect,
-        corner_radius,
-        block_bg,
-        egui::Stroke::new(1.0, accent_color),
-    );
+    // Issues well past their estimate get a dashed orange border instead of the solid accent one
+    let overrun = estimate_overrun(entry).is_some();
+    let border = if overrun { egui::Stroke::NONE } else { egui::Stroke::new(1.0, accent_color) };
+    painter.rect(rect, corner_radius, block_bg, border);
+    if overrun {
+        let outline = rounded_rect_outline(rect, corner_radius);
+        painter.extend(egui::Shape::dashed_line(&outline, egui::Stroke::new(1.5, Color32::from_rgb(0xf0, 0x8c, 0x30)), 4.0, 3.0));
+    }
 
-    // Left accent stripe
-    let accent_width = 3.0;
+    // Left accent stripe (wider when a colorblind mode draws a pattern on it)
+    let accent_width = if colorblind_mode == ColorblindMode::None { 3.0 } else { 6.0 };
     let accent_rect = egui::Rect::from_min_size(
         rect.min,
         egui::vec2(accent_width, rect.height())
@@ -1476,6 +2926,7 @@ fn render_schedule_entry_paint(
         accent_color,
         egui::Stroke::NONE,
     );
+    paint_bucket_pattern(painter, accent_rect, bucket, colorblind_mode);
 
     // Text content
     let text_left = rect.min.x + accent_width + 4.0;
@@ -1484,10 +2935,10 @@ fn render_schedule_entry_paint(
     let key_font = egui::FontId::proportional(font_size);
 
     // Get issue type icon info using shared function
-    let icon_style = issue_type_icon(&entry.issue_type);
+    let icon_style = issue_type_icon(&entry.issue_type, issue_type_colors);
 
     // First line: Icon + Issue key + Duration (all on same line)
-    if rect.height() > 20.0 {
+    if rect.height() >= label_threshold_px {
         let line_y = rect.min.y + 12.0;
         let mut x = text_left;
 
@@ -1520,8 +2971,16 @@ fn render_schedule_entry_paint(
         painter.galley(egui::pos2(x, line_y - key_galley.size().y / 2.0), key_galley.clone(), Color32::WHITE);
         x += key_galley.size().x + 6.0;
 
+        // Status pill, only when there's room left for it
+        if let Some(status_color) = status_pill_color(&entry.issue_status) {
+            let pill_width = painter.layout_no_wrap(entry.issue_status.to_uppercase(), egui::FontId::proportional(9.0), Color32::WHITE).size().x + 10.0;
+            if x + pill_width < rect.max.x - 24.0 {
+                x += paint_status_pill(painter, egui::pos2(x, line_y), &entry.issue_status, status_color, 9.0) + 6.0;
+            }
+        }
+
         // Duration - bright white bold for times to stand out
-        let duration_text = crate::api::format_duration_with_format(entry.seconds, time_format);
+        let duration_text = card_duration_text(entry.seconds, time_format, decimal_precision);
         let dur_font = egui::FontId::new(key_font.size, super::theme::bold_family());
         let dur_galley = painter.layout_no_wrap(duration_text, dur_font, Color32::WHITE);
         // Only show duration if it fits (leave room for dots menu)
@@ -1532,6 +2991,30 @@ fn render_schedule_entry_paint(
     }
 }
 
+/// Sample schedule blocks at each height, for previewing the block height settings
+pub fn render_schedule_block_preview(ui: &mut Ui, heights: &[f32], label_threshold_px: f32, colorblind_mode: ColorblindMode, project_colors: &[(String, [u8; 3])]) {
+    let sample = TimeEntry {
+        worklog_id: String::new(),
+        issue_key: "PROJ-123".to_string(),
+        issue_summary: String::new(),
+        issue_type: "Task".to_string(),
+        issue_status: String::new(),
+        seconds: 900,
+        description: String::new(),
+        date: Local::now().date_naive(),
+        start_time: String::new(),
+        time_spent_total: 0,
+        time_original_estimate: 0,
+        custom_properties: HashMap::new(),
+    };
+    ui.horizontal_top(|ui| {
+        for &height in heights {
+            let (rect, _) = ui.allocate_exact_size(egui::vec2(120.0, height), egui::Sense::hover());
+            render_schedule_entry_paint(ui, rect, &sample, TimeFormat::HoursMinutes, 2, colorblind_mode, project_colors, &HashMap::new(), label_threshold_px);
+        }
+    });
+}
+
 /// Render an entry as a ghost (semi-transparent) during drag
 /// display_text is either the new start time or the new duration depending on drag mode
 fn render_schedule_entry_ghost(
@@ -1540,25 +3023,16 @@ fn render_schedule_entry_ghost(
     entry: &crate::api::TimeEntry,
     _time_format: TimeFormat,
     display_text: &str,
+    colorblind_mode: ColorblindMode,
+    project_colors: &[(String, [u8; 3])],
 ) {
     let painter = ui.painter();
     let alpha = 180; // Semi-transparent
 
     // Accent color based on ticket type (same logic as paint version)
-    let accent_color = if entry.issue_key.starts_with("TIM-") {
-        let summary_upper = entry.issue_summary.to_uppercase();
-        if summary_upper.contains("MEETING") {
-            Color32::from_rgba_unmultiplied(0xe8, 0x28, 0x71, alpha)
-        } else if summary_upper.contains("SUPPORT") {
-            Color32::from_rgba_unmultiplied(0xec, 0x71, 0x1b, alpha)
-        } else if summary_upper.contains("ADMIN") {
-            Color32::from_rgba_unmultiplied(0xe5, 0xaa, 0x00, alpha)
-        } else {
-            Color32::from_rgba_unmultiplied(0x13, 0x98, 0xf4, alpha)
-        }
-    } else {
-        Color32::from_rgba_unmultiplied(0x13, 0x98, 0xf4, alpha)
-    };
+    let bucket = bucket_kind(&entry.issue_key, &entry.issue_summary, project_colors);
+    let solid = accent_color_for_entry(entry, project_colors, colorblind_mode);
+    let accent_color = Color32::from_rgba_unmultiplied(solid.r(), solid.g(), solid.b(), alpha);
 
     // Draw block background
     let block_bg = Color32::from_rgba_unmultiplied(0x1c, 0x1c, 0x1a, alpha);
@@ -1571,8 +3045,8 @@ fn render_schedule_entry_ghost(
         egui::Stroke::new(2.0, accent_color), // Thicker border for ghost
     );
 
-    // Left accent stripe
-    let accent_width = 3.0;
+    // Left accent stripe (wider when a colorblind mode draws a pattern on it)
+    let accent_width = if colorblind_mode == ColorblindMode::None { 3.0 } else { 6.0 };
     let accent_rect = egui::Rect::from_min_size(
         rect.min,
         egui::vec2(accent_width, rect.height())
@@ -1588,6 +3062,7 @@ fn render_schedule_entry_ghost(
         accent_color,
         egui::Stroke::NONE,
     );
+    paint_bucket_pattern(painter, accent_rect, bucket, colorblind_mode);
 
     // Text content - always show, centered vertically
     let text_left = rect.min.x + accent_width + 4.0;
@@ -1615,3 +3090,202 @@ fn render_schedule_entry_ghost(
         painter.galley(egui::pos2(x, line_y - key_galley.size().y / 2.0), key_galley, Color32::WHITE);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Grid from 8:00 to 18:00 at 1px per minute, column at x=100 and 200px wide
+    const GRID: (i32, i32) = (8 * 60, 18 * 60);
+
+    fn ghost(drag_mode: u8, original_start: i32, new_range: (i32, i32)) -> egui::Rect {
+        drag_ghost_rect(drag_mode, original_start, new_range, GRID, 50.0, 1.0, 100.0, 200.0, 16.0)
+    }
+
+    fn entry(seconds: i64, description: &str) -> TimeEntry {
+        TimeEntry {
+            worklog_id: String::new(),
+            issue_key: "PROJ-1".to_string(),
+            issue_summary: String::new(),
+            issue_type: "Task".to_string(),
+            issue_status: String::new(),
+            seconds,
+            description: description.to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 10, 12).unwrap(),
+            start_time: "09:00".to_string(),
+            time_spent_total: 0,
+            time_original_estimate: 0,
+            custom_properties: HashMap::new(),
+        }
+    }
+
+    fn with_worklog_id(mut entry: TimeEntry, worklog_id: &str, date: NaiveDate) -> TimeEntry {
+        entry.worklog_id = worklog_id.to_string();
+        entry.date = date;
+        entry
+    }
+
+    #[test]
+    fn merge_skips_worklogs_already_present() {
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let mut week = WeekData { week_start: monday, entries: vec![with_worklog_id(entry(3600, "First"), "1", monday)] };
+        week.merge(WeekData {
+            week_start: monday,
+            entries: vec![
+                with_worklog_id(entry(3600, "First again"), "1", monday),
+                with_worklog_id(entry(1800, "Second"), "2", monday),
+            ],
+        });
+        let ids: Vec<&str> = week.entries.iter().map(|e| e.worklog_id.as_str()).collect();
+        assert_eq!(ids, ["1", "2"]);
+        assert_eq!(week.entries[0].description, "First");
+    }
+
+    #[test]
+    fn total_seconds_for_range_includes_both_ends() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let week = WeekData {
+            week_start: day(12),
+            entries: vec![
+                with_worklog_id(entry(3600, ""), "1", day(12)),
+                with_worklog_id(entry(1800, ""), "2", day(13)),
+                with_worklog_id(entry(900, ""), "3", day(14)),
+                with_worklog_id(entry(600, ""), "4", day(15)),
+            ],
+        };
+        assert_eq!(week.total_seconds_for_range(day(13), day(14)), 2700);
+        assert_eq!(week.total_seconds_for_range(day(12), day(12)), 3600);
+        assert_eq!(week.total_seconds_for_range(day(16), day(18)), 0);
+    }
+
+    #[test]
+    fn filter_by_tag_reads_tags_like_the_entry_dialog() {
+        let tags: Vec<TagDefinition> = ["FE", "Bugfix"].iter()
+            .map(|name| TagDefinition { name: name.to_string(), emoji: None })
+            .collect();
+        let week = WeekData {
+            week_start: NaiveDate::from_ymd_opt(2026, 10, 12).unwrap(),
+            entries: vec![
+                entry(3600, "[FE][Bugfix] Fixed login"),
+                entry(1800, "[bugfix] - Lowercase tag"),
+                entry(900, "[Other][Bugfix] Unknown tag ends the prefix"),
+                entry(600, "No tag"),
+            ],
+        };
+        let seconds = |tag| week.filter_by_tag(tag, &tags).iter().map(|e| e.seconds).collect::<Vec<_>>();
+        assert_eq!(seconds("Bugfix"), [3600, 1800]);
+        assert_eq!(seconds("fe"), [3600]);
+        assert!(seconds("Unknown").is_empty());
+    }
+
+    #[test]
+    fn hour_heatmap_splits_entries_across_hours_and_counts_weekends() {
+        let mut spanning = entry(90 * 60, "");
+        spanning.start_time = "09:30".to_string();
+        let mut saturday = entry(3600, "");
+        saturday.date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        saturday.start_time = "14:00".to_string();
+        let week = WeekData {
+            week_start: NaiveDate::from_ymd_opt(2026, 10, 12).unwrap(),
+            entries: vec![spanning, saturday],
+        };
+
+        let heatmap = compute_hour_heatmap(&[week]);
+        assert_eq!(heatmap[9], 0.5);
+        assert_eq!(heatmap[10], 1.0);
+        assert_eq!(heatmap[14], 1.0);
+        assert_eq!(heatmap.iter().filter(|&&v| v > 0.0).count(), 3);
+    }
+
+    #[test]
+    fn tag_totals_read_tags_like_the_entry_dialog() {
+        let tags: Vec<TagDefinition> = ["FE", "Bugfix"].iter()
+            .map(|name| TagDefinition { name: name.to_string(), emoji: None })
+            .collect();
+        let week = WeekData {
+            week_start: NaiveDate::from_ymd_opt(2026, 10, 12).unwrap(),
+            entries: vec![
+                entry(3600, "[FE][Bugfix] Fixed login"),
+                entry(1800, "[fe] - Lowercase tag"),
+                entry(900, "[Other][FE] Unknown tag ends the prefix"),
+                entry(600, "No tag"),
+            ],
+        };
+        assert_eq!(week.tag_totals(&tags), vec![("FE".to_string(), 5400), ("Bugfix".to_string(), 3600)]);
+        assert_eq!(week.untagged_seconds(&tags), 1500);
+    }
+
+    #[test]
+    fn estimate_overrun_uses_the_entry_duration() {
+        let mut over = entry(5 * 3600, "");
+        over.time_original_estimate = 4 * 3600;
+        over.time_spent_total = 4 * 3600;
+        assert_eq!(estimate_overrun(&over), Some(3600));
+
+        // The issue is over, but this entry alone is within 20% of the estimate
+        let mut within = entry(4 * 3600, "");
+        within.time_original_estimate = 4 * 3600;
+        within.time_spent_total = 20 * 3600;
+        assert_eq!(estimate_overrun(&within), None);
+
+        assert_eq!(estimate_overrun(&entry(5 * 3600, "")), None);  // No estimate
+    }
+
+    #[test]
+    fn rounded_outline_stays_inside_the_rect_and_closes() {
+        let rect = egui::Rect::from_min_size(egui::pos2(10.0, 20.0), egui::vec2(100.0, 40.0));
+        let outline = rounded_rect_outline(rect, 4.0);
+        assert_eq!(outline.first(), outline.last());
+        assert!(outline.iter().all(|p| rect.expand(0.001).contains(*p)));
+        // The corners themselves are cut off
+        assert!(!outline.contains(&rect.left_top()));
+        assert!(outline.iter().all(|p| p.distance(rect.right_bottom()) > 1.0));
+    }
+
+    #[test]
+    fn ghost_rect_resize_bottom_keeps_original_top() {
+        // 9:00-10:00 stretched to a snapped 10:15; new_start is ignored in mode 2
+        let rect = ghost(2, 9 * 60, (9 * 60 + 30, 10 * 60 + 15));
+        assert_eq!(rect.min.y, 50.0 + 60.0);
+        assert_eq!(rect.height(), 75.0 - 2.0);
+    }
+
+    #[test]
+    fn ghost_rect_move_follows_new_start() {
+        let rect = ghost(0, 9 * 60, (11 * 60, 12 * 60));
+        assert_eq!(rect.min.y, 50.0 + 180.0);
+        assert_eq!(rect.height(), 60.0 - 2.0);
+        assert_eq!(rect.min.x, 102.0);
+        assert_eq!(rect.width(), 196.0);
+    }
+
+    #[test]
+    fn ghost_rect_clamps_to_visible_range() {
+        // 7:30-8:30 only shows its visible half
+        let above = ghost(0, 9 * 60, (7 * 60 + 30, 8 * 60 + 30));
+        assert_eq!(above.min.y, 50.0);
+        assert_eq!(above.height(), 30.0 - 2.0);
+
+        let below = ghost(1, 17 * 60, (17 * 60 + 45, 19 * 60));
+        assert_eq!(below.min.y, 50.0 + 585.0);
+        assert_eq!(below.height(), 16.0);  // 15 visible minutes, raised to the minimum block height
+    }
+
+    #[test]
+    fn overlap_lanes_disjoint_intervals_get_full_width() {
+        // Back to back doesn't overlap: [start, end)
+        assert_eq!(overlap_lanes(&[(60, 120), (0, 60), (200, 230)]), vec![(0, 1), (0, 1), (0, 1)]);
+    }
+
+    #[test]
+    fn overlap_lanes_nested_intervals_split_the_column() {
+        assert_eq!(overlap_lanes(&[(0, 120), (30, 60)]), vec![(0, 2), (1, 2)]);
+        assert_eq!(overlap_lanes(&[(20, 40), (0, 60), (10, 50)]), vec![(2, 3), (0, 3), (1, 3)]);
+    }
+
+    #[test]
+    fn overlap_lanes_chained_intervals_reuse_freed_lanes() {
+        // The third starts once the first has ended, so two lanes cover the whole chain
+        assert_eq!(overlap_lanes(&[(0, 60), (30, 90), (60, 120)]), vec![(0, 2), (1, 2), (0, 2)]);
+    }
+}
@@ -1,6 +1,8 @@
 use anyhow::Result;
 use self_update::cargo_crate_version;
 
+use crate::config::UpdateChannel;
+
 const GITHUB_OWNER: &str = "tzankich";
 const GITHUB_REPO: &str = "timebox";
 
@@ -9,7 +11,7 @@ pub struct UpdateInfo {
     pub latest_version: String,
 }
 
-pub fn check_for_update() -> Result<Option<UpdateInfo>> {
+pub fn check_for_update(channel: UpdateChannel) -> Result<Option<UpdateInfo>> {
     let current_version = cargo_crate_version!();
 
     let releases = self_update::backends::github::ReleaseList::configure()
@@ -18,7 +20,13 @@ pub fn check_for_update() -> Result<Option<UpdateInfo>> {
         .build()?
         .fetch()?;
 
-    if let Some(latest) = releases.first() {
+    // Releases are newest first - pick the first one the channel allows
+    let latest = releases.iter().find(|r| match channel {
+        UpdateChannel::Stable => !is_pre_release(&r.version),
+        UpdateChannel::PreRelease => true,
+    });
+
+    if let Some(latest) = latest {
         let latest_version = latest.version.trim_start_matches('v').to_string();
 
         if latest_version != current_version {
@@ -31,12 +39,15 @@ pub fn check_for_update() -> Result<Option<UpdateInfo>> {
     Ok(None)
 }
 
-pub fn apply_update() -> Result<()> {
+/// Download and install the given version (as reported by `check_for_update`)
+pub fn apply_update(version: &str) -> Result<()> {
     let status = self_update::backends::github::Update::configure()
         .repo_owner(GITHUB_OWNER)
         .repo_name(GITHUB_REPO)
         .bin_name(get_bin_name())
         .target(get_target())
+        // Pin the tag - GitHub's "latest" endpoint never returns pre-releases
+        .target_version_tag(&format!("v{}", version))
         .no_confirm(true)
         .current_version(cargo_crate_version!())
         .build()?
@@ -49,6 +60,11 @@ pub fn apply_update() -> Result<()> {
     Ok(())
 }
 
+fn is_pre_release(version: &str) -> bool {
+    let version = version.to_lowercase();
+    version.contains("-alpha") || version.contains("-beta") || version.contains("-rc")
+}
+
 fn get_bin_name() -> &'static str {
     #[cfg(target_os = "windows")]
     return "timebox.exe";
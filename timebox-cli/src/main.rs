@@ -0,0 +1,166 @@
+//! Command-line companion for quick time entry, e.g. from scripts or git hooks
+//! Uses the same config file and Jira client as the desktop app
+
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Local, NaiveDate};
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
+
+use timebox_core::api::{format_duration, normalize_issue_key, parse_duration, JiraClient, TimeEntry};
+use timebox_core::config::Config;
+use timebox_core::export::ExportEntry;
+use timebox_core::week_start;
+
+#[derive(Parser)]
+#[command(version, about = "Log, list and delete Jira worklogs with the Timebox desktop app's config")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Log time to an issue
+    Log {
+        /// Issue key, e.g. PROJ-123
+        issue: String,
+        /// Duration, e.g. 1h30m, 1.5h or 90 (minutes)
+        duration: String,
+        /// Worklog description (the remaining words)
+        description: Vec<String>,
+        /// today, yesterday or YYYY-MM-DD
+        #[arg(long, default_value = "today")]
+        date: String,
+        /// Start time (HH:MM); defaults to the app's default start time
+        #[arg(long)]
+        start: Option<String>,
+    },
+    /// List a week's worklogs
+    List {
+        /// current, last, next or any date in the week (YYYY-MM-DD)
+        #[arg(long, default_value = "current")]
+        week: String,
+        /// Print the entries as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Delete a worklog by its ID (as shown by `list`)
+    Delete {
+        worklog_id: String,
+    },
+}
+
+/// "today", "yesterday" or "YYYY-MM-DD"
+fn parse_day(input: &str) -> Result<NaiveDate> {
+    let today = Local::now().date_naive();
+    match input.to_lowercase().as_str() {
+        "today" => Ok(today),
+        "yesterday" => Ok(today - Duration::days(1)),
+        other => NaiveDate::parse_from_str(other, "%Y-%m-%d")
+            .with_context(|| format!("Invalid date: {}", input)),
+    }
+}
+
+fn client() -> Result<(Config, JiraClient)> {
+    let config = Config::load()?;
+    if !config.is_configured() {
+        bail!("Timebox is not configured - open the desktop app and enter your Jira credentials");
+    }
+    let client = JiraClient::new(&config)?;
+    Ok((config, client))
+}
+
+async fn log(issue: &str, duration: &str, description: &[String], date: &str, start: Option<String>) -> Result<()> {
+    let seconds = parse_duration(duration)
+        .with_context(|| format!("Invalid duration: {}", duration))?;
+    let description = description.join(" ");
+    let date = parse_day(date)?;
+
+    let (config, client) = client()?;
+    // Recently used projects also match typed lowercase, e.g. "proj 123"
+    let known_projects: Vec<&str> = config.recent_issues.iter()
+        .filter_map(|(key, _, _)| key.split_once('-').map(|(project, _)| project))
+        .collect();
+    let issue_key = normalize_issue_key(issue, &known_projects);
+    let start_time = start.unwrap_or_else(|| config.default_start_time.clone());
+
+    let worklog = client.log_time(&issue_key, seconds, date, &description, Some(&start_time)).await?;
+    println!("Logged {} to {} on {} (worklog {})", format_duration(seconds), issue_key, date.format("%Y-%m-%d"), worklog.id);
+    Ok(())
+}
+
+async fn list(week: &str, json: bool) -> Result<()> {
+    let (config, client) = client()?;
+    let today = Local::now().date_naive();
+    let day_in_week = match week.to_lowercase().as_str() {
+        "current" => today,
+        "last" => today - Duration::days(7),
+        "next" => today + Duration::days(7),
+        _ => parse_day(week)?,
+    };
+    let week_start = week_start(day_in_week, config.week_start_day);
+    let week_end = week_start + Duration::days(6);
+
+    let mut entries: Vec<TimeEntry> = client.get_my_worklogs(week_start, week_end).await?
+        .into_iter()
+        .map(|(issue, worklog)| TimeEntry::from_worklog(issue, worklog))
+        .collect();
+    entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.start_time.cmp(&b.start_time)));
+
+    if json {
+        let export: Vec<ExportEntry> = entries.iter().map(ExportEntry::from).collect();
+        println!("{}", serde_json::to_string_pretty(&export)?);
+        return Ok(());
+    }
+
+    let key_width = entries.iter().map(|e| e.issue_key.len()).max().unwrap_or(5).max(5);
+    println!("{:<10}  {:<5}  {:<key_width$}  {:>8}  {:<10}  DESCRIPTION", "DATE", "START", "ISSUE", "DURATION", "WORKLOG");
+    for entry in &entries {
+        let description = entry.description.lines().next().unwrap_or("");
+        println!(
+            "{:<10}  {:<5}  {:<key_width$}  {:>8}  {:<10}  {}",
+            entry.date.format("%Y-%m-%d"),
+            entry.start_time,
+            entry.issue_key,
+            format_duration(entry.seconds),
+            entry.worklog_id,
+            description
+        );
+    }
+    let total: i64 = entries.iter().map(|e| e.seconds).sum();
+    println!("\nTotal {} for the week of {}", format_duration(total), week_start.format("%Y-%m-%d"));
+    Ok(())
+}
+
+async fn delete(worklog_id: &str) -> Result<()> {
+    let (_, client) = client()?;
+    // Jira deletes worklogs through their issue, so look the issue up first
+    let worklog = client.get_worklogs_by_id(&[worklog_id]).await?
+        .into_iter()
+        .next()
+        .with_context(|| format!("Worklog {} not found", worklog_id))?;
+    let issue_id = worklog.issue_id.context("Worklog has no issue")?;
+
+    client.delete_worklog(&issue_id, worklog_id).await?;
+    println!("Deleted worklog {}", worklog_id);
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run() -> Result<()> {
+    match Cli::parse().command {
+        Command::Log { issue, duration, description, date, start } => log(&issue, &duration, &description, &date, start).await,
+        Command::List { week, json } => list(&week, json).await,
+        Command::Delete { worklog_id } => delete(&worklog_id).await,
+    }
+}
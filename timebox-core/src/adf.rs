@@ -1,102 +1,9 @@
-use chrono::NaiveDate;
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct User {
-    #[serde(rename = "accountId")]
-    pub account_id: String,
-    #[serde(rename = "displayName")]
-    pub display_name: Option<String>,
-    #[serde(rename = "emailAddress")]
-    pub email_address: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Issue {
-    pub id: String,
-    pub key: String,
-    #[serde(rename = "self")]
-    pub self_url: String,
-    pub fields: IssueFields,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IssueFields {
-    pub summary: String,
-    pub project: Option<Project>,
-    #[serde(rename = "issuetype")]
-    pub issue_type: Option<IssueType>,
-    pub timespent: Option<i64>,
-    #[serde(rename = "timeoriginalestimate")]
-    pub time_original_estimate: Option<i64>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IssueType {
-    pub id: String,
-    pub name: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Project {
-    pub id: String,
-    pub key: String,
-    pub name: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Worklog {
-    pub id: String,
-    #[serde(rename = "self")]
-    pub self_url: String,
-    pub author: User,
-    #[serde(rename = "timeSpent")]
-    pub time_spent: String,
-    #[serde(rename = "timeSpentSeconds")]
-    pub time_spent_seconds: i64,
-    pub started: String,
-    pub comment: Option<WorklogComment>,
-    #[serde(rename = "issueId")]
-    pub issue_id: Option<String>,
-}
-
-// WorklogComment uses serde_json::Value to handle Jira's flexible ADF format
-// which can contain paragraphs, bullet lists, code blocks, etc.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WorklogComment {
-    #[serde(rename = "type")]
-    pub doc_type: String,
-    #[serde(default)]
-    pub content: Option<Vec<serde_json::Value>>,
-}
-
-impl Worklog {
-    /// Extract comment as markdown for editing
-    pub fn comment_text(&self) -> String {
-        let Some(comment) = &self.comment else {
-            return String::new();
-        };
-        let Some(contents) = &comment.content else {
-            return String::new();
-        };
-
-        let mut lines: Vec<String> = Vec::new();
-        for node in contents {
-            extract_adf_to_markdown(node, &mut lines, 0);
-        }
-
-        // Clean up: remove leading/trailing empty lines and excessive whitespace
-        let result = lines.join("\n");
-        result.trim().to_string()
-    }
-}
-
 // ============================================================================
 // ADF → Markdown conversion (for editing existing worklogs)
 // ============================================================================
 
 /// Recursively extract ADF nodes to markdown format
-fn extract_adf_to_markdown(node: &serde_json::Value, lines: &mut Vec<String>, indent: usize) {
+pub fn extract_adf_to_markdown(node: &serde_json::Value, lines: &mut Vec<String>, indent: usize) {
     let Some(obj) = node.as_object() else {
         return;
     };
@@ -198,6 +105,21 @@ fn extract_adf_to_markdown(node: &serde_json::Value, lines: &mut Vec<String>, in
                 }
             }
         }
+        "table" => {
+            // Pipe table - the first row becomes the header, as markdown needs one
+            if let Some(rows) = obj.get("content").and_then(|c| c.as_array()) {
+                for (row_idx, row) in rows.iter().enumerate() {
+                    let cells: Vec<String> = row.get("content")
+                        .and_then(|c| c.as_array())
+                        .map(|cells| cells.iter().map(extract_table_cell_markdown).collect())
+                        .unwrap_or_default();
+                    lines.push(format!("| {} |", cells.join(" | ")));
+                    if row_idx == 0 {
+                        lines.push(format!("|{}|", vec![" --- "; cells.len().max(1)].join("|")));
+                    }
+                }
+            }
+        }
         "hardBreak" => {
             // Hard line break within a paragraph
             lines.push(String::new());
@@ -226,6 +148,7 @@ fn apply_marks_to_text(text: &str, marks: Option<&serde_json::Value>) -> String
     let mut has_em = false;
     let mut has_code = false;
     let mut has_strike = false;
+    let mut link_href = None;
 
     for mark in marks_array {
         if let Some(mark_type) = mark.get("type").and_then(|t| t.as_str()) {
@@ -234,6 +157,7 @@ fn apply_marks_to_text(text: &str, marks: Option<&serde_json::Value>) -> String
                 "em" => has_em = true,
                 "code" => has_code = true,
                 "strike" => has_strike = true,
+                "link" => link_href = mark.get("attrs").and_then(|a| a.get("href")).and_then(|h| h.as_str()),
                 _ => {}
             }
         }
@@ -252,10 +176,31 @@ fn apply_marks_to_text(text: &str, marks: Option<&serde_json::Value>) -> String
     if has_strike {
         result = format!("~~{}~~", result);
     }
+    // Link outermost, so the other marks stay inside the [text]
+    if let Some(href) = link_href {
+        result = format!("[{}]({})", result, href);
+    }
 
     result
 }
 
+/// Extract a table cell (tableHeader or tableCell) as single-line markdown
+fn extract_table_cell_markdown(node: &serde_json::Value) -> String {
+    let mut cell_lines = Vec::new();
+    if let Some(content) = node.get("content").and_then(|c| c.as_array()) {
+        for child in content {
+            extract_adf_to_markdown(child, &mut cell_lines, 0);
+        }
+    }
+    // A pipe table row can't span lines, and a bare | would start a new cell
+    cell_lines.iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
+}
+
 /// Extract text from a list item with proper indentation and marker (markdown format)
 fn extract_list_item_markdown(node: &serde_json::Value, lines: &mut Vec<String>, indent: usize, marker: &str) {
     let Some(obj) = node.as_object() else {
@@ -289,160 +234,10 @@ fn extract_list_item_markdown(node: &serde_json::Value, lines: &mut Vec<String>,
     }
 }
 
-/// Parse time strings like "1h 30m", "2h", "45m", "1.5h", "90" (minutes), "4" (hours)
-/// Bare integers 1-8 are treated as hours, 9+ as minutes
-/// Returns seconds
-pub fn parse_duration(input: &str) -> Option<i64> {
-    let input = input.trim().to_lowercase();
-
-    if input.is_empty() {
-        return None;
-    }
-
-    let mut total_seconds: i64 = 0;
-    let mut current_num = String::new();
-    let mut has_unit = false;
-
-    for c in input.chars() {
-        if c.is_ascii_digit() || c == '.' {
-            current_num.push(c);
-        } else if c == 'h' {
-            if let Ok(hours) = current_num.parse::<f32>() {
-                total_seconds += (hours * 3600.0) as i64;
-                has_unit = true;
-            }
-            current_num.clear();
-        } else if c == 'm' {
-            if let Ok(mins) = current_num.parse::<f32>() {
-                total_seconds += (mins * 60.0) as i64;
-                has_unit = true;
-            }
-            current_num.clear();
-        } else if c == 's' {
-            if let Ok(secs) = current_num.parse::<i64>() {
-                total_seconds += secs;
-                has_unit = true;
-            }
-            current_num.clear();
-        } else if c.is_whitespace() {
-            // ignore whitespace
-        } else {
-            return None; // invalid character
-        }
-    }
-
-    // Handle trailing number without unit
-    if !current_num.is_empty() {
-        if let Ok(num) = current_num.parse::<f32>() {
-            if has_unit {
-                // If we already had units, trailing number is invalid
-                return None;
-            }
-            // Bare number: if it has decimal, treat as hours
-            // If integer 1-8, treat as hours (nobody logs 3 minutes)
-            // If integer 9+, treat as minutes
-            if current_num.contains('.') {
-                total_seconds = (num * 3600.0) as i64;
-            } else {
-                let int_val = num as i64;
-                if int_val >= 1 && int_val <= 8 {
-                    total_seconds = int_val * 3600;
-                } else {
-                    total_seconds = (num * 60.0) as i64;
-                }
-            }
-        }
-    }
-
-    if total_seconds > 0 {
-        Some(total_seconds)
-    } else {
-        None
-    }
-}
-
-use crate::config::TimeFormat;
-
-/// Format seconds as "Xh Ym" string
-pub fn format_duration(seconds: i64) -> String {
-    let hours = seconds / 3600;
-    let mins = (seconds % 3600) / 60;
-
-    if hours > 0 && mins > 0 {
-        format!("{}h {}m", hours, mins)
-    } else if hours > 0 {
-        format!("{}h", hours)
-    } else if mins > 0 {
-        format!("{}m", mins)
-    } else {
-        "0m".to_string()
-    }
-}
-
-/// Format seconds based on user's preferred time format
-pub fn format_duration_with_format(seconds: i64, time_format: TimeFormat) -> String {
-    match time_format {
-        TimeFormat::HoursMinutes => format_duration(seconds),
-        TimeFormat::Decimal => {
-            let hours = seconds as f32 / 3600.0;
-            if hours == 0.0 {
-                "0h".to_string()
-            } else if hours == hours.floor() {
-                format!("{}h", hours as i32)
-            } else {
-                // Format with 2 decimals, then trim trailing zeros
-                let s = format!("{:.2}", hours);
-                let trimmed = s.trim_end_matches('0').trim_end_matches('.');
-                format!("{}h", trimmed)
-            }
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WorklogResponse {
-    pub worklogs: Vec<Worklog>,
-    pub total: i32,
-    #[serde(rename = "maxResults")]
-    pub max_results: i32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SearchResponse {
-    pub issues: Vec<Issue>,
-    #[serde(default)]
-    pub total: Option<i32>,
-    #[serde(rename = "maxResults", default)]
-    pub max_results: Option<i32>,
-    #[serde(rename = "nextPageToken", default)]
-    pub next_page_token: Option<String>,
-}
-
 // ============================================================================
 // Markdown → ADF conversion (for saving worklogs)
 // ============================================================================
 
-#[derive(Debug, Clone, Serialize)]
-pub struct CreateWorklogRequest {
-    #[serde(rename = "timeSpentSeconds")]
-    pub time_spent_seconds: i64,
-    pub started: String,
-    pub comment: serde_json::Value,
-}
-
-impl CreateWorklogRequest {
-    pub fn from_seconds_with_time(seconds: i64, date: NaiveDate, description: &str, start_time: Option<&str>) -> Self {
-        let started = super::time::build_jira_timestamp(date, start_time);
-        let comment = markdown_to_adf(description);
-
-        Self {
-            time_spent_seconds: seconds,
-            started,
-            comment,
-        }
-    }
-}
-
 /// Convert markdown text to ADF (Atlassian Document Format)
 pub fn markdown_to_adf(markdown: &str) -> serde_json::Value {
     let blocks = parse_markdown_blocks(markdown);
@@ -482,8 +277,8 @@ fn parse_markdown_blocks(markdown: &str) -> Vec<serde_json::Value> {
             let mut quote_lines = Vec::new();
             while i < lines.len() {
                 let l = lines[i].trim_start();
-                if l.starts_with("> ") {
-                    quote_lines.push(&l[2..]);
+                if let Some(rest) = l.strip_prefix("> ") {
+                    quote_lines.push(rest);
                     i += 1;
                 } else if l == ">" {
                     quote_lines.push("");
@@ -524,6 +319,16 @@ fn parse_markdown_blocks(markdown: &str) -> Vec<serde_json::Value> {
             continue;
         }
 
+        // Pipe table (header row, then a | --- | separator row)
+        if line.contains('|')
+            && lines.get(i + 1).is_some_and(|next| is_table_separator(next, split_table_row(line).len()))
+        {
+            let (table_block, consumed) = parse_table(&lines[i..]);
+            blocks.push(table_block);
+            i += consumed;
+            continue;
+        }
+
         // Regular paragraph (or empty line)
         let trimmed = line.trim();
         if !trimmed.is_empty() {
@@ -608,6 +413,87 @@ fn parse_ordered_list(lines: &[&str]) -> (serde_json::Value, usize) {
     }), i)
 }
 
+/// Check if line is a table separator row (| --- | :---: |) with one cell per header column
+fn is_table_separator(line: &str, columns: usize) -> bool {
+    if !line.contains('-') {
+        return false;
+    }
+    let cells = split_table_row(line);
+    cells.len() == columns && cells.iter().all(|cell| {
+        let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+        !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+    })
+}
+
+/// Split a table row into trimmed cells, dropping the outer pipes (\| is a literal pipe)
+fn split_table_row(line: &str) -> Vec<String> {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let trimmed = if trimmed.ends_with('|') && !trimmed.ends_with("\\|") {
+        &trimmed[..trimmed.len() - 1]
+    } else {
+        trimmed
+    };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek() == Some(&'|') {
+            cell.push('|');
+            chars.next();
+        } else if c == '|' {
+            cells.push(cell.trim().to_string());
+            cell.clear();
+        } else {
+            cell.push(c);
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+/// Parse a pipe table, returns (ADF node, lines consumed)
+fn parse_table(lines: &[&str]) -> (serde_json::Value, usize) {
+    let header = split_table_row(lines[0]);
+    let columns = header.len();
+    let mut rows = vec![create_table_row(&header, "tableHeader", columns)];
+    let mut i = 2; // header and separator
+
+    while i < lines.len() && lines[i].contains('|') && !lines[i].trim().is_empty() {
+        rows.push(create_table_row(&split_table_row(lines[i]), "tableCell", columns));
+        i += 1;
+    }
+
+    (serde_json::json!({
+        "type": "table",
+        "content": rows
+    }), i)
+}
+
+/// Create an ADF table row, padding or truncating to the header's column count
+fn create_table_row(cells: &[String], cell_type: &str, columns: usize) -> serde_json::Value {
+    let content: Vec<serde_json::Value> = (0..columns)
+        .map(|col| {
+            let text = cells.get(col).map(String::as_str).unwrap_or("");
+            let paragraph = if text.is_empty() {
+                serde_json::json!({ "type": "paragraph", "content": [] })
+            } else {
+                create_paragraph(text)
+            };
+            serde_json::json!({
+                "type": cell_type,
+                "content": [paragraph]
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "type": "tableRow",
+        "content": content
+    })
+}
+
 /// Create an ADF paragraph node with inline formatting
 fn create_paragraph(text: &str) -> serde_json::Value {
     let inline_content = parse_inline_formatting(text);
@@ -648,7 +534,7 @@ fn create_blockquote(text: &str) -> serde_json::Value {
     let paragraphs: Vec<serde_json::Value> = text
         .lines()
         .filter(|l| !l.is_empty())
-        .map(|line| create_paragraph(line))
+        .map(create_paragraph)
         .collect();
 
     let content = if paragraphs.is_empty() {
@@ -674,7 +560,7 @@ fn create_list_item(text: &str) -> serde_json::Value {
     })
 }
 
-/// Parse inline formatting (bold, italic, code, strikethrough)
+/// Parse inline formatting (links, bold, italic, code, strikethrough)
 fn parse_inline_formatting(text: &str) -> Vec<serde_json::Value> {
     if text.is_empty() {
         return vec![serde_json::json!({ "type": "text", "text": "" })];
@@ -683,6 +569,74 @@ fn parse_inline_formatting(text: &str) -> Vec<serde_json::Value> {
     let mut result = Vec::new();
     let mut remaining = text;
 
+    // Links first, so underscores or asterisks in a URL aren't read as emphasis
+    while let Some((before, label, href, after)) = find_link(remaining) {
+        result.extend(parse_marked_text(before));
+        for mut node in parse_marked_text(label) {
+            let link = serde_json::json!({ "type": "link", "attrs": { "href": href } });
+            match node.get_mut("marks").and_then(|m| m.as_array_mut()) {
+                Some(marks) => marks.push(link),
+                None => node["marks"] = serde_json::json!([link]),
+            }
+            result.push(node);
+        }
+        remaining = after;
+    }
+    result.extend(parse_marked_text(remaining));
+
+    if result.is_empty() {
+        result.push(serde_json::json!({ "type": "text", "text": "" }));
+    }
+
+    result
+}
+
+/// Find a [text](url) link - tags like "[FE]" that aren't followed by (url) are skipped
+/// Returns (before, text, url, after) or None
+fn find_link(text: &str) -> Option<(&str, &str, &str, &str)> {
+    let mut search_from = 0;
+    while let Some(offset) = text[search_from..].find('[') {
+        let open = search_from + offset;
+        search_from = open + 1;
+        let rest = &text[open + 1..];
+        let Some(close) = closing_bracket(rest) else {
+            continue;
+        };
+        let label = &rest[..close];
+        let Some(after_label) = rest[close + 1..].strip_prefix('(') else {
+            continue;
+        };
+        let Some(href_end) = after_label.find(')') else {
+            continue;
+        };
+        let href = &after_label[..href_end];
+        if label.is_empty() || href.is_empty() || href.contains(char::is_whitespace) {
+            continue;
+        }
+        return Some((&text[..open], label, href, &after_label[href_end + 1..]));
+    }
+    None
+}
+
+/// Find the ] that closes an already-opened [, skipping nested pairs like "[see [FE] notes]"
+fn closing_bracket(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 0 => return Some(i),
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse bold, italic, code and strikethrough into text nodes (empty input gives no nodes)
+fn parse_marked_text(text: &str) -> Vec<serde_json::Value> {
+    let mut result = Vec::new();
+    let mut remaining = text;
+
     while !remaining.is_empty() {
         // Try to match inline patterns
         if let Some((before, content, marks, after)) = try_match_inline(remaining) {
@@ -704,10 +658,6 @@ fn parse_inline_formatting(text: &str) -> Vec<serde_json::Value> {
         }
     }
 
-    if result.is_empty() {
-        result.push(serde_json::json!({ "type": "text", "text": "" }));
-    }
-
     result
 }
 
@@ -770,15 +720,116 @@ fn find_pattern<'a>(text: &'a str, start: &str, end: &str) -> Option<(&'a str, &
     Some((before, content, after))
 }
 
-// Time entry display for the UI
-#[derive(Debug, Clone)]
-pub struct TimeEntry {
-    pub worklog_id: String,
-    pub issue_key: String,
-    pub issue_summary: String,
-    pub issue_type: String,  // "Task", "Bug", "Story", "Epic", etc.
-    pub seconds: i64,
-    pub description: String,
-    pub date: NaiveDate,
-    pub start_time: String,  // "HH:MM" format for sorting
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocks(markdown: &str) -> Vec<serde_json::Value> {
+        markdown_to_adf(markdown)["content"].as_array().cloned().unwrap_or_default()
+    }
+
+    fn cell_text(row: &serde_json::Value, col: usize) -> &str {
+        row["content"][col]["content"][0]["content"][0]["text"].as_str().unwrap_or("")
+    }
+
+    fn paragraph_nodes(markdown: &str) -> Vec<serde_json::Value> {
+        blocks(markdown)[0]["content"].as_array().cloned().unwrap_or_default()
+    }
+
+    fn link_href(node: &serde_json::Value) -> Option<&str> {
+        node["marks"].as_array()?.iter()
+            .find(|mark| mark["type"] == "link")
+            .and_then(|mark| mark["attrs"]["href"].as_str())
+    }
+
+    #[test]
+    fn link_becomes_link_mark() {
+        let nodes = paragraph_nodes("See [the docs](https://example.com/a_b) now");
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[0]["text"], "See ");
+        assert_eq!(nodes[1]["text"], "the docs");
+        assert_eq!(link_href(&nodes[1]), Some("https://example.com/a_b"));
+        assert_eq!(nodes[2]["text"], " now");
+    }
+
+    #[test]
+    fn brackets_inside_link_text_stay_in_the_label() {
+        let nodes = paragraph_nodes("[see [FE] notes](https://example.com)");
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0]["text"], "see [FE] notes");
+        assert_eq!(link_href(&nodes[0]), Some("https://example.com"));
+
+        // A tag that isn't a link doesn't swallow the link after it
+        let nodes = paragraph_nodes("[FE] [fix](https://example.com)");
+        assert_eq!(nodes[0]["text"], "[FE] ");
+        assert_eq!(link_href(&nodes[0]), None);
+        assert_eq!(nodes[1]["text"], "fix");
+        assert_eq!(link_href(&nodes[1]), Some("https://example.com"));
+    }
+
+    #[test]
+    fn link_without_closing_paren_stays_plain_text() {
+        let nodes = paragraph_nodes("[docs](https://example.com");
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0]["text"], "[docs](https://example.com");
+        assert_eq!(link_href(&nodes[0]), None);
+    }
+
+    #[test]
+    fn marked_link_round_trips_through_markdown() {
+        let markdown = "Read [**spec [v2]**](https://example.com/spec) first";
+        let mut lines = Vec::new();
+        extract_adf_to_markdown(&markdown_to_adf(markdown), &mut lines, 0);
+        let rebuilt = lines.join("\n");
+        assert_eq!(markdown_to_adf(&rebuilt), markdown_to_adf(markdown));
+
+        let nodes = paragraph_nodes(&rebuilt);
+        let link = nodes.iter().find(|node| link_href(node).is_some()).unwrap();
+        assert_eq!(link["text"], "spec [v2]");
+        assert!(link["marks"].as_array().unwrap().iter().any(|mark| mark["type"] == "strong"));
+    }
+
+    #[test]
+    fn pipe_table_becomes_adf_table() {
+        let content = blocks("| Task | Time |\n| --- | :---: |\n| Review | 1h |\n| Fix a\\|b | 2h |");
+        assert_eq!(content.len(), 1);
+        let table = &content[0];
+        assert_eq!(table["type"], "table");
+        let rows = table["content"].as_array().unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0]["content"][0]["type"], "tableHeader");
+        assert_eq!(rows[1]["content"][0]["type"], "tableCell");
+        assert_eq!(cell_text(&rows[0], 1), "Time");
+        assert_eq!(cell_text(&rows[1], 0), "Review");
+        assert_eq!(cell_text(&rows[2], 0), "Fix a|b");
+    }
+
+    #[test]
+    fn short_rows_are_padded_to_header_width() {
+        let content = blocks("| a | b | c |\n|---|---|---|\n| 1 |");
+        let row = &content[0]["content"][1];
+        assert_eq!(row["content"].as_array().unwrap().len(), 3);
+        assert_eq!(cell_text(row, 0), "1");
+        assert_eq!(cell_text(row, 2), "");
+    }
+
+    #[test]
+    fn separator_must_match_header_columns() {
+        // A line with a pipe followed by a horizontal rule isn't a table
+        let content = blocks("a | b\n---");
+        assert!(content.iter().all(|block| block["type"] != "table"));
+
+        let content = blocks("| a | b |\n| --- |\n| 1 | 2 |");
+        assert!(content.iter().all(|block| block["type"] != "table"));
+    }
+
+    #[test]
+    fn table_round_trips_through_markdown() {
+        let markdown = "| Task | Time |\n| --- | --- |\n| Review | 1h |";
+        let mut lines = Vec::new();
+        extract_adf_to_markdown(&markdown_to_adf(markdown), &mut lines, 0);
+        let rebuilt = lines.join("\n");
+        assert_eq!(markdown_to_adf(&rebuilt), markdown_to_adf(markdown));
+        assert!(rebuilt.contains("| Review | 1h |"));
+    }
 }
@@ -0,0 +1,591 @@
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::NaiveDate;
+use reqwest::{header, Client, RequestBuilder, Response, StatusCode};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::types::*;
+use crate::config::{AuthMethod, Config};
+
+/// Retries after Jira answers 429 Too Many Requests, waiting 1x, 2x, then 4x its Retry-After
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Used when a 429 has no (or an unreadable) Retry-After header
+const DEFAULT_RETRY_AFTER_SECS: u64 = 5;
+
+/// Longest single wait, so a huge Retry-After can't stall the UI indefinitely
+const MAX_RETRY_WAIT_SECS: u64 = 60;
+
+/// Pause before resending a request that timed out
+const TIMEOUT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Issue fields every search asks for
+const ISSUE_FIELDS: [&str; 6] = ["summary", "project", "issuetype", "status", "timespent", "timeoriginalestimate"];
+
+/// Jira's limit on IDs per /worklog/list request
+const WORKLOG_LIST_MAX_IDS: usize = 1000;
+
+pub struct JiraClient {
+    client: Client,
+    base_url: String,
+    agile_base_url: String,
+    auth_header: String,
+    max_retries: u8,
+    // Data Center / Server: REST API v2, with its older search endpoint and plain-text comments
+    data_center: bool,
+}
+
+impl JiraClient {
+    pub fn new(config: &Config) -> Result<Self> {
+        let token = config.api_token.as_ref()
+            .context("API token not configured")?;
+
+        let auth_header = match config.auth_method {
+            AuthMethod::Basic => {
+                let auth_string = format!("{}:{}", config.email, token);
+                format!("Basic {}", STANDARD.encode(auth_string))
+            }
+            AuthMethod::Bearer => format!("Bearer {}", token),
+        };
+
+        // Without timeouts a request behind some corporate proxies never returns
+        let client = Client::builder()
+            .connect_timeout(Duration::from_secs(config.connect_timeout_secs.max(1)))
+            .timeout(Duration::from_secs(config.request_timeout_secs.max(1)))
+            .build()?;
+
+        Ok(Self {
+            client,
+            base_url: config.base_url(),
+            agile_base_url: config.agile_base_url(),
+            auth_header,
+            max_retries: config.max_retries,
+            data_center: config.auth_method == AuthMethod::Bearer,
+        })
+    }
+
+    /// Send a request, retrying when rate limited or timed out (up to `max_retries` times);
+    /// any other non-2xx status is an error
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        self.send_with_timeout_retries(request, self.max_retries).await
+    }
+
+    /// `send` with its own limit on timeout retries; 0 for requests that mustn't run twice,
+    /// since a timed-out request may still have reached Jira
+    async fn send_with_timeout_retries(&self, request: RequestBuilder, max_timeout_retries: u8) -> Result<Response> {
+        let mut attempt = 0;
+        let mut timeouts = 0;
+        loop {
+            let sent = request.try_clone()
+                .context("Request body can't be resent")?
+                .send()
+                .await;
+            let response = match sent {
+                Err(e) if e.is_timeout() && timeouts < max_timeout_retries => {
+                    timeouts += 1;
+                    tokio::time::sleep(TIMEOUT_RETRY_DELAY).await;
+                    continue;
+                }
+                result => result?,
+            };
+            let status = response.status();
+
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                if attempt == MAX_RATE_LIMIT_RETRIES {
+                    anyhow::bail!("Rate limited by Jira - too many requests, try again in a minute");
+                }
+                let retry_after = response.headers()
+                    .get(header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+                let wait = (retry_after << attempt).min(MAX_RETRY_WAIT_SECS);
+                tokio::time::sleep(Duration::from_secs(wait)).await;
+                attempt += 1;
+                continue;
+            }
+
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                anyhow::bail!("API request failed: {} - {}", status, body);
+            }
+            return Ok(response);
+        }
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        self.get_url(&format!("{}{}", self.base_url, endpoint)).await
+    }
+
+    async fn get_url<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let request = self.client
+            .get(url)
+            .header(header::AUTHORIZATION, &self.auth_header)
+            .header(header::ACCEPT, "application/json");
+        let response = self.send(request).await?;
+
+        let result = response.json::<T>().await?;
+        Ok(result)
+    }
+
+    async fn post<T: serde::de::DeserializeOwned, B: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        body: &B,
+    ) -> Result<T> {
+        self.post_with_timeout_retries(endpoint, body, self.max_retries).await
+    }
+
+    async fn post_with_timeout_retries<T: serde::de::DeserializeOwned, B: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        body: &B,
+        max_timeout_retries: u8,
+    ) -> Result<T> {
+        let url = format!("{}{}", self.base_url, endpoint);
+
+        let request = self.client
+            .post(&url)
+            .header(header::AUTHORIZATION, &self.auth_header)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::ACCEPT, "application/json")
+            .json(body);
+        let response = self.send_with_timeout_retries(request, max_timeout_retries).await?;
+
+        let result = response.json::<T>().await?;
+        Ok(result)
+    }
+
+    async fn put<T: serde::de::DeserializeOwned, B: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        body: &B,
+    ) -> Result<T> {
+        let url = format!("{}{}", self.base_url, endpoint);
+
+        let request = self.client
+            .put(&url)
+            .header(header::AUTHORIZATION, &self.auth_header)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::ACCEPT, "application/json")
+            .json(body);
+        let response = self.send(request).await?;
+
+        let result = response.json::<T>().await?;
+        Ok(result)
+    }
+
+    /// Get the current user
+    pub async fn get_myself(&self) -> Result<User> {
+        self.get("/myself").await
+    }
+
+    /// Search issues using JQL (POST /search/jql on Cloud, /search on Data Center)
+    pub async fn search_issues(&self, jql: &str, max_results: i32) -> Result<SearchResponse> {
+        self.search_issues_with_fields(jql, max_results, &ISSUE_FIELDS).await
+    }
+
+    async fn search_issues_with_fields(&self, jql: &str, max_results: i32, fields: &[&str]) -> Result<SearchResponse> {
+        let request_body = serde_json::json!({
+            "jql": jql,
+            "maxResults": max_results,
+            "fields": fields
+        });
+        let endpoint = if self.data_center { "/search" } else { "/search/jql" };
+        self.post(endpoint, &request_body).await
+    }
+
+    /// Every issue matching the JQL, a page of 100 at a time
+    /// Cloud pages with `nextPageToken`, Data Center with `startAt` up to `total`
+    async fn search_all_issues_with_fields(&self, jql: &str, fields: &[&str]) -> Result<Vec<Issue>> {
+        let endpoint = if self.data_center { "/search" } else { "/search/jql" };
+        let mut issues = Vec::new();
+        let mut next_page_token: Option<String> = None;
+        loop {
+            let mut request_body = serde_json::json!({
+                "jql": jql,
+                "maxResults": 100,
+                "fields": fields
+            });
+            if self.data_center {
+                request_body["startAt"] = issues.len().into();
+            } else if let Some(token) = &next_page_token {
+                request_body["nextPageToken"] = token.as_str().into();
+            }
+            let page: SearchResponse = self.post(endpoint, &request_body).await?;
+            let page_len = page.issues.len();
+            issues.extend(page.issues);
+            // An empty page ends it either way, in case Jira's total or token is stale
+            let more = if self.data_center {
+                page.total.is_some_and(|total| issues.len() < total.max(0) as usize)
+            } else {
+                next_page_token = page.next_page_token;
+                next_page_token.is_some()
+            };
+            if page_len == 0 || !more {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Worklog body in the format the API version expects
+    fn worklog_request(&self, seconds: i64, date: NaiveDate, description: &str, start_time: Option<&str>) -> CreateWorklogRequest {
+        if self.data_center {
+            CreateWorklogRequest::plain_text(seconds, date, description, start_time)
+        } else {
+            CreateWorklogRequest::from_seconds_with_time(seconds, date, description, start_time)
+        }
+    }
+
+    /// Get worklogs for a specific issue
+    /// Jira pages worklogs (20 by default), so keep requesting until `total` is reached
+    pub async fn get_issue_worklogs(&self, issue_key: &str) -> Result<Vec<Worklog>> {
+        let mut all_worklogs = Vec::new();
+        loop {
+            let endpoint = format!("/issue/{}/worklog?expand=properties&startAt={}", issue_key, all_worklogs.len());
+            let response: WorklogResponse = self.get(&endpoint).await?;
+            let page_len = response.worklogs.len();
+            all_worklogs.extend(response.worklogs);
+            // An empty page means Jira's total is stale - stop rather than loop forever
+            if page_len == 0 || all_worklogs.len() >= response.total.max(0) as usize {
+                break;
+            }
+        }
+
+        fill_time_spent_seconds(&mut all_worklogs);
+        Ok(all_worklogs)
+    }
+
+    /// Get worklogs for current user within a date range
+    /// Returns (issue, worklog) pairs so callers can read issue-level fields like time tracking
+    pub async fn get_my_worklogs(&self, start_date: NaiveDate, end_date: NaiveDate) -> Result<Vec<(Issue, Worklog)>> {
+        // Get current user to filter worklogs
+        let myself = self.get_myself().await?;
+        self.get_author_worklogs("currentUser()", myself.id(), start_date, end_date).await
+    }
+
+    /// Same as `get_my_worklogs`, for another user's account ID (username on Data Center)
+    pub async fn get_user_worklogs(&self, account_id: &str, start_date: NaiveDate, end_date: NaiveDate) -> Result<Vec<(Issue, Worklog)>> {
+        // Account IDs look like "557058:f58131cb-...", usernames like "jane.doe" - escape_jql_string
+        // would drop the colon, so keep only the characters they can contain
+        let account_id: String = account_id.chars()
+            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '-' | '_' | '.' | '@'))
+            .collect();
+        let author = format!("\"{}\"", account_id);
+        self.get_author_worklogs(&author, &account_id, start_date, end_date).await
+    }
+
+    /// Worklogs in the date range by one author (`author_jql` is the worklogAuthor operand)
+    async fn get_author_worklogs(&self, author_jql: &str, account_id: &str, start_date: NaiveDate, end_date: NaiveDate) -> Result<Vec<(Issue, Worklog)>> {
+        // Search for issues with worklogs by the author in date range
+        let jql = format!(
+            "worklogAuthor = {} AND worklogDate >= '{}' AND worklogDate <= '{}' ORDER BY updated DESC",
+            author_jql,
+            start_date.format("%Y-%m-%d"),
+            end_date.format("%Y-%m-%d")
+        );
+
+        // The search embeds each issue's first page of worklogs, so most issues need no
+        // request of their own - only the matching IDs are fetched again, in bulk, for
+        // their properties (which the embedded copies lack)
+        let mut fields = ISSUE_FIELDS.to_vec();
+        fields.push("worklog");
+        let issues = self.search_all_issues_with_fields(&jql, &fields).await?;
+
+        let matches = |worklog: &Worklog| {
+            worklog.author.id() == account_id
+                && parse_worklog_date(&worklog.started)
+                    .is_ok_and(|date| date >= start_date && date <= end_date)
+        };
+
+        let mut all_worklogs = Vec::new();
+        let mut bulk_ids = Vec::new();
+        let mut issues_by_id = HashMap::new();
+
+        for mut issue in issues {
+            match issue.fields.worklog.take() {
+                Some(page) if page.worklogs.len() >= page.total.max(0) as usize => {
+                    bulk_ids.extend(page.worklogs.iter().filter(|w| matches(w)).map(|w| w.id.clone()));
+                    issues_by_id.insert(issue.id.clone(), issue);
+                }
+                _ => {
+                    // More worklogs than Jira embeds - page through them the long way
+                    // Skip issues that fail to fetch (permissions, network, etc.)
+                    let worklogs = match self.get_issue_worklogs(&issue.key).await {
+                        Ok(w) => w,
+                        Err(_) => continue,
+                    };
+                    for worklog in worklogs.into_iter().filter(|w| matches(w)) {
+                        all_worklogs.push((issue.clone(), worklog));
+                    }
+                }
+            }
+        }
+
+        let bulk_ids: Vec<&str> = bulk_ids.iter().map(String::as_str).collect();
+        for worklog in self.get_worklogs_by_id(&bulk_ids).await? {
+            if let Some(issue) = worklog.issue_id.as_ref().and_then(|id| issues_by_id.get(id)) {
+                all_worklogs.push((issue.clone(), worklog));
+            }
+        }
+
+        Ok(all_worklogs)
+    }
+
+    /// Log time to an issue
+    pub async fn log_time(
+        &self,
+        issue_key: &str,
+        seconds: i64,
+        date: NaiveDate,
+        description: &str,
+        start_time: Option<&str>,
+    ) -> Result<Worklog> {
+        let endpoint = format!("/issue/{}/worklog", issue_key);
+        let request = self.worklog_request(seconds, date, description, start_time);
+        // Not retried on timeout: Jira may already have created it
+        self.post_with_timeout_retries(&endpoint, &request, 0).await
+    }
+
+    /// Update an existing worklog
+    pub async fn update_worklog(
+        &self,
+        issue_key: &str,
+        worklog_id: &str,
+        seconds: i64,
+        description: &str,
+        date: chrono::NaiveDate,
+        start_time: Option<&str>,
+    ) -> Result<Worklog> {
+        // Same body as a new worklog: started timestamp (09:00 if empty/None) and the
+        // description (converted from markdown to ADF on Cloud)
+        let request = self.worklog_request(seconds, date, description, start_time);
+
+        let endpoint = format!("/issue/{}/worklog/{}", issue_key, worklog_id);
+        self.put(&endpoint, &request).await
+    }
+
+    /// Delete a worklog
+    pub async fn delete_worklog(&self, issue_key: &str, worklog_id: &str) -> Result<()> {
+        let url = format!("{}/issue/{}/worklog/{}", self.base_url, issue_key, worklog_id);
+
+        let request = self.client
+            .delete(&url)
+            .header(header::AUTHORIZATION, &self.auth_header);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Look up worklogs by ID (the response includes each worklog's issue ID and properties)
+    /// Jira takes up to 1000 IDs per request, so longer lists are split
+    pub async fn get_worklogs_by_id(&self, worklog_ids: &[&str]) -> Result<Vec<Worklog>> {
+        let mut all_worklogs = Vec::new();
+        for chunk in worklog_ids.chunks(WORKLOG_LIST_MAX_IDS) {
+            let ids: Vec<i64> = chunk.iter()
+                .map(|id| id.parse().with_context(|| format!("Invalid worklog ID: {}", id)))
+                .collect::<Result<_>>()?;
+            let request_body = serde_json::json!({ "ids": ids });
+            let worklogs: Vec<Worklog> = self.post("/worklog/list?expand=properties", &request_body).await?;
+            all_worklogs.extend(worklogs);
+        }
+        fill_time_spent_seconds(&mut all_worklogs);
+        Ok(all_worklogs)
+    }
+
+    /// Active sprints across the user's scrum boards (Agile API)
+    /// Every page of boards is read, then their sprints are fetched concurrently
+    /// Boards that fail (e.g. no permission) are skipped; sprints shared by boards appear once
+    pub async fn get_active_sprints(&self) -> Result<Vec<Sprint>> {
+        let mut boards: Vec<Board> = Vec::new();
+        loop {
+            let url = format!("{}/board?type=scrum&startAt={}", self.agile_base_url, boards.len());
+            let page: AgilePage<Board> = self.get_url(&url).await?;
+            let page_len = page.values.len();
+            boards.extend(page.values);
+            if page.is_last || page_len == 0 {
+                break;
+            }
+        }
+
+        let pages = futures::future::join_all(boards.iter().map(|board| {
+            let url = format!("{}/board/{}/sprint?state=active", self.agile_base_url, board.id);
+            async move { self.get_url::<AgilePage<Sprint>>(&url).await }
+        })).await;
+
+        let mut sprints: Vec<Sprint> = Vec::new();
+        for page in pages.into_iter().flatten() {
+            for sprint in page.values {
+                if !sprints.iter().any(|s| s.id == sprint.id) {
+                    sprints.push(sprint);
+                }
+            }
+        }
+        Ok(sprints)
+    }
+
+    /// Get TIME board bucket issues (Meetings, Support, Admin)
+    pub async fn get_time_buckets(&self) -> Result<Vec<Issue>> {
+        // Search for TIME board issues - adjust JQL as needed for your setup
+        let jql = "project = TIM AND status = 'In Progress' ORDER BY key ASC";
+        let response = self.search_issues(jql, 10).await?;
+        Ok(response.issues)
+    }
+
+    /// Search issues by text (for autocomplete)
+    pub async fn search_issues_by_text(&self, text: &str) -> Result<Vec<Issue>> {
+        // If it looks like an issue key (e.g., "ABC-123" or "ABC"), search by key first
+        let text = &escape_jql_string(text);
+        if text.trim().is_empty() {
+            return self.get_recent_issues().await;
+        }
+        let text_upper = text.to_uppercase();
+        let jql = if text.contains('-') || text.chars().all(|c| c.is_alphabetic()) {
+            // Likely an issue key or project prefix
+            format!(
+                "key = '{}' OR key ~ '{}' OR summary ~ '{}' ORDER BY lastViewed DESC",
+                text_upper, text_upper, text
+            )
+        } else {
+            // General text search
+            format!(
+                "text ~ '{}' ORDER BY lastViewed DESC",
+                text
+            )
+        };
+        let response = self.search_issues(&jql, 10).await?;
+        Ok(response.issues)
+    }
+
+    /// Get recently viewed issues (for autocomplete suggestions)
+    pub async fn get_recent_issues(&self) -> Result<Vec<Issue>> {
+        let jql = "ORDER BY lastViewed DESC";
+        let response = self.search_issues(jql, 15).await?;
+        Ok(response.issues)
+    }
+
+    /// Search for a weekly bucket ticket by keyword and week dates
+    /// Searches for tickets containing the keyword (e.g., "MEETING") AND a date from the week
+    /// Uses Monday and Friday dates in multiple formats for robustness
+    pub async fn search_weekly_bucket(&self, keyword: &str, week_start: NaiveDate) -> Result<Option<Issue>> {
+        let monday = week_start;
+        let friday = week_start + chrono::Duration::days(4);
+
+        // Generate date patterns for both Monday and Friday in multiple formats
+        // Examples for Dec 2, 2024: "12/2", "12/02", "Dec 2", "December 2"
+        let mon_patterns = generate_date_patterns(monday);
+        let fri_patterns = generate_date_patterns(friday);
+
+        // Build JQL with OR conditions for all date patterns
+        let date_conditions: Vec<String> = mon_patterns
+            .iter()
+            .chain(fri_patterns.iter())
+            .map(|p| format!("summary ~ \"{}\"", p))
+            .collect();
+
+        let jql = format!(
+            "summary ~ \"{}\" AND ({}) ORDER BY key DESC",
+            keyword,
+            date_conditions.join(" OR ")
+        );
+
+        let response = self.search_issues(&jql, 1).await?;
+        Ok(response.issues.into_iter().next())
+    }
+
+    /// Search for all weekly bucket tickets for a given week
+    /// Returns a map of category -> Issue for Meeting, Support, Admin
+    pub async fn search_all_weekly_buckets(&self, week_start: NaiveDate) -> Result<Vec<(String, Issue)>> {
+        let categories = ["MEETING", "SUPPORT", "ADMIN"];
+        let mut results = Vec::new();
+
+        for category in categories {
+            if let Some(issue) = self.search_weekly_bucket(category, week_start).await? {
+                results.push((category.to_string(), issue));
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Make user text safe inside a quoted JQL string
+/// Quotes and backslashes are backslash-escaped; characters the text search treats as
+/// operators (& | ( ) + and friends) are dropped since they only make the query fail
+fn escape_jql_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' | '\'' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '&' | '|' | '(' | ')' | '+' | '!' | '{' | '}' | '[' | ']' | '^' | '~' | ':' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Generate multiple date pattern strings for searching
+fn generate_date_patterns(date: NaiveDate) -> Vec<String> {
+    let year = date.format("%Y").to_string();             // "2025"
+    let month = date.format("%m").to_string().trim_start_matches('0').to_string(); // "12" or "1"
+    let day = date.format("%d").to_string().trim_start_matches('0').to_string();   // "2" or "31"
+    let month_padded = date.format("%m").to_string();     // "12" or "01"
+    let day_padded = date.format("%d").to_string();       // "02" or "31"
+    let month_abbrev = date.format("%b").to_string();     // "Dec"
+    let month_full = date.format("%B").to_string();       // "December"
+
+    vec![
+        // ISO format (most likely based on user feedback)
+        format!("{}-{}-{}", year, month_padded, day_padded), // "2025-12-02"
+        format!("{}-{}-{}", year, month, day),               // "2025-12-2"
+        // US format with slashes
+        format!("{}/{}", month, day),                        // "12/2"
+        format!("{}/{}", month_padded, day_padded),          // "12/02"
+        // Dash format without year
+        format!("{}-{}", month_padded, day_padded),          // "12-02"
+        format!("{}-{}", month, day),                        // "12-2"
+        // Readable formats
+        format!("{} {}", month_abbrev, day),                 // "Dec 2"
+        format!("{} {}", month_full, day),                   // "December 2"
+    ]
+}
+
+/// Some responses only carry the Jira-formatted "timeSpent" (e.g. "3d 2h")
+fn fill_time_spent_seconds(worklogs: &mut [Worklog]) {
+    for worklog in worklogs {
+        if worklog.time_spent_seconds == 0 {
+            worklog.time_spent_seconds = parse_duration_jira_days(&worklog.time_spent, 8.0).unwrap_or(0);
+        }
+    }
+}
+
+fn parse_worklog_date(started: &str) -> Result<NaiveDate> {
+    // Format: "2025-12-02T09:00:00.000+0000"
+    let date_part = started.split('T').next().unwrap_or(started);
+    NaiveDate::parse_from_str(date_part, "%Y-%m-%d")
+        .context("Failed to parse worklog date")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_jql_string_escapes_quotes_and_backslashes() {
+        assert_eq!(escape_jql_string(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(escape_jql_string("it's"), r"it\'s");
+        assert_eq!(escape_jql_string(r"C:\temp"), r"C\\temp");
+    }
+
+    #[test]
+    fn escape_jql_string_drops_reserved_characters() {
+        assert_eq!(escape_jql_string("C++ library"), "C library");
+        assert_eq!(escape_jql_string("a & b | (c) !d {e} [f] ^g ~h"), "a  b  c d e f g h");
+        assert_eq!(escape_jql_string("plain text 123"), "plain text 123");
+    }
+}
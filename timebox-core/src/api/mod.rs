@@ -4,4 +4,4 @@ mod time;
 
 pub use types::*;
 pub use client::JiraClient;
-pub use time::{extract_time, parse_date};
+pub use time::{extract_time, parse_date, parse_start_time};
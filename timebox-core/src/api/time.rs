@@ -22,7 +22,7 @@ fn debug_log(_message: &str) {}
 /// Extract time as "HH:MM" from a Jira datetime string like "2025-12-02T09:00:00.000+0000"
 /// Converts from the stored timezone to local time
 pub fn extract_time(started: &str) -> String {
-    debug_log(&format!("\n--- extract_time ---"));
+    debug_log("\n--- extract_time ---");
     debug_log(&format!("Input: {}", started));
 
     // Normalize timezone offset: convert "+0800" to "+08:00" format for parsing
@@ -67,13 +67,19 @@ pub fn parse_date(started: &str) -> NaiveDate {
         .unwrap_or_else(|_| Local::now().date_naive())
 }
 
-/// Parse a user-entered start time string (e.g., "9:00am", "14:30", "2pm") to "HH:MM:SS" format
+/// Parse a user-entered start time string (e.g., "9:00am", "14:30", "2pm", "noon", "9.5") to "HH:MM:SS" format
 pub fn parse_start_time(input: &str) -> Option<String> {
     let input = input.trim().to_lowercase();
     if input.is_empty() {
         return None;
     }
 
+    match input.as_str() {
+        "noon" => return Some("12:00:00".to_string()),
+        "midnight" => return Some("00:00:00".to_string()),
+        _ => {}
+    }
+
     // Check for am/pm suffix
     let (time_part, is_pm) = if input.ends_with("pm") {
         (&input[..input.len() - 2], true)
@@ -90,12 +96,22 @@ pub fn parse_start_time(input: &str) -> Option<String> {
     let time_part = time_part.trim();
 
     // Parse hour and optional minute
-    let (hour, minute) = if time_part.contains(':') {
+    let (hour, minute) = if time_part.contains('.') {
+        // Decimal hours: "9.5" = 09:30
+        let hours = time_part.parse::<f64>().ok().filter(|h| *h >= 0.0)?;
+        let total_minutes = (hours * 60.0).round() as u32;
+        // Rounding must not carry into the next hour ("9.99999" isn't 10:00)
+        if total_minutes / 60 != hours.trunc() as u32 {
+            return None;
+        }
+        (total_minutes / 60, total_minutes % 60)
+    } else if time_part.contains(':') {
         let parts: Vec<&str> = time_part.split(':').collect();
         let h = parts[0].parse::<u32>().ok()?;
         let m = parts.get(1).and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
         (h, m)
     } else {
+        // Bare hour; without am/pm it is read as 24-hour ("9" = 09:00, "14" = 14:00)
         let h = time_part.parse::<u32>().ok()?;
         (h, 0)
     };
@@ -133,7 +149,7 @@ pub fn build_jira_timestamp(date: NaiveDate, start_time: Option<&str>) -> String
 
     let started = format!("{}T{}.000{}", date.format("%Y-%m-%d"), time_str, offset_str);
 
-    debug_log(&format!("\n--- build_jira_timestamp ---"));
+    debug_log("\n--- build_jira_timestamp ---");
     debug_log(&format!("Input start_time: {:?}", start_time));
     debug_log(&format!("Parsed time_str: {}", time_str));
     debug_log(&format!("Local offset (seconds): {}", local_offset));
@@ -161,3 +177,35 @@ fn normalize_timezone_offset(started: &str) -> String {
     }
     started.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_start_time_accepts_common_forms() {
+        let cases = [
+            ("noon", "12:00:00"),
+            ("Midnight", "00:00:00"),
+            ("9", "09:00:00"),
+            ("14", "14:00:00"),
+            ("9.5", "09:30:00"),
+            ("9.5pm", "21:30:00"),
+            ("9:15am", "09:15:00"),
+            ("2pm", "14:00:00"),
+            ("12am", "00:00:00"),
+            ("12pm", "12:00:00"),
+            (" 14:30 ", "14:30:00"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_start_time(input).as_deref(), Some(expected), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn parse_start_time_rejects_invalid_times() {
+        for input in ["24", "9.99999", "abc", "", "9:60", "-1.5"] {
+            assert_eq!(parse_start_time(input), None, "{:?}", input);
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::extract_adf_to_markdown;
+
+pub use crate::{format_duration, format_duration_with_format, markdown_to_adf, parse_duration, parse_duration_jira_days};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    // Jira Cloud only - Data Center identifies users by `name` (username) and `key`
+    #[serde(rename = "accountId", default)]
+    pub account_id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub key: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "emailAddress")]
+    pub email_address: Option<String>,
+}
+
+impl User {
+    /// The ID worklogAuthor accepts: the account ID on Cloud, the username (or key) on Data Center
+    pub fn id(&self) -> &str {
+        self.account_id.as_deref()
+            .or(self.name.as_deref())
+            .or(self.key.as_deref())
+            .unwrap_or("")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Issue {
+    pub id: String,
+    pub key: String,
+    #[serde(rename = "self")]
+    pub self_url: String,
+    pub fields: IssueFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueFields {
+    pub summary: String,
+    pub project: Option<Project>,
+    #[serde(rename = "issuetype")]
+    pub issue_type: Option<IssueType>,
+    pub timespent: Option<i64>,
+    #[serde(rename = "timeoriginalestimate")]
+    pub time_original_estimate: Option<i64>,
+    pub status: Option<IssueStatus>,
+    // First page of worklogs, only present when the search asks for the "worklog" field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worklog: Option<WorklogResponse>,
+}
+
+impl Issue {
+    /// Project key from the issue's fields, falling back to the key prefix ("PROJ-123" -> "PROJ")
+    pub fn project_key(&self) -> &str {
+        self.fields.project.as_ref()
+            .map(|p| p.key.as_str())
+            .unwrap_or_else(|| self.key.split('-').next().unwrap_or(&self.key))
+    }
+
+    /// Workflow status name ("In Progress", "Done"), empty if Jira didn't send one
+    pub fn status_name(&self) -> &str {
+        self.fields.status.as_ref().map(|s| s.name.as_str()).unwrap_or("")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueType {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueStatus {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Worklog {
+    pub id: String,
+    #[serde(rename = "self")]
+    pub self_url: String,
+    pub author: User,
+    #[serde(rename = "timeSpent", default)]
+    pub time_spent: String,
+    #[serde(rename = "timeSpentSeconds", default)]
+    pub time_spent_seconds: i64,
+    pub started: String,
+    pub comment: Option<WorklogComment>,
+    #[serde(rename = "issueId")]
+    pub issue_id: Option<String>,
+    // Entity properties (requested with expand=properties), e.g. Tempo billing attributes
+    #[serde(default)]
+    pub properties: Option<serde_json::Value>,
+}
+
+// WorklogComment uses serde_json::Value to handle Jira's flexible ADF format
+// which can contain paragraphs, bullet lists, code blocks, etc.
+// The v2 API (Data Center) sends a plain string instead
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WorklogComment {
+    Adf {
+        #[serde(rename = "type")]
+        doc_type: String,
+        #[serde(default)]
+        content: Option<Vec<serde_json::Value>>,
+    },
+    Text(String),
+}
+
+impl Worklog {
+    /// Extract comment as markdown for editing
+    pub fn comment_text(&self) -> String {
+        let contents = match &self.comment {
+            Some(WorklogComment::Adf { content: Some(contents), .. }) => contents,
+            Some(WorklogComment::Text(text)) => return text.trim().to_string(),
+            _ => return String::new(),
+        };
+
+        let mut lines: Vec<String> = Vec::new();
+        for node in contents {
+            extract_adf_to_markdown(node, &mut lines, 0);
+        }
+
+        // Clean up: remove leading/trailing empty lines and excessive whitespace
+        let result = lines.join("\n");
+        result.trim().to_string()
+    }
+
+    /// Flatten worklog properties into key/value strings for display
+    /// Jira returns `[{"key": "...", "value": ...}]`; object values are expanded to "key.field"
+    pub fn custom_properties(&self) -> HashMap<String, String> {
+        let mut result = HashMap::new();
+        let Some(properties) = self.properties.as_ref().and_then(|p| p.as_array()) else {
+            return result;
+        };
+
+        for property in properties {
+            let Some(key) = property.get("key").and_then(|k| k.as_str()) else {
+                continue;
+            };
+            match property.get("value") {
+                Some(serde_json::Value::Object(fields)) => {
+                    for (field, value) in fields {
+                        result.insert(format!("{}.{}", key, field), property_value_text(value));
+                    }
+                }
+                Some(value) => {
+                    result.insert(key.to_string(), property_value_text(value));
+                }
+                None => {}
+            }
+        }
+
+        result
+    }
+}
+
+fn property_value_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Normalize a typed issue key: "  proj-123 " -> "PROJ-123"
+/// The space-separated form ("PROJ 123", or "proj 123" when PROJ is in `known_projects`) is only taken as a key
+/// when the project part is already uppercase or known, so searches like "login 2" stay as typed
+pub fn normalize_issue_key(input: &str, known_projects: &[&str]) -> String {
+    let trimmed = input.trim();
+    let Some(split) = trimmed.find(|c: char| c == '-' || c.is_whitespace()) else {
+        return input.to_string();
+    };
+
+    let (project, rest) = trimmed.split_at(split);
+    let number = rest.trim_start_matches(|c: char| c == '-' || c.is_whitespace());
+
+    let valid_project = project.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && project.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    let valid_number = !number.is_empty() && number.chars().all(|c| c.is_ascii_digit());
+    let valid_separator = rest.starts_with('-')
+        || project.chars().all(|c| !c.is_ascii_lowercase())
+        || known_projects.iter().any(|known| known.eq_ignore_ascii_case(project));
+
+    if valid_project && valid_number && valid_separator {
+        format!("{}-{}", project.to_uppercase(), number)
+    } else {
+        input.to_string()
+    }
+}
+
+// Agile API (boards and sprints)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Board {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sprint {
+    pub id: i64,
+    pub name: String,
+    pub state: String,
+    #[serde(rename = "startDate", default)]
+    pub start_date: Option<String>,
+    #[serde(rename = "endDate", default)]
+    pub end_date: Option<String>,
+}
+
+/// Paged list wrapper used by the Agile API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgilePage<T> {
+    pub values: Vec<T>,
+    #[serde(rename = "isLast", default = "default_true")]
+    pub is_last: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorklogResponse {
+    pub worklogs: Vec<Worklog>,
+    pub total: i32,
+    #[serde(rename = "maxResults")]
+    pub max_results: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResponse {
+    pub issues: Vec<Issue>,
+    #[serde(default)]
+    pub total: Option<i32>,
+    #[serde(rename = "maxResults", default)]
+    pub max_results: Option<i32>,
+    #[serde(rename = "nextPageToken", default)]
+    pub next_page_token: Option<String>,
+}
+
+// ============================================================================
+// Markdown → ADF conversion (for saving worklogs)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateWorklogRequest {
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: i64,
+    pub started: String,
+    pub comment: serde_json::Value,
+}
+
+impl CreateWorklogRequest {
+    /// Request body for the v3 API, with the description converted from markdown to ADF
+    pub fn from_seconds_with_time(seconds: i64, date: NaiveDate, description: &str, start_time: Option<&str>) -> Self {
+        let started = super::time::build_jira_timestamp(date, start_time);
+        let comment = markdown_to_adf(description);
+
+        Self {
+            time_spent_seconds: seconds,
+            started,
+            comment,
+        }
+    }
+
+    /// Same as `from_seconds_with_time`, for the v2 API (Data Center), which takes the comment as plain text
+    pub fn plain_text(seconds: i64, date: NaiveDate, description: &str, start_time: Option<&str>) -> Self {
+        Self {
+            comment: serde_json::Value::String(description.to_string()),
+            ..Self::from_seconds_with_time(seconds, date, description, start_time)
+        }
+    }
+}
+
+// Time entry display for the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub worklog_id: String,
+    pub issue_key: String,
+    pub issue_summary: String,
+    pub issue_type: String,  // "Task", "Bug", "Story", "Epic", etc.
+    pub issue_status: String,  // Workflow status name ("In Progress", "Done"), empty if unknown
+    pub seconds: i64,
+    pub description: String,
+    pub date: NaiveDate,
+    pub start_time: String,  // "HH:MM" format for sorting
+    pub time_spent_total: i64,        // Issue-level time spent in seconds (all users)
+    pub time_original_estimate: i64,  // Issue original estimate in seconds, 0 if none
+    pub custom_properties: HashMap<String, String>,  // Worklog properties (e.g. Tempo attributes)
+}
+
+impl TimeEntry {
+    /// Build a display entry from a fetched worklog and its issue
+    pub fn from_worklog(issue: Issue, worklog: Worklog) -> Self {
+        let description = worklog.comment_text();
+        let seconds = worklog.time_spent_seconds;
+        let date = super::time::parse_date(&worklog.started);
+        let start_time = super::time::extract_time(&worklog.started);
+        let custom_properties = worklog.custom_properties();
+        let issue_status = issue.status_name().to_string();
+        let issue_type = issue.fields.issue_type
+            .map(|t| t.name)
+            .unwrap_or_else(|| "Task".to_string());
+        Self {
+            worklog_id: worklog.id,
+            issue_key: issue.key,
+            issue_summary: issue.fields.summary,
+            issue_type,
+            issue_status,
+            seconds,
+            description,
+            date,
+            start_time,
+            time_spent_total: issue.fields.timespent.unwrap_or(0),
+            time_original_estimate: issue.fields.time_original_estimate.unwrap_or(0),
+            custom_properties,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worklog(json: serde_json::Value) -> Worklog {
+        serde_json::from_value(json).expect("worklog should deserialize")
+    }
+
+    #[test]
+    fn normalize_issue_key_accepts_key_like_input() {
+        assert_eq!(normalize_issue_key("  proj-123 ", &[]), "PROJ-123");
+        assert_eq!(normalize_issue_key("PROJ 123", &[]), "PROJ-123");
+        assert_eq!(normalize_issue_key("proj 123", &["PROJ"]), "PROJ-123");
+        assert_eq!(normalize_issue_key("Ab2 7", &["AB2"]), "AB2-7");
+    }
+
+    #[test]
+    fn normalize_issue_key_leaves_searches_unchanged() {
+        assert_eq!(normalize_issue_key("login 2", &[]), "login 2");
+        assert_eq!(normalize_issue_key("sprint 12", &["PROJ"]), "sprint 12");
+        assert_eq!(normalize_issue_key("Login 2", &[]), "Login 2");
+        assert_eq!(normalize_issue_key("PROJ-12a", &[]), "PROJ-12a");
+        assert_eq!(normalize_issue_key("123 45", &[]), "123 45");
+        assert_eq!(normalize_issue_key("login", &[]), "login");
+    }
+
+    #[test]
+    fn data_center_worklog_has_username_and_plain_comment() {
+        let worklog = worklog(serde_json::json!({
+            "id": "10001",
+            "self": "https://jira.example.com/rest/api/2/issue/10000/worklog/10001",
+            "author": { "name": "jane.doe", "key": "JIRAUSER10100", "displayName": "Jane Doe" },
+            "started": "2026-10-12T09:00:00.000+0000",
+            "comment": "  Reviewed the **spec**\n"
+        }));
+        assert_eq!(worklog.author.id(), "jane.doe");
+        assert_eq!(worklog.comment_text(), "Reviewed the **spec**");
+    }
+
+    #[test]
+    fn cloud_worklog_has_account_id_and_adf_comment() {
+        let worklog = worklog(serde_json::json!({
+            "id": "10001",
+            "self": "https://example.atlassian.net/rest/api/3/issue/10000/worklog/10001",
+            "author": { "accountId": "557058:f58131cb", "name": "ignored" },
+            "started": "2026-10-12T09:00:00.000+0000",
+            "comment": markdown_to_adf("Reviewed the spec")
+        }));
+        assert_eq!(worklog.author.id(), "557058:f58131cb");
+        assert_eq!(worklog.comment_text(), "Reviewed the spec");
+    }
+
+    #[test]
+    fn worklog_properties_are_flattened_to_text() {
+        let worklog = worklog(serde_json::json!({
+            "id": "10001",
+            "self": "https://example.atlassian.net/rest/api/3/issue/10000/worklog/10001",
+            "author": { "accountId": "557058:f58131cb" },
+            "started": "2026-10-12T09:00:00.000+0000",
+            "properties": [
+                { "key": "billing", "value": "Billable" },
+                { "key": "tempo", "value": { "account": "ACME", "hours": 2 } },
+                { "key": "no-value" }
+            ]
+        }));
+        let properties = worklog.custom_properties();
+        assert_eq!(properties.len(), 3);
+        assert_eq!(properties["billing"], "Billable");
+        assert_eq!(properties["tempo.account"], "ACME");
+        assert_eq!(properties["tempo.hours"], "2");
+    }
+
+    #[test]
+    fn worklog_without_properties_has_none() {
+        let worklog = worklog(serde_json::json!({
+            "id": "10001",
+            "self": "https://jira.example.com/rest/api/2/issue/10000/worklog/10001",
+            "author": { "name": "jane.doe" },
+            "started": "2026-10-12T09:00:00.000+0000"
+        }));
+        assert!(worklog.custom_properties().is_empty());
+    }
+}
@@ -0,0 +1,905 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc, Weekday};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+pub use crate::TimeFormat;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ClockFormat {
+    #[default]
+    Hour24,      // "14:30"
+    Hour12,      // "2:30pm"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ListViewMode {
+    #[default]
+    Contracted,  // Compact 2-line cards, description truncated
+    Expanded,    // Cards grow to fit full wrapped description
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ViewMode {
+    #[default]
+    List,        // Traditional list of time entries
+    Schedule,    // Multi-day schedule/timeline view
+    IssueGrouped,  // The day's entries under one collapsible row per issue
+    Month,       // Calendar of the month, each day shaded by its logged time
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LinkBehavior {
+    #[default]
+    OpenBrowser,  // Issue keys open the issue in Jira
+    OpenInApp,    // Issue keys open the details panel; Ctrl/Cmd+click opens Jira
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum WeekendDisplay {
+    #[default]
+    Auto,        // Shown when today is a weekend day or the week has weekend entries
+    AlwaysShow,  // Always seven days
+    AlwaysHide,  // Always Mon-Fri
+}
+
+impl WeekendDisplay {
+    pub fn label(&self) -> &'static str {
+        match self {
+            WeekendDisplay::Auto => "Auto",
+            WeekendDisplay::AlwaysShow => "Always show",
+            WeekendDisplay::AlwaysHide => "Always hide",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SnapInterval {
+    OneMinute,   // 1 minute snapping
+    FiveMinutes, // 5 minute snapping
+    SixMinutes,  // 6 minute snapping (0.1h billing)
+    TenMinutes,  // 10 minute snapping
+    #[default]
+    FifteenMinutes, // 15 minute snapping (default)
+    ThirtyMinutes,  // 30 minute snapping
+}
+
+impl SnapInterval {
+    pub fn minutes(&self) -> i32 {
+        match self {
+            SnapInterval::OneMinute => 1,
+            SnapInterval::FiveMinutes => 5,
+            SnapInterval::SixMinutes => 6,
+            SnapInterval::TenMinutes => 10,
+            SnapInterval::FifteenMinutes => 15,
+            SnapInterval::ThirtyMinutes => 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum UpdateChannel {
+    #[default]
+    Stable,      // Only full releases
+    PreRelease,  // Includes -alpha, -beta and -rc builds
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ColorblindMode {
+    #[default]
+    None,          // Standard accent colors
+    Deuteranopia,  // Green-weak safe palette + patterns
+    Protanopia,    // Red-weak safe palette + patterns
+}
+
+/// A category tag, written into descriptions as "[name]"; the emoji is display-only
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "TagConfig")]
+pub struct TagDefinition {
+    pub name: String,
+    pub emoji: Option<char>,
+}
+
+// Older configs store tags as plain strings
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TagConfig {
+    Name(String),
+    Full {
+        name: String,
+        #[serde(default)]
+        emoji: Option<char>,
+    },
+}
+
+impl From<TagConfig> for TagDefinition {
+    fn from(tag: TagConfig) -> Self {
+        match tag {
+            TagConfig::Name(name) => Self { name, emoji: None },
+            TagConfig::Full { name, emoji } => Self { name, emoji },
+        }
+    }
+}
+
+impl TagDefinition {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), emoji: None }
+    }
+
+    /// Parse the settings form "🐛 Bugfix" (emoji optional) into a tag
+    pub fn parse(input: &str) -> Self {
+        let input = input.trim();
+        let mut chars = input.chars();
+        if let Some(first) = chars.next() {
+            let rest = chars.as_str();
+            if !first.is_alphanumeric() && !first.is_ascii() && rest.starts_with(' ') && !rest.trim().is_empty() {
+                return Self { name: rest.trim().to_string(), emoji: Some(first) };
+            }
+        }
+        Self::new(input)
+    }
+
+    /// Display label: emoji (if any) followed by the name
+    pub fn label(&self) -> String {
+        match self.emoji {
+            Some(emoji) => format!("{} {}", emoji, self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Parse category tags like [FE][BE] from the start of a description
+/// Returns which of `tags` are set (case-insensitive; a separating space or dash is skipped,
+/// and the first unknown tag ends the prefix) and the description that follows
+pub fn parse_categories_from_description(description: &str, tags: &[TagDefinition]) -> (Vec<bool>, String) {
+    let mut categories = vec![false; tags.len()];
+    let mut remaining = description.trim();
+
+    // Parse all tags at the start of the description
+    loop {
+        let trimmed = remaining.trim_start();
+        if !trimmed.starts_with('[') {
+            remaining = trimmed;
+            break;
+        }
+
+        if let Some(end) = trimmed.find(']') {
+            let tag = &trimmed[1..end];
+            // Check if this matches one of our tags (case-insensitive)
+            let mut found = false;
+            for (i, cat) in tags.iter().enumerate() {
+                if tag.eq_ignore_ascii_case(&cat.name) {
+                    categories[i] = true;
+                    found = true;
+                    break;
+                }
+            }
+            if found {
+                remaining = &trimmed[end + 1..];
+                // Skip any separator after the tag (space, dash, etc.)
+                remaining = remaining.trim_start_matches([' ', '-']);
+            } else {
+                // Unknown tag - stop parsing
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+
+    (categories, remaining.to_string())
+}
+
+/// Pre-selects tags in the add dialog for issues whose key starts with a prefix (e.g. "INFRA-")
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagAutoRule {
+    pub project_prefix: String,
+    #[serde(default)]
+    pub tags: Vec<String>,  // Tag names, matched case-insensitively against `Config::tags`
+    // Older configs point at tags by position; read once, then saved as names
+    #[serde(default, skip_serializing)]
+    tag_indices: Vec<usize>,
+}
+
+impl TagAutoRule {
+    pub fn new(project_prefix: &str, tags: Vec<String>) -> Self {
+        Self { project_prefix: project_prefix.to_string(), tags, tag_indices: Vec::new() }
+    }
+
+    /// Turn positions from an older config into the names of `tags`
+    fn migrate_indices(&mut self, tags: &[TagDefinition]) {
+        for idx in std::mem::take(&mut self.tag_indices) {
+            if let Some(tag) = tags.get(idx) {
+                if !self.applies_tag(&tag.name) {
+                    self.tags.push(tag.name.clone());
+                }
+            }
+        }
+    }
+
+    /// Whether the rule ticks the tag named `name` (case-insensitive)
+    pub fn applies_tag(&self, name: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(name))
+    }
+}
+
+/// A colleague whose worklogs are shown beside your own in the schedule view
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamMember {
+    pub jira_account_id: String,
+    pub display_name: String,
+}
+
+/// A user-defined toolbar button
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuickAction {
+    pub icon: String,  // Phosphor icon name, e.g. "globe" or "terminal"
+    pub label: String,
+    pub action: QuickActionType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuickActionType {
+    OpenUrl(String),       // Opened in the browser
+    CopyTemplate(String),  // Expanded ({date}, {week_total}, ...) and copied to the clipboard
+    RunScript(String),     // Run through the system shell
+}
+
+impl QuickActionType {
+    pub const KINDS: [&'static str; 3] = ["Open URL", "Copy template", "Run script"];
+
+    pub fn kind_index(&self) -> usize {
+        match self {
+            QuickActionType::OpenUrl(_) => 0,
+            QuickActionType::CopyTemplate(_) => 1,
+            QuickActionType::RunScript(_) => 2,
+        }
+    }
+
+    /// Same value under another kind (index into `KINDS`)
+    pub fn with_kind(&self, kind: usize) -> Self {
+        let value = self.value().to_string();
+        match kind {
+            1 => QuickActionType::CopyTemplate(value),
+            2 => QuickActionType::RunScript(value),
+            _ => QuickActionType::OpenUrl(value),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        match self {
+            QuickActionType::OpenUrl(v) | QuickActionType::CopyTemplate(v) | QuickActionType::RunScript(v) => v,
+        }
+    }
+
+    pub fn value_mut(&mut self) -> &mut String {
+        match self {
+            QuickActionType::OpenUrl(v) | QuickActionType::CopyTemplate(v) | QuickActionType::RunScript(v) => v,
+        }
+    }
+}
+
+/// Outgoing mail server for the weekly report email
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    #[serde(default = "default_smtp_port")]
+    pub port: u16,  // 465 connects with TLS, any other port upgrades with STARTTLS
+    pub username: String,
+    #[serde(default)]
+    pub password: Option<String>,  // Plain text in config.json, like api_token
+    pub from_address: String,
+    pub to_addresses: Vec<String>,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// How requests authenticate; both methods read the secret from `api_token`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum AuthMethod {
+    #[default]
+    Basic,   // Jira Cloud: `email:api_token` as HTTP Basic
+    Bearer,  // Jira Data Center / Server: personal access token, no email
+}
+
+impl AuthMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AuthMethod::Basic => "Email and API token",
+            AuthMethod::Bearer => "Personal access token",
+        }
+    }
+}
+
+/// One Jira site and login; the active one is mirrored in Config's flat credential fields
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigProfile {
+    pub name: String,
+    pub jira_domain: String,
+    pub email: String,
+    #[serde(default)]
+    pub api_token: Option<String>,
+    #[serde(default)]
+    pub auth_method: AuthMethod,
+}
+
+/// Window position and inner size in logical pixels, restored on the next launch
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Built-in issue type icon colors, used unless overridden in `issue_type_colors`
+pub const DEFAULT_ISSUE_TYPE_COLORS: [(&str, [u8; 3]); 4] = [
+    ("Task", [0x42, 0x9c, 0xd6]),   // Blue
+    ("Story", [0x65, 0xba, 0x43]),  // Green
+    ("Bug", [0xe5, 0x4d, 0x42]),    // Red
+    ("Epic", [0x90, 0x4e, 0xe2]),   // Purple
+];
+
+/// Built-in color for an issue type (case-insensitive); other types use the Task color
+pub fn default_issue_type_color(issue_type: &str) -> [u8; 3] {
+    DEFAULT_ISSUE_TYPE_COLORS.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(issue_type))
+        .unwrap_or(&DEFAULT_ISSUE_TYPE_COLORS[0])
+        .1
+}
+
+/// An issue type's color from `Config::issue_type_colors`, falling back to the built-in one
+pub fn issue_type_color(issue_type: &str, overrides: &HashMap<String, [u8; 3]>) -> [u8; 3] {
+    overrides.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(issue_type))
+        .map(|(_, rgb)| *rgb)
+        .unwrap_or_else(|| default_issue_type_color(issue_type))
+}
+
+/// An active sprint remembered between launches so the header can show it immediately
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedSprint {
+    pub name: String,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+/// How long cached sprints are trusted before asking Jira again
+pub const SPRINT_CACHE_HOURS: i64 = 12;
+
+/// Minimum time between fetches of the shared team config
+pub const REMOTE_CONFIG_REFRESH_HOURS: i64 = 24;
+
+/// Team-managed fields from `remote_config_url`
+/// Fields missing from the file keep their local values; credentials and personal settings are never read
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RemoteConfig {
+    #[serde(default)]
+    pub tags: Option<Vec<TagDefinition>>,
+    #[serde(default)]
+    pub tag_auto_rules: Option<Vec<TagAutoRule>>,
+    #[serde(default)]
+    pub issue_type_colors: Option<HashMap<String, [u8; 3]>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    pub jira_domain: String,
+    pub email: String,
+    #[serde(default)]
+    pub api_token: Option<String>,
+    #[serde(default)]
+    pub auth_method: AuthMethod,
+    #[serde(default)]
+    pub profiles: Vec<ConfigProfile>,  // Every saved Jira site, including the active one
+    #[serde(default)]
+    pub active_profile: usize,  // Index into profiles whose credentials are in the fields above
+    #[serde(default = "default_font_scale")]
+    pub font_scale: f32,
+    #[serde(default)]
+    pub time_format: TimeFormat,
+    #[serde(default)]
+    pub clock_format: ClockFormat,
+    #[serde(default = "default_true")]
+    pub show_start_time: bool,
+    #[serde(default = "default_true")]
+    pub show_status_bar: bool,
+    #[serde(default = "default_decimal_precision")]
+    pub decimal_precision: u8,  // Decimals shown in the Decimal time format (1-3)
+    #[serde(default = "default_tags")]
+    pub tags: Vec<TagDefinition>,
+    #[serde(default)]
+    pub tag_auto_rules: Vec<TagAutoRule>,
+    #[serde(default)]
+    pub team_members: Vec<TeamMember>,  // Read-only sub-columns in the schedule view
+    #[serde(default)]
+    pub list_view_mode: ListViewMode,
+    #[serde(default)]
+    pub week_summary_collapsed: bool,  // Week statistics above the entry list folded to their header
+    #[serde(default)]
+    pub view_mode: ViewMode,
+    #[serde(default = "default_schedule_start_hour")]
+    pub schedule_start_hour: u8,
+    #[serde(default = "default_schedule_end_hour")]
+    pub schedule_end_hour: u8,
+    #[serde(default)]
+    pub snap_interval: SnapInterval,
+    #[serde(default = "default_min_column_width")]
+    pub min_column_width: f32,  // Schedule day columns narrower than this scroll horizontally
+    #[serde(default = "default_min_schedule_block_px")]
+    pub min_schedule_block_px: f32,  // Shortest drawn schedule block, so 5 minute entries stay clickable (10-60)
+    #[serde(default = "default_show_block_label_threshold_px")]
+    pub show_block_label_threshold_px: f32,  // Schedule blocks shorter than this show no text
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+    #[serde(default = "default_start_time")]
+    pub default_start_time: String,  // Used when a new worklog has no start time
+    #[serde(default)]
+    pub colorblind_mode: ColorblindMode,
+    #[serde(default)]
+    pub weekend_display: WeekendDisplay,
+    #[serde(default = "default_week_start_day")]
+    pub week_start_day: Weekday,  // Monday, or Sunday for US-style weeks
+    #[serde(default)]
+    pub link_behavior: LinkBehavior,
+    #[serde(default = "default_daily_target_hours")]
+    pub daily_target_hours: f32,
+    // Opt-in weekly summary from a self-hosted model, e.g. "http://localhost:11434/api/generate"
+    #[serde(default)]
+    pub ai_summary_endpoint: Option<String>,
+    #[serde(default = "default_ai_summary_model")]
+    pub ai_summary_model: String,
+    #[serde(default)]
+    pub quick_actions: Vec<QuickAction>,
+    #[serde(default)]
+    pub smtp_config: Option<SmtpConfig>,
+    #[serde(default)]
+    pub export_include_annotations: bool,  // Private notes are left out of exports unless enabled
+    #[serde(default)]
+    pub export_directory: Option<String>,  // Folder for exported files; None uses the data directory's "exports"
+    #[serde(default)]
+    pub issue_type_colors: HashMap<String, [u8; 3]>,  // Issue type name -> icon RGB, overriding the defaults
+    #[serde(default = "default_project_colors")]
+    pub project_colors: Vec<(String, [u8; 3])>,  // Project key -> card accent; these projects' Meeting/Support/Admin tickets get bucket colors
+    #[serde(default)]
+    pub remote_config_url: Option<String>,  // Shared team baseline, e.g. a static file or raw gist URL
+    #[serde(default)]
+    pub remote_config_last_fetched: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub recent_issues: Vec<(String, String, String)>,  // Last issues logged to (key, summary, type), newest first
+    #[serde(default)]
+    pub window_state: Option<WindowState>,  // Last normal (not maximized) window placement
+    #[serde(default)]
+    pub cached_sprints: Vec<CachedSprint>,
+    #[serde(default)]
+    pub cached_sprints_at: Option<NaiveDateTime>,  // Refetched once older than SPRINT_CACHE_HOURS
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,  // Whole request, including reading the response
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u8,  // Extra attempts after a request times out
+}
+
+fn default_connect_timeout_secs() -> u64 {
+    10
+}
+
+fn default_request_timeout_secs() -> u64 {
+    30
+}
+
+fn default_max_retries() -> u8 {
+    2
+}
+
+fn default_schedule_start_hour() -> u8 {
+    5  // 5am
+}
+
+fn default_schedule_end_hour() -> u8 {
+    20  // 8pm
+}
+
+fn default_min_column_width() -> f32 {
+    100.0
+}
+
+fn default_min_schedule_block_px() -> f32 {
+    20.0
+}
+
+fn default_show_block_label_threshold_px() -> f32 {
+    30.0
+}
+
+fn default_ai_summary_model() -> String {
+    "llama3.2".to_string()
+}
+
+fn default_daily_target_hours() -> f32 {
+    8.0
+}
+
+fn default_project_colors() -> Vec<(String, [u8; 3])> {
+    vec![("TIM".to_string(), [0x13, 0x98, 0xf4])]
+}
+
+fn default_decimal_precision() -> u8 {
+    2
+}
+
+fn default_start_time() -> String {
+    "09:00".to_string()
+}
+
+fn default_week_start_day() -> Weekday {
+    Weekday::Mon
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_font_scale() -> f32 {
+    1.0
+}
+
+fn default_tags() -> Vec<TagDefinition> {
+    ["FE", "BE", "Bugfix", "CR", "Support", "Meetings", "Refactor", "Admin"]
+        .into_iter()
+        .map(TagDefinition::new)
+        .collect()
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            jira_domain: String::new(),
+            email: String::new(),
+            api_token: None,
+            auth_method: AuthMethod::Basic,
+            profiles: Vec::new(),
+            active_profile: 0,
+            font_scale: 1.0,
+            time_format: TimeFormat::HoursMinutes,
+            clock_format: ClockFormat::Hour24,
+            show_start_time: true,
+            show_status_bar: true,
+            decimal_precision: default_decimal_precision(),
+            tags: default_tags(),
+            tag_auto_rules: Vec::new(),
+            team_members: Vec::new(),
+            list_view_mode: ListViewMode::Contracted,
+            week_summary_collapsed: false,
+            view_mode: ViewMode::List,
+            schedule_start_hour: 5,
+            schedule_end_hour: 20,
+            snap_interval: SnapInterval::FifteenMinutes,
+            min_column_width: default_min_column_width(),
+            min_schedule_block_px: default_min_schedule_block_px(),
+            show_block_label_threshold_px: default_show_block_label_threshold_px(),
+            update_channel: UpdateChannel::Stable,
+            default_start_time: default_start_time(),
+            colorblind_mode: ColorblindMode::None,
+            weekend_display: WeekendDisplay::Auto,
+            week_start_day: default_week_start_day(),
+            link_behavior: LinkBehavior::OpenBrowser,
+            daily_target_hours: default_daily_target_hours(),
+            ai_summary_endpoint: None,
+            ai_summary_model: default_ai_summary_model(),
+            quick_actions: Vec::new(),
+            smtp_config: None,
+            export_include_annotations: false,
+            export_directory: None,
+            issue_type_colors: HashMap::new(),
+            project_colors: default_project_colors(),
+            remote_config_url: None,
+            remote_config_last_fetched: None,
+            recent_issues: Vec::new(),
+            window_state: None,
+            cached_sprints: Vec::new(),
+            cached_sprints_at: None,
+            connect_timeout_secs: default_connect_timeout_secs(),
+            request_timeout_secs: default_request_timeout_secs(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let config_path = Self::config_path()?;
+
+        if config_path.exists() {
+            let contents = fs::read_to_string(&config_path)
+                .context("Failed to read config file")?;
+            let mut config: Config = serde_json::from_str(&contents)
+                .context("Failed to parse config file")?;
+            // Configs from before profiles only have the flat credential fields
+            config.profiles = config.profiles_with_active();
+            for rule in &mut config.tag_auto_rules {
+                rule.migrate_indices(&config.tags);
+            }
+            config.active_profile = config.active_profile.min(config.profiles.len() - 1);
+            Ok(config)
+        } else {
+            Ok(Config::default())
+        }
+    }
+
+    /// Saved profiles with the active one refreshed from the flat credential fields
+    /// (a single "Default" profile when none exist yet)
+    pub fn profiles_with_active(&self) -> Vec<ConfigProfile> {
+        let mut profiles = self.profiles.clone();
+        let current = ConfigProfile {
+            name: profiles.get(self.active_profile)
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| "Default".to_string()),
+            jira_domain: self.jira_domain.clone(),
+            email: self.email.clone(),
+            api_token: self.api_token.clone(),
+            auth_method: self.auth_method,
+        };
+        match profiles.get_mut(self.active_profile) {
+            Some(active) => *active = current,
+            None => profiles.push(current),
+        }
+        profiles
+    }
+
+    /// Make `index` the active profile, copying its credentials into the flat fields
+    pub fn activate_profile(&mut self, index: usize) {
+        if let Some(profile) = self.profiles.get(index) {
+            self.jira_domain = profile.jira_domain.clone();
+            self.email = profile.email.clone();
+            self.api_token = profile.api_token.clone();
+            self.auth_method = profile.auth_method;
+            self.active_profile = index;
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let config_path = Self::config_path()?;
+
+        // Ensure directory exists
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&config_path, contents)?;
+
+        Ok(())
+    }
+
+    pub fn is_configured(&self) -> bool {
+        !self.jira_domain.is_empty()
+            && (self.auth_method == AuthMethod::Bearer || !self.email.is_empty())
+            && self.api_token.is_some()
+    }
+
+    fn config_path() -> Result<PathBuf> {
+        let proj_dirs = ProjectDirs::from("com", "tzankich", "timebox")
+            .context("Could not determine config directory")?;
+        Ok(proj_dirs.config_dir().join("config.json"))
+    }
+
+    /// Cached sprints are missing or older than SPRINT_CACHE_HOURS
+    pub fn sprints_expired(&self) -> bool {
+        let now = Local::now().naive_local();
+        self.cached_sprints_at
+            .map(|fetched| now - fetched > chrono::Duration::hours(SPRINT_CACHE_HOURS))
+            .unwrap_or(true)
+    }
+
+    /// A remote config is set and wasn't fetched in the last REMOTE_CONFIG_REFRESH_HOURS
+    pub fn remote_config_due(&self) -> bool {
+        let configured = self.remote_config_url.as_deref().is_some_and(|url| !url.trim().is_empty());
+        let stale = self.remote_config_last_fetched
+            .map(|fetched| Utc::now() - fetched > chrono::Duration::hours(REMOTE_CONFIG_REFRESH_HOURS))
+            .unwrap_or(true);
+        configured && stale
+    }
+
+    /// Take the team-managed fields present in the remote file (the remote copy wins)
+    pub fn apply_remote(&mut self, remote: RemoteConfig) {
+        if let Some(tags) = remote.tags {
+            self.tags = tags;
+        }
+        if let Some(rules) = remote.tag_auto_rules {
+            self.tag_auto_rules = rules;
+            for rule in &mut self.tag_auto_rules {
+                rule.migrate_indices(&self.tags);
+            }
+        }
+        if let Some(colors) = remote.issue_type_colors {
+            self.issue_type_colors = colors;
+        }
+        self.remote_config_last_fetched = Some(Utc::now());
+    }
+
+    pub fn base_url(&self) -> String {
+        // Data Center / Server (personal access tokens) has no v3 API
+        let version = match self.auth_method {
+            AuthMethod::Basic => 3,
+            AuthMethod::Bearer => 2,
+        };
+        format!("https://{}/rest/api/{}", self.clean_domain(), version)
+    }
+
+    /// Jira Software's Agile API lives beside the platform REST API
+    pub fn agile_base_url(&self) -> String {
+        format!("https://{}/rest/agile/1.0", self.clean_domain())
+    }
+
+    fn clean_domain(&self) -> &str {
+        // Clean up the domain - remove protocol, trailing slashes, paths
+        self.jira_domain
+            .trim()
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+            .split('/')
+            .next()
+            .unwrap_or(&self.jira_domain)
+    }
+}
+
+/// Private notes attached to worklogs, kept in annotations.json beside the config
+/// and never sent to Jira
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Annotations {
+    #[serde(default)]
+    pub local_annotations: HashMap<String, String>,  // Worklog ID -> note
+}
+
+impl Annotations {
+    pub fn load() -> Result<Self> {
+        let path = Self::annotations_path()?;
+
+        if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .context("Failed to read annotations file")?;
+            serde_json::from_str(&contents)
+                .context("Failed to parse annotations file")
+        } else {
+            Ok(Annotations::default())
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::annotations_path()?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&path, contents)?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, worklog_id: &str) -> Option<&str> {
+        self.local_annotations.get(worklog_id).map(String::as_str)
+    }
+
+    /// Set the note for a worklog; an empty note removes it
+    pub fn set(&mut self, worklog_id: &str, note: &str) {
+        let note = note.trim();
+        if note.is_empty() {
+            self.local_annotations.remove(worklog_id);
+        } else {
+            self.local_annotations.insert(worklog_id.to_string(), note.to_string());
+        }
+    }
+
+    fn annotations_path() -> Result<PathBuf> {
+        let proj_dirs = ProjectDirs::from("com", "tzankich", "timebox")
+            .context("Could not determine config directory")?;
+        Ok(proj_dirs.config_dir().join("annotations.json"))
+    }
+}
+
+/// A worklog change made while offline, waiting to be sent to Jira
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum PendingOperation {
+    Add {
+        issue_key: String,
+        seconds: i64,
+        date: NaiveDate,
+        description: String,
+        start_time: Option<String>,
+    },
+    Edit {
+        issue_key: String,
+        worklog_id: String,
+        seconds: i64,
+        date: NaiveDate,
+        description: String,
+        start_time: Option<String>,
+    },
+    Delete {
+        issue_key: String,
+        worklog_id: String,
+    },
+}
+
+impl PendingOperation {
+    pub fn issue_key(&self) -> &str {
+        match self {
+            PendingOperation::Add { issue_key, .. }
+            | PendingOperation::Edit { issue_key, .. }
+            | PendingOperation::Delete { issue_key, .. } => issue_key,
+        }
+    }
+}
+
+/// A Jira domain made safe to use as a file or directory name, for data kept per site
+pub fn site_file_name(jira_domain: &str) -> String {
+    jira_domain.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect()
+}
+
+/// Changes queued while offline for one Jira site, kept in pending/<site>.json beside the config
+/// and replayed in order once Jira answers again (never against another profile's site)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PendingQueue {
+    #[serde(default)]
+    pub operations: Vec<PendingOperation>,
+    #[serde(skip)]
+    pub jira_domain: String,
+}
+
+impl PendingQueue {
+    pub fn load(jira_domain: &str) -> Result<Self> {
+        let path = Self::queue_path(jira_domain)?;
+
+        // The single queue file from before queues were per site belongs to the site open at the time
+        let legacy_path = path.parent().and_then(|dir| dir.parent()).map(|dir| dir.join("pending_operations.json"));
+        if let Some(legacy_path) = legacy_path.filter(|p| !path.exists() && p.exists()) {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::rename(&legacy_path, &path).context("Failed to move pending operations file")?;
+        }
+
+        let mut queue: PendingQueue = if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .context("Failed to read pending operations file")?;
+            serde_json::from_str(&contents)
+                .context("Failed to parse pending operations file")?
+        } else {
+            PendingQueue::default()
+        };
+        queue.jira_domain = jira_domain.to_string();
+        Ok(queue)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::queue_path(&self.jira_domain)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&path, contents)?;
+
+        Ok(())
+    }
+
+    fn queue_path(jira_domain: &str) -> Result<PathBuf> {
+        let proj_dirs = ProjectDirs::from("com", "tzankich", "timebox")
+            .context("Could not determine config directory")?;
+        Ok(proj_dirs.config_dir().join("pending").join(format!("{}.json", site_file_name(jira_domain))))
+    }
+}
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TimeFormat {
+    #[default]
+    HoursMinutes,  // "3h 15m"
+    Decimal,       // "3.25h"
+}
+
+/// Longest duration `parse_duration` accepts, so a slip like "1000h" isn't logged
+pub const MAX_DURATION_SECONDS: i64 = 48 * 3600;
+
+/// Parse time strings like "1h 30m", "2h", "45m", "1.5h", "1d 2h", "1:30", "90" (minutes), "4" (hours)
+/// Bare integers 1-8 are treated as hours, 9+ as minutes
+/// Days use Jira's default 8h working day
+/// Returns seconds, or None for zero or anything over MAX_DURATION_SECONDS
+pub fn parse_duration(input: &str) -> Option<i64> {
+    parse_duration_jira_days(input, 8.0).filter(|&seconds| seconds <= MAX_DURATION_SECONDS)
+}
+
+/// Same as `parse_duration`, with an explicit working-day length for "d" (and "w" = 5 days)
+/// Matches Jira's time tracking convention, e.g. "3d 2h" with 8h days = 26h
+pub fn parse_duration_jira_days(input: &str, hours_per_day: f32) -> Option<i64> {
+    let input = input.trim().to_lowercase();
+
+    if input.is_empty() {
+        return None;
+    }
+
+    // Clock notation "H:MM", e.g. "1:30" = 1h 30m, "0:05" = 5m
+    if let Some((hours, mins)) = input.split_once(':') {
+        let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if !is_digits(hours) || mins.len() != 2 || !is_digits(mins) {
+            return None;
+        }
+        let hours: i64 = hours.parse().ok()?;
+        let mins: i64 = mins.parse().ok()?;
+        if mins >= 60 {
+            return None;
+        }
+        let total_seconds = hours.checked_mul(3600)? + mins * 60;
+        return (total_seconds > 0).then_some(total_seconds);
+    }
+
+    let mut total_seconds: i64 = 0;
+    let mut current_num = String::new();
+    let mut has_unit = false;
+
+    for c in input.chars() {
+        if c.is_ascii_digit() || c == '.' {
+            current_num.push(c);
+        } else if c == 'w' {
+            if let Ok(weeks) = current_num.parse::<f32>() {
+                total_seconds += (weeks * 5.0 * hours_per_day * 3600.0) as i64;
+                has_unit = true;
+            }
+            current_num.clear();
+        } else if c == 'd' {
+            if let Ok(days) = current_num.parse::<f32>() {
+                total_seconds += (days * hours_per_day * 3600.0) as i64;
+                has_unit = true;
+            }
+            current_num.clear();
+        } else if c == 'h' {
+            if let Ok(hours) = current_num.parse::<f32>() {
+                total_seconds += (hours * 3600.0) as i64;
+                has_unit = true;
+            }
+            current_num.clear();
+        } else if c == 'm' {
+            if let Ok(mins) = current_num.parse::<f32>() {
+                total_seconds += (mins * 60.0) as i64;
+                has_unit = true;
+            }
+            current_num.clear();
+        } else if c == 's' {
+            if let Ok(secs) = current_num.parse::<i64>() {
+                total_seconds += secs;
+                has_unit = true;
+            }
+            current_num.clear();
+        } else if c.is_whitespace() {
+            // ignore whitespace
+        } else {
+            return None; // invalid character
+        }
+    }
+
+    // Handle trailing number without unit
+    if !current_num.is_empty() {
+        if let Ok(num) = current_num.parse::<f32>() {
+            if has_unit {
+                // If we already had units, trailing number is invalid
+                return None;
+            }
+            // Bare number: if it has decimal, treat as hours
+            // If integer 1-8, treat as hours (nobody logs 3 minutes)
+            // If integer 9+, treat as minutes
+            if current_num.contains('.') {
+                total_seconds = (num * 3600.0) as i64;
+            } else {
+                let int_val = num as i64;
+                if (1..=8).contains(&int_val) {
+                    total_seconds = int_val * 3600;
+                } else {
+                    total_seconds = (num * 60.0) as i64;
+                }
+            }
+        }
+    }
+
+    if total_seconds > 0 {
+        Some(total_seconds)
+    } else {
+        None
+    }
+}
+
+/// Format seconds as "Xh Ym" string ("45s" under a minute, "0m" for zero)
+pub fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let mins = (seconds % 3600) / 60;
+
+    if hours > 0 && mins > 0 {
+        format!("{}h {}m", hours, mins)
+    } else if hours > 0 {
+        format!("{}h", hours)
+    } else if mins > 0 {
+        format!("{}m", mins)
+    } else if seconds > 0 {
+        format!("{}s", seconds)
+    } else {
+        "0m".to_string()
+    }
+}
+
+/// Format seconds based on user's preferred time format
+/// `decimal_precision` is the number of decimals (1-3) shown in the Decimal format
+pub fn format_duration_with_format(seconds: i64, time_format: TimeFormat, decimal_precision: u8) -> String {
+    match time_format {
+        TimeFormat::HoursMinutes => format_duration(seconds),
+        TimeFormat::Decimal => {
+            let hours = seconds as f32 / 3600.0;
+            if hours == 0.0 {
+                "0h".to_string()
+            } else if hours == hours.floor() {
+                format!("{}h", hours as i32)
+            } else {
+                // Format with 1-3 decimals, then trim trailing zeros
+                let s = format!("{:.prec$}", hours, prec = decimal_precision.clamp(1, 3) as usize);
+                let trimmed = s.trim_end_matches('0').trim_end_matches('.');
+                if trimmed == "0" {
+                    // Too short to show at this precision ("0h" would read as nothing logged)
+                    format_duration(seconds)
+                } else {
+                    format!("{}h", trimmed)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_covers_zero_and_sub_minute() {
+        assert_eq!(format_duration(0), "0m");
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(90), "1m");
+        assert_eq!(format_duration(3600), "1h");
+        assert_eq!(format_duration(5400), "1h 30m");
+    }
+
+    #[test]
+    fn decimal_format_never_rounds_to_nothing() {
+        assert_eq!(format_duration_with_format(0, TimeFormat::Decimal, 2), "0h");
+        assert_eq!(format_duration_with_format(30, TimeFormat::Decimal, 1), "30s");
+        assert_eq!(format_duration_with_format(120, TimeFormat::Decimal, 1), "2m");
+        assert_eq!(format_duration_with_format(120, TimeFormat::Decimal, 2), "0.03h");
+        assert_eq!(format_duration_with_format(5400, TimeFormat::Decimal, 2), "1.5h");
+    }
+
+    #[test]
+    fn clock_notation_reads_hours_and_minutes() {
+        assert_eq!(parse_duration("1:30"), Some(5400));
+        assert_eq!(parse_duration("0:45"), Some(2700));
+        assert_eq!(parse_duration("1:60"), None);
+        assert_eq!(parse_duration(":30"), None);
+        assert_eq!(parse_duration("1:5"), None);
+        assert_eq!(parse_duration("0:00"), None);
+    }
+
+    #[test]
+    fn durations_are_capped_at_48_hours() {
+        assert_eq!(parse_duration("48h"), Some(MAX_DURATION_SECONDS));
+        assert_eq!(parse_duration("48:00"), Some(MAX_DURATION_SECONDS));
+        assert_eq!(parse_duration("48h 1m"), None);
+        assert_eq!(parse_duration("1000h"), None);
+        assert_eq!(parse_duration("0h"), None);
+        assert_eq!(parse_duration("0"), None);
+    }
+
+    #[test]
+    fn sub_minute_text_parses_back() {
+        assert_eq!(parse_duration(&format_duration(45)), Some(45));
+    }
+}
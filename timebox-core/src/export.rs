@@ -0,0 +1,34 @@
+use serde::Serialize;
+
+use crate::api::TimeEntry;
+
+/// One worklog as written by the JSON and CSV exports and `timebox-cli list --json`
+#[derive(Serialize)]
+pub struct ExportEntry {
+    pub worklog_id: String,
+    pub issue_key: String,
+    pub issue_summary: String,
+    pub issue_type: String,
+    pub seconds: i64,
+    pub description: String,
+    pub date: String,
+    pub start_time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_note: Option<String>,
+}
+
+impl From<&TimeEntry> for ExportEntry {
+    fn from(entry: &TimeEntry) -> Self {
+        Self {
+            worklog_id: entry.worklog_id.clone(),
+            issue_key: entry.issue_key.clone(),
+            issue_summary: entry.issue_summary.clone(),
+            issue_type: entry.issue_type.clone(),
+            seconds: entry.seconds,
+            description: entry.description.clone(),
+            date: entry.date.format("%Y-%m-%d").to_string(),
+            start_time: entry.start_time.clone(),
+            private_note: None,
+        }
+    }
+}
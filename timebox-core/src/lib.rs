@@ -0,0 +1,13 @@
+//! Parsing, formatting, config and the Jira client shared by the timebox app and its companion tools
+//! (no GUI dependencies)
+
+pub mod api;
+pub mod config;
+pub mod export;
+mod adf;
+mod duration;
+mod week;
+
+pub use adf::{extract_adf_to_markdown, markdown_to_adf};
+pub use duration::*;
+pub use week::week_start;
@@ -0,0 +1,10 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// First day of the week containing `date`, for weeks beginning on `start_day`
+pub fn week_start(date: NaiveDate, start_day: Weekday) -> NaiveDate {
+    let days_into_week = match start_day {
+        Weekday::Sun => date.weekday().num_days_from_sunday(),
+        _ => date.weekday().num_days_from_monday(),
+    };
+    date - Duration::days(days_into_week as i64)
+}